@@ -5,11 +5,88 @@
 //! 
 //! ## Localização do Arquivo de Configuração
 //! As configurações são salvas em: `%APPDATA%\iris\config.json`
+//! 
+//! ## Versões do Formato
+//! Arquivos de versões anteriores são migrados ao carregar (ver `migrations`).
+//! Antes da migração, o arquivo original é copiado para
+//! `config.v<versão>.json.bak` na mesma pasta.
+//! 
+//! ## Segurança dos Dados
+//! - Gravação atômica: arquivo temporário, `fsync` e renomeação
+//! - Backups periódicos em `backups/config-<timestamp>.json` (os mais recentes são mantidos)
+//! - Toda versão salva também é registrada no histórico (`history/`)
+//! - Arquivos inválidos nunca são sobrescritos: `try_load` retorna o erro com a
+//!   posição, e a interface oferece restaurar um backup
 
+use std::fmt;
 use std::fs;
-use std::path::PathBuf;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use super::formats::ConfigFormat;
+use super::history::{content_hash, ConfigHistory};
+use super::migrations::{migrate, CURRENT_SCHEMA_VERSION};
 use super::models::AppState;
 
+/// Quantidade de backups mantidos na pasta `backups`
+pub const MAX_BACKUPS: usize = 20;
+
+/// Intervalo mínimo entre dois backups automáticos
+const BACKUP_INTERVAL: Duration = Duration::from_secs(10 * 60);
+
+/// Erro ao carregar um arquivo de configuração
+#[derive(Clone, Debug)]
+pub struct ConfigError {
+    /// Descrição do erro
+    pub message: String,
+    /// Linha e coluna do erro (a partir de 1), quando conhecidas
+    pub location: Option<(usize, usize)>,
+    /// Conteúdo da linha onde o erro ocorreu
+    pub excerpt: Option<String>,
+}
+
+impl ConfigError {
+    /// Cria um erro sem posição no arquivo
+    pub fn new(message: impl Into<String>) -> Self {
+        Self {
+            message: message.into(),
+            location: None,
+            excerpt: None,
+        }
+    }
+
+    /// Cria um erro de sintaxe com a posição e a linha correspondente
+    /// (`line` = 0 quando a posição é desconhecida)
+    pub(crate) fn at(message: impl Into<String>, content: &str, line: usize, column: usize) -> Self {
+        let location = (line > 0).then_some((line, column));
+        Self {
+            message: message.into(),
+            location,
+            excerpt: location.and_then(|(line, _)| content.lines().nth(line - 1).map(str::to_string)),
+        }
+    }
+
+    /// Cria um erro de sintaxe JSON com a posição e a linha correspondente
+    pub(crate) fn from_json(content: &str, error: &serde_json::Error) -> Self {
+        Self::at(format!("Erro ao processar JSON: {}", error), content, error.line(), error.column())
+    }
+}
+
+impl fmt::Display for ConfigError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.message)
+    }
+}
+
+/// Backup de configuração disponível para restauração
+#[derive(Clone, Debug)]
+pub struct BackupInfo {
+    /// Caminho do arquivo de backup
+    pub path: PathBuf,
+    /// Momento em que o backup foi criado
+    pub created: SystemTime,
+}
+
 /// Gerenciador de configurações da aplicação.
 /// 
 /// Responsável por:
@@ -31,6 +108,11 @@ impl ConfigManager {
         Self { config_path }
     }
     
+    /// Cria um gerenciador para um arquivo de configuração específico
+    pub fn with_path(config_path: PathBuf) -> Self {
+        Self { config_path }
+    }
+    
     /// Retorna o caminho do arquivo de configuração.
     /// 
     /// No Windows: `%APPDATA%\iris\config.json`
@@ -51,26 +133,138 @@ impl ConfigManager {
     /// Carrega o estado da aplicação do disco.
     /// 
     /// Se o arquivo não existir ou for inválido, retorna um estado vazio.
+    /// Para detectar arquivos inválidos (e não sobrescrevê-los), use `try_load`.
     /// 
     /// # Exemplo
     /// ```rust
+    /// use iris::ConfigManager;
+    ///
     /// let manager = ConfigManager::new();
     /// let state = manager.load();
     /// println!("Aplicações carregadas: {}", state.apps.len());
     /// ```
     pub fn load(&self) -> AppState {
-        if self.config_path.exists() {
-            if let Ok(content) = fs::read_to_string(&self.config_path) {
-                if let Ok(state) = serde_json::from_str(&content) {
-                    return state;
-                }
-            }
+        self.try_load().unwrap_or_else(|e| {
+            eprintln!("{}", e);
+            AppState::default()
+        })
+    }
+    
+    /// Lê, migra e desserializa o arquivo de configuração.
+    /// 
+    /// Se o arquivo não existir, retorna um estado vazio. Se for de uma
+    /// versão anterior, o original é copiado para o backup antes da migração.
+    /// 
+    /// # Retorno
+    /// O estado carregado, ou o erro com a posição no arquivo.
+    pub fn try_load(&self) -> Result<AppState, ConfigError> {
+        if !self.config_path.exists() {
+            return Ok(AppState::default());
+        }
+        let content = fs::read_to_string(&self.config_path)
+            .map_err(|e| ConfigError::new(format!("Erro ao ler configurações: {}", e)))?;
+        let (state, original_version) = parse_state(&content)?;
+        if original_version < CURRENT_SCHEMA_VERSION {
+            let backup = migration_backup_path(&self.config_path, original_version);
+            fs::copy(&self.config_path, &backup)
+                .map_err(|e| ConfigError::new(format!("Erro ao criar backup antes da migração: {}", e)))?;
+        }
+        Ok(state)
+    }
+    
+    /// Hash do conteúdo atual do arquivo (None se não existir)
+    pub fn file_hash(&self) -> Option<String> {
+        fs::read_to_string(&self.config_path)
+            .ok()
+            .map(|content| content_hash(&content))
+    }
+    
+    /// Histórico de versões salvas (pasta `history` ao lado do arquivo)
+    pub fn history(&self) -> ConfigHistory {
+        ConfigHistory::new(self.config_path.with_file_name("history"))
+    }
+    
+    /// Pasta dos backups automáticos
+    pub fn backup_dir(&self) -> PathBuf {
+        self.config_path.with_file_name("backups")
+    }
+    
+    /// Lista os backups disponíveis, do mais recente para o mais antigo
+    pub fn list_backups(&self) -> Vec<BackupInfo> {
+        let Ok(entries) = fs::read_dir(self.backup_dir()) else {
+            return Vec::new();
+        };
+        let mut backups: Vec<BackupInfo> = entries
+            .flatten()
+            .filter_map(|entry| {
+                let name = entry.file_name().to_string_lossy().to_string();
+                let millis = name.strip_prefix("config-")?.strip_suffix(".json")?.parse().ok()?;
+                Some(BackupInfo {
+                    path: entry.path(),
+                    created: UNIX_EPOCH + Duration::from_millis(millis),
+                })
+            })
+            .collect();
+        backups.sort_by_key(|backup| std::cmp::Reverse(backup.created));
+        backups
+    }
+    
+    /// Restaura um backup como arquivo de configuração atual.
+    /// 
+    /// O arquivo atual (possivelmente corrompido) é preservado ao lado
+    /// como `config.corrupt-<timestamp>.json` antes de ser substituído.
+    pub fn restore_backup(&self, backup: &Path) -> Result<AppState, ConfigError> {
+        let content = fs::read_to_string(backup)
+            .map_err(|e| ConfigError::new(format!("Erro ao ler backup: {}", e)))?;
+        let (state, _) = parse_state(&content)?;
+        self.set_aside_current().map_err(ConfigError::new)?;
+        write_atomic(&self.config_path, &content).map_err(ConfigError::new)?;
+        Ok(state)
+    }
+    
+    /// Move o arquivo atual para `config.corrupt-<timestamp>.json`.
+    /// 
+    /// Usado ao recomeçar com uma configuração vazia, para que o
+    /// arquivo inválido não seja perdido.
+    pub fn set_aside_current(&self) -> Result<(), String> {
+        if !self.config_path.exists() {
+            return Ok(());
         }
-        AppState::default()
+        let target = self
+            .config_path
+            .with_file_name(format!("config.corrupt-{}.json", unix_millis(SystemTime::now())));
+        fs::rename(&self.config_path, target)
+            .map_err(|e| format!("Erro ao preservar o arquivo atual: {}", e))
+    }
+    
+    /// Copia o arquivo atual para a pasta de backups, se o último backup
+    /// for mais antigo que `BACKUP_INTERVAL`, e remove os excedentes.
+    fn rotate_backups(&self) -> Result<(), String> {
+        if !self.config_path.exists() {
+            return Ok(());
+        }
+        let backups = self.list_backups();
+        let now = SystemTime::now();
+        let is_due = backups.first().is_none_or(|latest| {
+            now.duration_since(latest.created).unwrap_or_default() >= BACKUP_INTERVAL
+        });
+        if is_due {
+            let dir = self.backup_dir();
+            fs::create_dir_all(&dir).map_err(|e| format!("Erro ao criar pasta de backups: {}", e))?;
+            fs::copy(&self.config_path, dir.join(format!("config-{}.json", unix_millis(now))))
+                .map_err(|e| format!("Erro ao criar backup: {}", e))?;
+        }
+        for old in self.list_backups().iter().skip(MAX_BACKUPS) {
+            let _ = fs::remove_file(&old.path);
+        }
+        Ok(())
     }
     
     /// Salva o estado da aplicação em disco.
     /// 
+    /// A gravação é atômica, e o arquivo anterior é copiado para a
+    /// pasta de backups periodicamente.
+    /// 
     /// # Argumentos
     /// * `state` - Estado a ser salvo
     /// 
@@ -79,8 +273,15 @@ impl ConfigManager {
     pub fn save(&self, state: &AppState) -> Result<(), String> {
         match serde_json::to_string_pretty(state) {
             Ok(json) => {
-                fs::write(&self.config_path, json)
-                    .map_err(|e| format!("Erro ao salvar configurações: {}", e))
+                if let Err(e) = self.rotate_backups() {
+                    eprintln!("{}", e);
+                }
+                write_atomic(&self.config_path, &json)
+                    .map_err(|e| format!("Erro ao salvar configurações: {}", e))?;
+                if let Err(e) = self.history().record(&json, state.apps.len()) {
+                    eprintln!("Erro ao registrar histórico: {}", e);
+                }
+                Ok(())
             }
             Err(e) => Err(format!("Erro ao serializar configurações: {}", e)),
         }
@@ -88,22 +289,35 @@ impl ConfigManager {
     
     /// Exporta as configurações para um arquivo específico.
     /// 
+    /// O formato (JSON, TOML ou YAML) é definido pela extensão do
+    /// arquivo; sem extensão conhecida, é usado JSON.
+    /// 
+    /// Todos os textos passam por `redact` antes de serem gravados,
+    /// permitindo remover valores sensíveis (ex: segredos colados nos comandos).
+    /// 
     /// # Argumentos
     /// * `state` - Estado a ser exportado
     /// * `path` - Caminho do arquivo de destino
-    pub fn export(&self, state: &AppState, path: &PathBuf) -> Result<(), String> {
-        match serde_json::to_string_pretty(state) {
-            Ok(json) => {
-                fs::write(path, json)
-                    .map_err(|e| format!("Erro ao exportar configurações: {}", e))
-            }
-            Err(e) => Err(format!("Erro ao serializar configurações: {}", e)),
-        }
+    /// * `redact` - Transformação aplicada a cada texto
+    pub fn export(
+        &self,
+        state: &AppState,
+        path: &PathBuf,
+        redact: impl Fn(&str) -> String,
+    ) -> Result<(), String> {
+        let mut value = serde_json::to_value(state)
+            .map_err(|e| format!("Erro ao serializar configurações: {}", e))?;
+        redact_strings(&mut value, &redact);
+        let format = ConfigFormat::from_path(path).unwrap_or_default();
+        let content = format.serialize(&value)?;
+        fs::write(path, content)
+            .map_err(|e| format!("Erro ao exportar configurações: {}", e))
     }
     
     /// Importa configurações de um arquivo.
     /// 
     /// As aplicações importadas recebem novos IDs para evitar conflitos.
+    /// O formato é detectado pela extensão ou, sem ela, pelo conteúdo.
     /// 
     /// # Argumentos
     /// * `path` - Caminho do arquivo a ser importado
@@ -114,8 +328,8 @@ impl ConfigManager {
         let content = fs::read_to_string(path)
             .map_err(|e| format!("Erro ao ler arquivo: {}", e))?;
         
-        let mut state: AppState = serde_json::from_str(&content)
-            .map_err(|e| format!("Erro ao processar JSON: {}", e))?;
+        let format = ConfigFormat::for_file(path, &content);
+        let (mut state, _) = parse_state_as(&content, format).map_err(|e| e.to_string())?;
         
         // Gera novos IDs para evitar conflitos
         for app in &mut state.apps {
@@ -126,6 +340,101 @@ impl ConfigManager {
     }
 }
 
+/// Interpreta o conteúdo de um arquivo de configuração, migrando-o
+/// para o formato atual.
+/// 
+/// # Retorno
+/// O estado e a versão original do arquivo.
+pub(crate) fn parse_state(content: &str) -> Result<(AppState, u32), ConfigError> {
+    parse_state_as(content, ConfigFormat::Json)
+}
+
+/// Interpreta um arquivo de configuração em qualquer formato suportado,
+/// migrando-o para o formato atual.
+pub(crate) fn parse_state_as(content: &str, format: ConfigFormat) -> Result<(AppState, u32), ConfigError> {
+    let mut value = format.parse(content)?;
+    let original_version = migrate(&mut value).map_err(ConfigError::new)?;
+    let state = serde_json::from_value(value)
+        .map_err(|e| ConfigError::new(format!("Erro ao processar configurações: {}", e)))?;
+    Ok((state, original_version))
+}
+
+/// Grava um arquivo de forma atômica: escreve em um arquivo temporário,
+/// força a gravação em disco e o renomeia sobre o destino.
+/// 
+/// Uma falha no meio da gravação nunca deixa o destino pela metade.
+pub fn write_atomic(path: &Path, content: &str) -> Result<(), String> {
+    write_atomic_with_mode(path, content, false)
+}
+
+/// Como [`write_atomic`], mas o arquivo temporário já nasce legível apenas
+/// pelo usuário (0600 no Unix), sem janela com as permissões padrão.
+pub fn write_atomic_private(path: &Path, content: &str) -> Result<(), String> {
+    write_atomic_with_mode(path, content, true)
+}
+
+fn write_atomic_with_mode(path: &Path, content: &str, private: bool) -> Result<(), String> {
+    let mut temp_name = path.file_name().unwrap_or_default().to_os_string();
+    temp_name.push(".tmp");
+    let temp_path = path.with_file_name(temp_name);
+
+    let write = || -> std::io::Result<()> {
+        // Um temporário antigo manteria as permissões com que foi criado
+        let _ = fs::remove_file(&temp_path);
+        let mut options = fs::OpenOptions::new();
+        options.write(true).create_new(true);
+        #[cfg(unix)]
+        if private {
+            use std::os::unix::fs::OpenOptionsExt;
+            options.mode(0o600);
+        }
+        #[cfg(not(unix))]
+        let _ = private;
+        let mut file = options.open(&temp_path)?;
+        file.write_all(content.as_bytes())?;
+        file.sync_all()?;
+        fs::rename(&temp_path, path)?;
+        #[cfg(unix)]
+        if let Some(dir) = path.parent() {
+            // Garante que a renomeação também esteja em disco
+            fs::File::open(dir)?.sync_all()?;
+        }
+        Ok(())
+    };
+    write().map_err(|e| {
+        let _ = fs::remove_file(&temp_path);
+        e.to_string()
+    })
+}
+
+/// Milissegundos desde UNIX epoch
+fn unix_millis(time: SystemTime) -> u128 {
+    time.duration_since(UNIX_EPOCH).unwrap_or_default().as_millis()
+}
+
+/// Caminho do backup criado antes de migrar um arquivo da versão `version`
+fn migration_backup_path(config_path: &Path, version: u32) -> PathBuf {
+    config_path.with_file_name(format!("config.v{}.json.bak", version))
+}
+
+/// Aplica `redact` a todos os textos de um valor JSON
+pub(crate) fn redact_strings(value: &mut serde_json::Value, redact: &impl Fn(&str) -> String) {
+    match value {
+        serde_json::Value::String(text) => *text = redact(text),
+        serde_json::Value::Array(items) => {
+            for item in items {
+                redact_strings(item, redact);
+            }
+        }
+        serde_json::Value::Object(map) => {
+            for item in map.values_mut() {
+                redact_strings(item, redact);
+            }
+        }
+        _ => {}
+    }
+}
+
 impl Default for ConfigManager {
     fn default() -> Self {
         Self::new()
@@ -147,4 +456,64 @@ mod tests {
         let state = AppState::default();
         assert!(state.apps.is_empty());
     }
+
+    #[test]
+    fn test_load_migrates_and_backs_up_old_files() {
+        let dir = std::env::temp_dir().join(format!("iris_config_migration_{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("config.json");
+        let legacy = include_str!("../../tests/fixtures/config_v0.json");
+        fs::write(&path, legacy).unwrap();
+
+        let manager = ConfigManager::with_path(path.clone());
+        let state = manager.load();
+        assert_eq!(state.apps.len(), 3);
+        assert_eq!(state.schema_version, CURRENT_SCHEMA_VERSION);
+        assert_eq!(fs::read_to_string(migration_backup_path(&path, 0)).unwrap(), legacy);
+
+        manager.save(&state).unwrap();
+        let saved: serde_json::Value = serde_json::from_str(&fs::read_to_string(&path).unwrap()).unwrap();
+        assert_eq!(saved["schema_version"], CURRENT_SCHEMA_VERSION);
+        let _ = fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn test_invalid_file_reports_location_and_restores_backup() {
+        let dir = std::env::temp_dir().join(format!("iris_config_recovery_{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        let manager = ConfigManager::with_path(dir.join("config.json"));
+
+        let mut state = AppState::default();
+        state.add_app(crate::core::AppConfig::new("API".to_string()));
+        manager.save(&state).unwrap();
+        manager.save(&state).unwrap();
+        assert_eq!(manager.list_backups().len(), 1);
+
+        fs::write(manager.path(), "{\n  \"apps\": [\n    { \"name\": }\n  ]\n}").unwrap();
+        let error = manager.try_load().unwrap_err();
+        assert_eq!(error.location.map(|(line, _)| line), Some(3));
+        assert_eq!(error.excerpt.as_deref(), Some("    { \"name\": }"));
+
+        let backup = &manager.list_backups()[0];
+        assert_eq!(manager.restore_backup(&backup.path).unwrap().apps.len(), 1);
+        assert_eq!(manager.try_load().unwrap().apps.len(), 1);
+        let corrupt_files = fs::read_dir(&dir)
+            .unwrap()
+            .flatten()
+            .filter(|entry| entry.file_name().to_string_lossy().starts_with("config.corrupt-"))
+            .count();
+        assert_eq!(corrupt_files, 1);
+        let _ = fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn test_export_redacts_every_string() {
+        let mut value = serde_json::json!({
+            "apps": [{ "name": "api", "commands": ["deploy --token tok_123"], "port": 8080 }]
+        });
+        redact_strings(&mut value, &|text| text.replace("tok_123", "${secret:TOKEN}"));
+        assert_eq!(value["apps"][0]["commands"][0], "deploy --token ${secret:TOKEN}");
+        assert_eq!(value["apps"][0]["port"], 8080);
+    }
 }
@@ -0,0 +1,512 @@
+//! # Detecção de Projetos
+//!
+//! Inspeciona uma pasta e sugere nome, ícone e comandos a partir dos
+//! arquivos de manifesto encontrados.
+//!
+//! ## Tecnologias reconhecidas (em ordem de prioridade)
+//! - `package.json`: scripts e gerenciador pelo lockfile (npm, yarn, pnpm, bun)
+//! - `Cargo.toml`, `go.mod`
+//! - `pyproject.toml` / `manage.py` (Django), com poetry ou uv
+//! - `*.csproj`, `Gemfile` (Rails)
+//! - `Makefile` (alvos) e `docker-compose.yml`
+//!
+//! Uma pasta pode ter várias (ex: Node + Makefile); nome, ícone e
+//! comandos principais vêm da primeira, e as sugestões reúnem todas.
+//!
+//! `scan_projects` percorre uma árvore de pastas aplicando a detecção
+//! em cada uma (descoberta em lote). A varredura é limitada em pastas
+//! visitadas e pode ser cancelada, pois roda fora da thread da interface.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+
+use serde_json::Value;
+
+use super::importers::compose::COMPOSE_FILE_NAMES;
+use super::importers::folder_name;
+
+/// Resultado da inspeção de uma pasta
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ProjectDetection {
+    /// Tecnologias encontradas (ex: "Node (pnpm)", "Rust")
+    pub stacks: Vec<String>,
+    /// Nome sugerido (do manifesto ou da pasta)
+    pub name: String,
+    /// Ícone sugerido (vazio se nenhum)
+    pub icon: String,
+    /// Sequência de comandos sugerida para executar o projeto
+    pub commands: Vec<String>,
+    /// Todos os comandos encontrados, para escolha manual
+    pub suggestions: Vec<String>,
+}
+
+/// Tecnologia encontrada por um detector
+struct Stack {
+    label: String,
+    name: Option<String>,
+    icon: &'static str,
+    commands: Vec<String>,
+    suggestions: Vec<String>,
+}
+
+/// Inspeciona uma pasta.
+///
+/// # Retorno
+/// `None` se a pasta não existir ou nenhum manifesto for reconhecido.
+///
+/// # Exemplo
+/// ```rust,no_run
+/// use std::path::Path;
+/// use iris::core::detect_project;
+///
+/// if let Some(detection) = detect_project(Path::new("/projetos/api")) {
+///     println!("{}: {:?}", detection.name, detection.commands);
+/// }
+/// ```
+pub fn detect_project(dir: &Path) -> Option<ProjectDetection> {
+    if !dir.is_dir() {
+        return None;
+    }
+    let detectors: [fn(&Path) -> Option<Stack>; 8] = [
+        detect_node,
+        detect_rust,
+        detect_go,
+        detect_python,
+        detect_dotnet,
+        detect_ruby,
+        detect_make,
+        detect_compose,
+    ];
+    let stacks: Vec<Stack> = detectors.iter().filter_map(|detect| detect(dir)).collect();
+    if stacks.is_empty() {
+        return None;
+    }
+
+    let mut suggestions: Vec<String> = Vec::new();
+    for command in stacks.iter().flat_map(|stack| &stack.suggestions) {
+        if !suggestions.contains(command) {
+            suggestions.push(command.clone());
+        }
+    }
+    Some(ProjectDetection {
+        stacks: stacks.iter().map(|stack| stack.label.clone()).collect(),
+        name: stacks
+            .iter()
+            .find_map(|stack| stack.name.clone())
+            .unwrap_or_else(|| folder_name(dir)),
+        icon: stacks
+            .iter()
+            .map(|stack| stack.icon)
+            .find(|icon| !icon.is_empty())
+            .unwrap_or_default()
+            .to_string(),
+        commands: stacks
+            .iter()
+            .map(|stack| &stack.commands)
+            .find(|commands| !commands.is_empty())
+            .cloned()
+            .unwrap_or_default(),
+        suggestions,
+    })
+}
+
+/// Pastas nunca percorridas na descoberta (dependências e saídas de build)
+const SCAN_SKIP: [&str; 9] = ["node_modules", "target", "vendor", "dist", "build", "bin", "obj", "venv", "__pycache__"];
+
+/// Quantidade máxima de projetos retornados por `scan_projects`
+pub const MAX_SCAN_RESULTS: usize = 200;
+
+/// Quantidade máxima de pastas visitadas por `scan_projects`
+pub const MAX_SCAN_DIRS: usize = 5000;
+
+/// Resultado de `scan_projects`
+#[derive(Debug, Default)]
+pub struct ProjectScan {
+    /// Pastas com projeto detectado
+    pub projects: Vec<(PathBuf, ProjectDetection)>,
+    /// Se a varredura parou antes do fim (limite de pastas ou de resultados)
+    pub truncated: bool,
+}
+
+/// Percorre uma pasta até `max_depth` níveis abaixo dela (0 = só a
+/// própria pasta) e detecta os projetos encontrados.
+///
+/// Pastas ocultas e de dependências/build (`node_modules`, `target`...)
+/// são ignoradas. Os resultados seguem a ordem alfabética por nível.
+/// Para ao atingir `MAX_SCAN_DIRS` pastas ou `MAX_SCAN_RESULTS` projetos,
+/// ou quando `cancel` é marcado.
+pub fn scan_projects(root: &Path, max_depth: usize, cancel: &AtomicBool) -> ProjectScan {
+    let mut scan = ProjectScan::default();
+    let mut visited = 0;
+    let mut level = vec![root.to_path_buf()];
+    for depth in 0..=max_depth {
+        let mut next = Vec::new();
+        for dir in level {
+            if cancel.load(Ordering::Relaxed) || visited >= MAX_SCAN_DIRS {
+                scan.truncated = true;
+                return scan;
+            }
+            visited += 1;
+            if let Some(detection) = detect_project(&dir) {
+                scan.projects.push((dir.clone(), detection));
+                if scan.projects.len() >= MAX_SCAN_RESULTS {
+                    scan.truncated = true;
+                    return scan;
+                }
+            }
+            if depth == max_depth {
+                continue;
+            }
+            let Ok(entries) = fs::read_dir(&dir) else {
+                continue;
+            };
+            let mut children: Vec<PathBuf> = entries
+                .flatten()
+                .filter(|entry| entry.file_type().is_ok_and(|kind| kind.is_dir()))
+                .filter(|entry| {
+                    let name = entry.file_name().to_string_lossy().to_string();
+                    !name.starts_with('.') && !SCAN_SKIP.contains(&name.as_str())
+                })
+                .map(|entry| entry.path())
+                .collect();
+            children.sort();
+            next.extend(children);
+        }
+        level = next;
+    }
+    scan
+}
+
+/// Lê um arquivo de texto da pasta, se existir
+fn read(dir: &Path, name: &str) -> Option<String> {
+    fs::read_to_string(dir.join(name)).ok()
+}
+
+/// Node: scripts do `package.json` com o gerenciador indicado pelo lockfile
+fn detect_node(dir: &Path) -> Option<Stack> {
+    let package: Value = serde_json::from_str(&read(dir, "package.json")?).ok()?;
+    let manager = [
+        ("bun.lockb", "bun"),
+        ("bun.lock", "bun"),
+        ("pnpm-lock.yaml", "pnpm"),
+        ("yarn.lock", "yarn"),
+    ]
+    .iter()
+    .find(|(lockfile, _)| dir.join(lockfile).exists())
+    .map_or("npm", |(_, manager)| *manager);
+    let run = |script: &str| match manager {
+        "npm" | "bun" => format!("{} run {}", manager, script),
+        _ => format!("{} {}", manager, script),
+    };
+    let install = format!("{} install", manager);
+
+    let scripts: Vec<&String> = package
+        .get("scripts")
+        .and_then(Value::as_object)
+        .map(|scripts| scripts.keys().collect())
+        .unwrap_or_default();
+    let main_script = ["dev", "start", "serve", "watch"]
+        .iter()
+        .find(|name| scripts.iter().any(|s| s == *name));
+
+    let has_dependency = |name: &str| {
+        ["dependencies", "devDependencies"]
+            .iter()
+            .any(|section| package.get(section).and_then(|deps| deps.get(name)).is_some())
+    };
+    let icon = [
+        ("next", "nextjs"),
+        ("nuxt", "nuxtjs"),
+        ("@nestjs/core", "nestjs"),
+        ("@angular/core", "angular"),
+        ("svelte", "svelte"),
+        ("vue", "vuejs"),
+        ("react", "react"),
+        ("vite", "vitejs"),
+    ]
+    .iter()
+    .find(|(dependency, _)| has_dependency(dependency))
+    .map_or("nodejs", |(_, icon)| *icon);
+
+    let mut suggestions = vec![install.clone()];
+    suggestions.extend(scripts.iter().map(|script| run(script)));
+    Some(Stack {
+        label: format!("Node ({})", manager),
+        name: package.get("name").and_then(Value::as_str).map(str::to_string),
+        icon,
+        commands: main_script.map(|script| vec![install, run(script)]).unwrap_or_default(),
+        suggestions,
+    })
+}
+
+/// Rust: `cargo run` com o nome do pacote
+fn detect_rust(dir: &Path) -> Option<Stack> {
+    let manifest: toml::Table = read(dir, "Cargo.toml")?.parse().ok()?;
+    let name = manifest
+        .get("package")
+        .and_then(|package| package.get("name"))
+        .and_then(toml::Value::as_str)
+        .map(str::to_string);
+    Some(Stack {
+        label: "Rust".to_string(),
+        name,
+        icon: "rust",
+        commands: vec!["cargo run".to_string()],
+        suggestions: ["cargo run", "cargo build", "cargo test", "cargo watch -x run"]
+            .map(str::to_string)
+            .to_vec(),
+    })
+}
+
+/// Go: nome pelo último segmento do módulo
+fn detect_go(dir: &Path) -> Option<Stack> {
+    let content = read(dir, "go.mod")?;
+    let name = content
+        .lines()
+        .find_map(|line| line.trim().strip_prefix("module "))
+        .and_then(|module| module.trim().rsplit('/').next())
+        .map(str::to_string);
+    Some(Stack {
+        label: "Go".to_string(),
+        name,
+        icon: "go",
+        commands: vec!["go run .".to_string()],
+        suggestions: ["go run .", "go build ./...", "go test ./..."].map(str::to_string).to_vec(),
+    })
+}
+
+/// Python: Django (`manage.py`), scripts do `pyproject.toml` e poetry/uv
+fn detect_python(dir: &Path) -> Option<Stack> {
+    let pyproject: Option<toml::Table> = read(dir, "pyproject.toml").and_then(|content| content.parse().ok());
+    let is_django = dir.join("manage.py").exists();
+    if pyproject.is_none() && !is_django {
+        return None;
+    }
+
+    let poetry = pyproject.as_ref().and_then(|p| p.get("tool")).and_then(|tool| tool.get("poetry"));
+    let runner = if dir.join("uv.lock").exists() {
+        "uv run "
+    } else if poetry.is_some() {
+        "poetry run "
+    } else {
+        ""
+    };
+    let project = pyproject.as_ref().and_then(|p| p.get("project"));
+    let name = project
+        .or(poetry)
+        .and_then(|section| section.get("name"))
+        .and_then(toml::Value::as_str)
+        .map(str::to_string);
+
+    let mut suggestions = Vec::new();
+    match runner {
+        "uv run " => suggestions.push("uv sync".to_string()),
+        "poetry run " => suggestions.push("poetry install".to_string()),
+        _ if dir.join("requirements.txt").exists() => suggestions.push("pip install -r requirements.txt".to_string()),
+        _ => {}
+    }
+    let mut commands = Vec::new();
+    if is_django {
+        commands.push(format!("{}python manage.py migrate", runner));
+        commands.push(format!("{}python manage.py runserver", runner));
+    } else if let Some(entry) = ["main.py", "app.py"].iter().find(|file| dir.join(file).exists()) {
+        commands.push(format!("{}python {}", runner, entry));
+    }
+    let scripts = project
+        .and_then(|p| p.get("scripts"))
+        .or_else(|| poetry.and_then(|p| p.get("scripts")))
+        .and_then(toml::Value::as_table);
+    if let Some(scripts) = scripts {
+        suggestions.extend(scripts.keys().map(|script| format!("{}{}", runner, script)));
+    }
+    suggestions.splice(0..0, commands.iter().cloned());
+
+    Some(Stack {
+        label: if is_django { "Python (Django)" } else { "Python" }.to_string(),
+        name,
+        icon: "python",
+        commands,
+        suggestions,
+    })
+}
+
+/// .NET: primeiro `*.csproj` da pasta
+fn detect_dotnet(dir: &Path) -> Option<Stack> {
+    let mut projects: Vec<String> = fs::read_dir(dir)
+        .ok()?
+        .flatten()
+        .filter_map(|entry| entry.file_name().to_str().map(str::to_string))
+        .filter(|name| name.ends_with(".csproj"))
+        .collect();
+    projects.sort();
+    let name = projects.first()?.trim_end_matches(".csproj").to_string();
+    Some(Stack {
+        label: ".NET".to_string(),
+        name: Some(name),
+        icon: "dotnetcore",
+        commands: vec!["dotnet run".to_string()],
+        suggestions: ["dotnet run", "dotnet watch run", "dotnet build", "dotnet test"]
+            .map(str::to_string)
+            .to_vec(),
+    })
+}
+
+/// Ruby: `Gemfile`, com os comandos do Rails quando houver `bin/rails`
+fn detect_ruby(dir: &Path) -> Option<Stack> {
+    if !dir.join("Gemfile").exists() {
+        return None;
+    }
+    let is_rails = dir.join("bin").join("rails").exists() || dir.join("config").join("application.rb").exists();
+    let commands: Vec<String> = if is_rails {
+        ["bundle install", "bin/rails db:prepare", "bin/rails server"].map(str::to_string).to_vec()
+    } else {
+        Vec::new()
+    };
+    let mut suggestions = commands.clone();
+    if dir.join("bin").join("dev").exists() {
+        suggestions.push("bin/dev".to_string());
+    }
+    if suggestions.is_empty() {
+        suggestions.push("bundle install".to_string());
+    }
+    Some(Stack {
+        label: if is_rails { "Ruby (Rails)" } else { "Ruby" }.to_string(),
+        name: None,
+        icon: "ruby",
+        commands,
+        suggestions,
+    })
+}
+
+/// Makefile: um comando por alvo
+fn detect_make(dir: &Path) -> Option<Stack> {
+    let content = ["Makefile", "makefile", "GNUmakefile"].iter().find_map(|name| read(dir, name))?;
+    let targets = make_targets(&content);
+    Some(Stack {
+        label: "Makefile".to_string(),
+        name: None,
+        icon: "",
+        commands: Vec::new(),
+        suggestions: targets.iter().map(|target| format!("make {}", target)).collect(),
+    })
+}
+
+/// Alvos declarados em um Makefile (sem alvos especiais e padrões)
+pub fn make_targets(content: &str) -> Vec<String> {
+    let mut targets: Vec<String> = Vec::new();
+    for line in content.lines() {
+        if line.starts_with(['\t', ' ', '#', '.']) {
+            continue;
+        }
+        let Some((names, rest)) = line.split_once(':') else {
+            continue;
+        };
+        // `VAR := valor` e `VAR ::= valor` são atribuições
+        if rest.starts_with('=') || rest.starts_with(":=") || names.contains('=') {
+            continue;
+        }
+        for name in names.split_whitespace() {
+            if !name.contains(['%', '$']) && !targets.iter().any(|t| t == name) {
+                targets.push(name.to_string());
+            }
+        }
+    }
+    targets
+}
+
+/// Docker Compose: sobe o stack inteiro
+fn detect_compose(dir: &Path) -> Option<Stack> {
+    COMPOSE_FILE_NAMES.iter().find(|name| dir.join(name).exists())?;
+    Some(Stack {
+        label: "Docker Compose".to_string(),
+        name: None,
+        icon: "docker",
+        commands: vec!["docker compose up".to_string()],
+        suggestions: ["docker compose up", "docker compose up -d", "docker compose down"]
+            .map(str::to_string)
+            .to_vec(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn temp_dir(name: &str, files: &[(&str, &str)]) -> std::path::PathBuf {
+        let dir = std::env::temp_dir().join(format!("iris_detect_{}_{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        for (file, content) in files {
+            let path = dir.join(file);
+            fs::create_dir_all(path.parent().unwrap()).unwrap();
+            fs::write(path, content).unwrap();
+        }
+        dir
+    }
+
+    #[test]
+    fn test_detects_node_with_lockfile_and_makefile() {
+        let dir = temp_dir(
+            "node",
+            &[
+                ("package.json", r#"{"name":"web","scripts":{"build":"vite build","dev":"vite"},"devDependencies":{"vite":"5"}}"#),
+                ("pnpm-lock.yaml", ""),
+                ("Makefile", "PORT := 3000\n.PHONY: lint\nlint test: deps\n\techo ok\n%.o: %.c\n"),
+            ],
+        );
+        let detection = detect_project(&dir).unwrap();
+        assert_eq!(detection.stacks, vec!["Node (pnpm)", "Makefile"]);
+        assert_eq!(detection.name, "web");
+        assert_eq!(detection.icon, "vitejs");
+        assert_eq!(detection.commands, vec!["pnpm install", "pnpm dev"]);
+        assert!(detection.suggestions.contains(&"pnpm build".to_string()));
+        assert!(detection.suggestions.contains(&"make test".to_string()));
+        assert!(!detection.suggestions.iter().any(|s| s.contains("PORT")));
+        let _ = fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn test_detects_django_with_poetry_and_rust() {
+        let dir = temp_dir(
+            "python",
+            &[
+                ("pyproject.toml", "[tool.poetry]\nname = \"loja\"\n"),
+                ("manage.py", ""),
+            ],
+        );
+        let detection = detect_project(&dir).unwrap();
+        assert_eq!(detection.name, "loja");
+        assert_eq!(detection.commands[1], "poetry run python manage.py runserver");
+        let _ = fs::remove_dir_all(&dir);
+
+        let dir = temp_dir("rust", &[("Cargo.toml", "[package]\nname = \"gateway\"\n")]);
+        let detection = detect_project(&dir).unwrap();
+        assert_eq!((detection.name.as_str(), detection.icon.as_str()), ("gateway", "rust"));
+        let _ = fs::remove_dir_all(&dir);
+
+        assert!(detect_project(&std::env::temp_dir().join("iris_detect_inexistente")).is_none());
+    }
+
+    #[test]
+    fn test_scan_respects_depth_and_skips_dependencies() {
+        let dir = temp_dir(
+            "scan",
+            &[
+                ("api/go.mod", "module github.com/time/api\n"),
+                ("web/package.json", r#"{"name":"web"}"#),
+                ("web/node_modules/left-pad/package.json", r#"{"name":"left-pad"}"#),
+                ("libs/core/Cargo.toml", "[package]\nname = \"core\"\n"),
+                (".cache/Cargo.toml", "[package]\nname = \"oculto\"\n"),
+            ],
+        );
+        let names = |depth| -> Vec<String> {
+            scan_projects(&dir, depth, &AtomicBool::new(false)).projects.into_iter().map(|(_, d)| d.name).collect()
+        };
+        assert_eq!(names(1), vec!["api", "web"]);
+        assert_eq!(names(3), vec!["api", "web", "core"]);
+
+        let cancelled = scan_projects(&dir, 3, &AtomicBool::new(true));
+        assert!(cancelled.projects.is_empty() && cancelled.truncated);
+        let _ = fs::remove_dir_all(&dir);
+    }
+}
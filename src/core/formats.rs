@@ -0,0 +1,168 @@
+//! # Formatos de Arquivo
+//!
+//! Importação e exportação de configurações em JSON, TOML ou YAML.
+//!
+//! Os três formatos compartilham o mesmo esquema: o conteúdo é convertido
+//! para um valor JSON intermediário, que passa pelas migrações e pela
+//! desserialização de `AppState` como qualquer `config.json`.
+//!
+//! ## Detecção
+//! 1. Pela extensão (`.json`, `.toml`, `.yaml`/`.yml`)
+//! 2. Pelo conteúdo: `{` indica JSON; um documento TOML válido indica TOML;
+//!    o restante é tratado como YAML
+
+use std::path::Path;
+
+use serde_json::Value;
+
+use super::config::ConfigError;
+
+/// Formato de um arquivo de configuração
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub enum ConfigFormat {
+    #[default]
+    Json,
+    Toml,
+    Yaml,
+}
+
+impl ConfigFormat {
+    /// Todos os formatos suportados
+    pub const ALL: [ConfigFormat; 3] = [ConfigFormat::Json, ConfigFormat::Toml, ConfigFormat::Yaml];
+
+    /// Formato indicado pela extensão do arquivo
+    pub fn from_path(path: &Path) -> Option<Self> {
+        let extension = path.extension()?.to_str()?.to_lowercase();
+        match extension.as_str() {
+            "json" => Some(Self::Json),
+            "toml" => Some(Self::Toml),
+            "yaml" | "yml" => Some(Self::Yaml),
+            _ => None,
+        }
+    }
+
+    /// Formato deduzido do conteúdo
+    pub fn detect(content: &str) -> Self {
+        let trimmed = content.trim_start_matches('\u{feff}').trim_start();
+        if trimmed.starts_with('{') {
+            Self::Json
+        } else if toml::from_str::<toml::Table>(content).is_ok_and(|table| !table.is_empty()) {
+            Self::Toml
+        } else {
+            Self::Yaml
+        }
+    }
+
+    /// Formato de um arquivo: pela extensão ou, sem ela, pelo conteúdo
+    pub fn for_file(path: &Path, content: &str) -> Self {
+        Self::from_path(path).unwrap_or_else(|| Self::detect(content))
+    }
+
+    /// Nome para exibição
+    pub fn label(&self) -> &'static str {
+        match self {
+            Self::Json => "JSON",
+            Self::Toml => "TOML",
+            Self::Yaml => "YAML",
+        }
+    }
+
+    /// Extensões aceitas (a primeira é a padrão)
+    pub fn extensions(&self) -> &'static [&'static str] {
+        match self {
+            Self::Json => &["json"],
+            Self::Toml => &["toml"],
+            Self::Yaml => &["yaml", "yml"],
+        }
+    }
+
+    /// Converte um valor para texto neste formato.
+    ///
+    /// Campos nulos são omitidos (TOML não tem `null`; na leitura,
+    /// ausente e nulo são equivalentes).
+    pub fn serialize(&self, value: &Value) -> Result<String, String> {
+        let mut value = value.clone();
+        strip_nulls(&mut value);
+        let result = match self {
+            Self::Json => serde_json::to_string_pretty(&value).map_err(|e| e.to_string()),
+            Self::Toml => toml::to_string_pretty(&value).map_err(|e| e.to_string()),
+            Self::Yaml => serde_yaml::to_string(&value).map_err(|e| e.to_string()),
+        };
+        result.map_err(|e| format!("Erro ao gerar {}: {}", self.label(), e))
+    }
+
+    /// Lê um texto neste formato como valor JSON
+    pub fn parse(&self, content: &str) -> Result<Value, ConfigError> {
+        match self {
+            Self::Json => serde_json::from_str(content).map_err(|e| ConfigError::from_json(content, &e)),
+            Self::Toml => toml::from_str(content).map_err(|e| {
+                let (line, column) = e
+                    .span()
+                    .map(|span| line_column(content, span.start))
+                    .unwrap_or_default();
+                ConfigError::at(format!("Erro ao processar TOML: {}", e.message()), content, line, column)
+            }),
+            Self::Yaml => serde_yaml::from_str(content).map_err(|e| {
+                let (line, column) = e
+                    .location()
+                    .map(|location| (location.line(), location.column()))
+                    .unwrap_or_default();
+                ConfigError::at(format!("Erro ao processar YAML: {}", e), content, line, column)
+            }),
+        }
+    }
+}
+
+/// Linha e coluna (a partir de 1) de uma posição em bytes
+fn line_column(content: &str, offset: usize) -> (usize, usize) {
+    let before = &content[..offset.min(content.len())];
+    let line = before.matches('\n').count() + 1;
+    let column = before.rsplit('\n').next().map_or(0, |text| text.chars().count()) + 1;
+    (line, column)
+}
+
+/// Remove recursivamente os campos nulos de objetos
+fn strip_nulls(value: &mut Value) {
+    match value {
+        Value::Object(map) => {
+            map.retain(|_, item| !item.is_null());
+            map.values_mut().for_each(strip_nulls);
+        }
+        Value::Array(items) => items.iter_mut().for_each(strip_nulls),
+        _ => {}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::config::parse_state_as;
+    use crate::core::models::AppState;
+
+    #[test]
+    fn test_round_trip_in_every_format() {
+        let (state, _) = parse_state_as(include_str!("../../tests/fixtures/config_v1.json"), ConfigFormat::Json)
+            .expect("fixture válida");
+        let value = serde_json::to_value(&state).unwrap();
+
+        for format in ConfigFormat::ALL {
+            let text = format.serialize(&value).unwrap();
+            assert_eq!(ConfigFormat::detect(&text), format, "detecção de {}", format.label());
+            let (parsed, _): (AppState, u32) = parse_state_as(&text, format)
+                .unwrap_or_else(|e| panic!("{}: {}", format.label(), e));
+            assert_eq!(parsed, state, "ida e volta em {}", format.label());
+        }
+    }
+
+    #[test]
+    fn test_detects_by_extension_and_reports_positions() {
+        assert_eq!(ConfigFormat::from_path(Path::new("team.YML")), Some(ConfigFormat::Yaml));
+        assert_eq!(ConfigFormat::from_path(Path::new("iris")), None);
+        assert_eq!(ConfigFormat::for_file(Path::new("iris"), "apps = []\nschema_version = 1"), ConfigFormat::Toml);
+
+        let error = ConfigFormat::Toml.parse("schema_version = 1\napps = [\n").unwrap_err();
+        assert_eq!(error.location.map(|(line, _)| line), Some(3));
+        let error = ConfigFormat::Yaml.parse("apps:\n  - name: [\n").unwrap_err();
+        assert!(error.location.is_some());
+    }
+}
@@ -0,0 +1,329 @@
+//! # Histórico de Configurações
+//!
+//! Cada versão salva do `config.json` é guardada na pasta `history`,
+//! endereçada pelo conteúdo (SHA-256): versões idênticas ocupam um
+//! único arquivo.
+//!
+//! ## Estrutura
+//! ```text
+//! history/
+//! ├── index.json           # versões em ordem (hash + momento)
+//! └── objects/<sha256>.json
+//! ```
+//!
+//! Também calcula diferenças estruturais por aplicação entre duas versões.
+
+use std::fs;
+use std::path::PathBuf;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use sha2::{Digest, Sha256};
+
+use super::config::{parse_state, write_atomic, ConfigError};
+use super::models::{AppConfig, AppState};
+
+/// Quantidade máxima de versões mantidas
+pub const MAX_HISTORY: usize = 200;
+
+/// Versão registrada no histórico
+#[derive(Clone, Serialize, Deserialize, Debug, PartialEq)]
+pub struct HistoryEntry {
+    /// SHA-256 do conteúdo (nome do arquivo em `objects`)
+    pub hash: String,
+    /// Momento em que foi salva (segundos desde UNIX epoch)
+    pub saved_at: u64,
+    /// Quantidade de aplicações nesta versão
+    pub app_count: usize,
+}
+
+/// Histórico de versões salvas em uma pasta
+pub struct ConfigHistory {
+    /// Pasta do histórico
+    dir: PathBuf,
+}
+
+impl ConfigHistory {
+    /// Cria o histórico na pasta informada
+    pub fn new(dir: PathBuf) -> Self {
+        Self { dir }
+    }
+
+    /// Versões registradas, da mais antiga para a mais recente
+    pub fn entries(&self) -> Vec<HistoryEntry> {
+        fs::read_to_string(self.dir.join("index.json"))
+            .ok()
+            .and_then(|content| serde_json::from_str(&content).ok())
+            .unwrap_or_default()
+    }
+
+    /// Registra uma versão do arquivo de configuração.
+    ///
+    /// Nada é registrado se o conteúdo for igual ao da última versão.
+    ///
+    /// # Retorno
+    /// O hash da nova versão, ou `None` se não houve mudança.
+    pub fn record(&self, content: &str, app_count: usize) -> Result<Option<String>, String> {
+        let hash = content_hash(content);
+        let mut entries = self.entries();
+        if entries.last().is_some_and(|last| last.hash == hash) {
+            return Ok(None);
+        }
+
+        let objects = self.dir.join("objects");
+        fs::create_dir_all(&objects).map_err(|e| format!("Erro ao criar pasta do histórico: {}", e))?;
+        let object = objects.join(format!("{}.json", hash));
+        if !object.exists() {
+            write_atomic(&object, content)?;
+        }
+
+        entries.push(HistoryEntry {
+            hash: hash.clone(),
+            saved_at: SystemTime::now().duration_since(UNIX_EPOCH).unwrap_or_default().as_secs(),
+            app_count,
+        });
+        if entries.len() > MAX_HISTORY {
+            let removed: Vec<HistoryEntry> = entries.drain(..entries.len() - MAX_HISTORY).collect();
+            for entry in removed {
+                if !entries.iter().any(|e| e.hash == entry.hash) {
+                    let _ = fs::remove_file(objects.join(format!("{}.json", entry.hash)));
+                }
+            }
+        }
+        self.save_index(&entries)?;
+        Ok(Some(hash))
+    }
+
+    /// Carrega o estado de uma versão (migrando-o, se for antiga)
+    pub fn load(&self, hash: &str) -> Result<AppState, ConfigError> {
+        let path = self.dir.join("objects").join(format!("{}.json", hash));
+        let content = fs::read_to_string(path)
+            .map_err(|e| ConfigError::new(format!("Versão {} não encontrada: {}", short_hash(hash), e)))?;
+        parse_state(&content).map(|(state, _)| state)
+    }
+
+    /// Grava o índice de versões
+    fn save_index(&self, entries: &[HistoryEntry]) -> Result<(), String> {
+        let json = serde_json::to_string_pretty(entries)
+            .map_err(|e| format!("Erro ao serializar histórico: {}", e))?;
+        write_atomic(&self.dir.join("index.json"), &json)
+    }
+}
+
+/// SHA-256 do conteúdo em hexadecimal
+pub fn content_hash(content: &str) -> String {
+    Sha256::digest(content.as_bytes())
+        .iter()
+        .map(|byte| format!("{:02x}", byte))
+        .collect()
+}
+
+/// Primeiros caracteres do hash, para exibição
+pub fn short_hash(hash: &str) -> &str {
+    &hash[..hash.len().min(8)]
+}
+
+/// Tipo de diferença de uma aplicação entre duas versões
+#[derive(Clone, Debug, PartialEq)]
+pub enum AppDiffKind {
+    /// Existe apenas na versão nova
+    Added,
+    /// Existe apenas na versão antiga
+    Removed,
+    /// Existe nas duas, com os campos alterados
+    Modified(Vec<FieldChange>),
+}
+
+/// Campo alterado de uma aplicação
+#[derive(Clone, Debug, PartialEq)]
+pub struct FieldChange {
+    /// Caminho do campo (ex: `commands[1]`, `watch.enabled`)
+    pub path: String,
+    /// Valor na versão antiga (JSON), `None` se não existia
+    pub old: Option<String>,
+    /// Valor na versão nova (JSON), `None` se foi removido
+    pub new: Option<String>,
+}
+
+/// Diferença de uma aplicação entre duas versões
+#[derive(Clone, Debug, PartialEq)]
+pub struct AppDiff {
+    /// ID da aplicação
+    pub app_id: String,
+    /// Nome da aplicação (da versão nova, se existir)
+    pub name: String,
+    /// Tipo de diferença
+    pub kind: AppDiffKind,
+}
+
+/// Compara as aplicações de duas versões, associando-as pelo ID.
+///
+/// Aplicações sem mudanças não aparecem no resultado.
+///
+/// # Exemplo
+/// ```rust
+/// use iris::core::{diff_states, AppConfig, AppDiffKind, AppState};
+///
+/// let mut old = AppState::default();
+/// old.add_app(AppConfig { id: "1".into(), name: "API".into(), ..Default::default() });
+/// let mut new = AppState::default();
+/// new.add_app(AppConfig { id: "1".into(), name: "API v2".into(), ..Default::default() });
+///
+/// let diff = diff_states(&old, &new);
+/// let AppDiffKind::Modified(changes) = &diff[0].kind else { panic!() };
+/// assert_eq!(changes[0].path, "name");
+/// ```
+pub fn diff_states(old: &AppState, new: &AppState) -> Vec<AppDiff> {
+    let mut diffs = Vec::new();
+
+    for app in &new.apps {
+        match old.apps.iter().find(|a| a.id == app.id) {
+            None => diffs.push(AppDiff {
+                app_id: app.id.clone(),
+                name: app.name.clone(),
+                kind: AppDiffKind::Added,
+            }),
+            Some(previous) => {
+                let changes = diff_apps(previous, app);
+                if !changes.is_empty() {
+                    diffs.push(AppDiff {
+                        app_id: app.id.clone(),
+                        name: app.name.clone(),
+                        kind: AppDiffKind::Modified(changes),
+                    });
+                }
+            }
+        }
+    }
+    for app in &old.apps {
+        if !new.apps.iter().any(|a| a.id == app.id) {
+            diffs.push(AppDiff {
+                app_id: app.id.clone(),
+                name: app.name.clone(),
+                kind: AppDiffKind::Removed,
+            });
+        }
+    }
+    diffs
+}
+
+/// Campos alterados entre duas versões de uma aplicação
+pub fn diff_apps(old: &AppConfig, new: &AppConfig) -> Vec<FieldChange> {
+    let mut changes = Vec::new();
+    diff_values("", &to_value(old), &to_value(new), &mut changes);
+    changes
+}
+
+/// Converte uma aplicação para JSON
+fn to_value(app: &AppConfig) -> Value {
+    serde_json::to_value(app).unwrap_or(Value::Null)
+}
+
+/// Compara dois valores JSON recursivamente, acumulando os campos alterados
+fn diff_values(path: &str, old: &Value, new: &Value, changes: &mut Vec<FieldChange>) {
+    match (old, new) {
+        (Value::Object(old_map), Value::Object(new_map)) => {
+            for (key, old_value) in old_map {
+                let child = join_path(path, key);
+                match new_map.get(key) {
+                    Some(new_value) => diff_values(&child, old_value, new_value, changes),
+                    None => changes.push(FieldChange { path: child, old: Some(old_value.to_string()), new: None }),
+                }
+            }
+            for (key, new_value) in new_map {
+                if !old_map.contains_key(key) {
+                    changes.push(FieldChange {
+                        path: join_path(path, key),
+                        old: None,
+                        new: Some(new_value.to_string()),
+                    });
+                }
+            }
+        }
+        (Value::Array(old_items), Value::Array(new_items)) => {
+            for i in 0..old_items.len().max(new_items.len()) {
+                let child = format!("{}[{}]", path, i);
+                match (old_items.get(i), new_items.get(i)) {
+                    (Some(o), Some(n)) => diff_values(&child, o, n, changes),
+                    (o, n) => changes.push(FieldChange {
+                        path: child,
+                        old: o.map(Value::to_string),
+                        new: n.map(Value::to_string),
+                    }),
+                }
+            }
+        }
+        _ if old != new => changes.push(FieldChange {
+            path: path.to_string(),
+            old: Some(old.to_string()),
+            new: Some(new.to_string()),
+        }),
+        _ => {}
+    }
+}
+
+/// Junta um caminho de campo com a próxima chave
+fn join_path(path: &str, key: &str) -> String {
+    if path.is_empty() {
+        key.to_string()
+    } else {
+        format!("{}.{}", path, key)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_record_deduplicates_and_loads_snapshots() {
+        let dir = std::env::temp_dir().join(format!("iris_history_{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        let history = ConfigHistory::new(dir.clone());
+
+        let v1 = r#"{"schema_version":1,"apps":[]}"#;
+        let v2 = r#"{"schema_version":1,"apps":[{"id":"1","name":"API","icon_emoji":"","working_dir":"","commands":[]}]}"#;
+        let first = history.record(v1, 0).unwrap().unwrap();
+        assert!(history.record(v1, 0).unwrap().is_none());
+        history.record(v2, 1).unwrap();
+        history.record(v1, 0).unwrap();
+
+        let entries = history.entries();
+        assert_eq!(entries.len(), 3);
+        assert_eq!(entries[2].hash, first);
+        assert_eq!(fs::read_dir(dir.join("objects")).unwrap().count(), 2);
+        assert_eq!(history.load(&entries[1].hash).unwrap().apps[0].name, "API");
+        let _ = fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn test_diff_reports_added_removed_and_field_paths() {
+        let app = |id: &str, commands: &[&str]| AppConfig {
+            id: id.to_string(),
+            name: format!("app {}", id),
+            commands: commands.iter().map(|c| c.to_string()).collect(),
+            ..Default::default()
+        };
+        let old = AppState {
+            apps: vec![app("1", &["npm i", "npm start"]), app("2", &[])],
+            ..Default::default()
+        };
+        let mut new = AppState {
+            apps: vec![app("1", &["npm i", "npm run dev"]), app("3", &[])],
+            ..Default::default()
+        };
+        new.apps[0].watch.enabled = true;
+
+        let diffs = diff_states(&old, &new);
+        assert_eq!(diffs.len(), 3);
+        let AppDiffKind::Modified(changes) = &diffs[0].kind else {
+            panic!("esperava alteração");
+        };
+        let paths: Vec<&str> = changes.iter().map(|c| c.path.as_str()).collect();
+        assert_eq!(paths, vec!["commands[1]", "watch.enabled"]);
+        assert_eq!(changes[0].new.as_deref(), Some("\"npm run dev\""));
+        assert_eq!(diffs[1].kind, AppDiffKind::Added);
+        assert_eq!(diffs[2].kind, AppDiffKind::Removed);
+    }
+}
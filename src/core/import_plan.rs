@@ -0,0 +1,292 @@
+//! # Plano de Importação
+//!
+//! Associa as aplicações de um arquivo importado às já existentes (mesmo
+//! nome e mesma pasta) e aplica a estratégia escolhida para cada uma:
+//! adicionar, ignorar, sobrescrever ou mesclar.
+//!
+//! ## Mesclagem
+//! Os campos do arquivo importado prevalecem, mas o que só existe
+//! localmente é mantido: variáveis de ambiente, ações, perfis e
+//! parâmetros são unidos pelo nome, e campos vazios no arquivo não
+//! apagam os locais.
+
+use serde_json::Value;
+
+use super::history::{diff_apps, FieldChange};
+use super::models::AppConfig;
+
+/// O que fazer com uma aplicação importada
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ImportStrategy {
+    /// Adicionar como nova aplicação
+    Add,
+    /// Não importar
+    Skip,
+    /// Substituir a aplicação existente
+    Overwrite,
+    /// Mesclar com a aplicação existente
+    Merge,
+}
+
+impl ImportStrategy {
+    /// Estratégias disponíveis para aplicações que já existem
+    pub const ALL: [ImportStrategy; 4] = [Self::Add, Self::Skip, Self::Overwrite, Self::Merge];
+
+    /// Nome exibido no diálogo
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Add => "Adicionar",
+            Self::Skip => "Ignorar",
+            Self::Overwrite => "Sobrescrever",
+            Self::Merge => "Mesclar",
+        }
+    }
+}
+
+/// Aplicação importada e sua correspondente local
+#[derive(Clone, Debug)]
+pub struct ImportItem {
+    /// Aplicação do arquivo importado
+    pub incoming: AppConfig,
+    /// Aplicação existente com o mesmo nome e pasta
+    pub existing: Option<AppConfig>,
+    /// Estratégia escolhida
+    pub strategy: ImportStrategy,
+}
+
+impl ImportItem {
+    /// Aplicação resultante da estratégia (None = nada muda na lista)
+    pub fn resolved(&self) -> Option<AppConfig> {
+        match (self.strategy, &self.existing) {
+            (ImportStrategy::Skip, _) => None,
+            (ImportStrategy::Add, _) | (_, None) => Some(self.incoming.clone()),
+            (ImportStrategy::Overwrite, Some(existing)) => Some(AppConfig {
+                id: existing.id.clone(),
+                ..self.incoming.clone()
+            }),
+            (ImportStrategy::Merge, Some(existing)) => Some(merge_app(existing, &self.incoming)),
+        }
+    }
+
+    /// Campos da aplicação existente alterados pela estratégia escolhida
+    pub fn changes(&self) -> Vec<FieldChange> {
+        match (self.strategy, &self.existing) {
+            (ImportStrategy::Overwrite | ImportStrategy::Merge, Some(existing)) => self
+                .resolved()
+                .map(|app| diff_apps(existing, &app))
+                .unwrap_or_default(),
+            _ => Vec::new(),
+        }
+    }
+}
+
+/// Quantas aplicações foram afetadas por estratégia
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct ImportSummary {
+    pub added: usize,
+    pub skipped: usize,
+    pub overwritten: usize,
+    pub merged: usize,
+}
+
+impl ImportSummary {
+    /// Total de aplicações adicionadas ou alteradas
+    pub fn applied(&self) -> usize {
+        self.added + self.overwritten + self.merged
+    }
+}
+
+/// Chave de comparação de um nome ou pasta (sem diferenciar maiúsculas,
+/// separadores ou barra final)
+fn match_key(text: &str) -> String {
+    text.trim()
+        .replace('\\', "/")
+        .trim_end_matches('/')
+        .to_lowercase()
+}
+
+/// Associa as aplicações importadas às existentes.
+///
+/// Cada aplicação existente é associada a no máximo uma importada. Por
+/// padrão, novas aplicações são adicionadas, as idênticas às locais são
+/// ignoradas e as diferentes são mescladas.
+///
+/// # Exemplo
+/// ```rust
+/// use iris::core::{plan_import, AppConfig, ImportStrategy};
+///
+/// let local = vec![AppConfig { working_dir: "C:\\api".into(), ..AppConfig::new("API".into()) }];
+/// let incoming = vec![
+///     AppConfig { working_dir: "C:\\api\\".into(), port: Some(8080), ..AppConfig::new("api".into()) },
+///     AppConfig::new("Web".into()),
+/// ];
+///
+/// let plan = plan_import(&local, incoming);
+/// assert_eq!(plan[0].strategy, ImportStrategy::Merge);
+/// assert_eq!(plan[1].strategy, ImportStrategy::Add);
+/// ```
+pub fn plan_import(current: &[AppConfig], incoming: Vec<AppConfig>) -> Vec<ImportItem> {
+    let mut matched: Vec<&str> = Vec::new();
+    incoming
+        .into_iter()
+        .map(|app| {
+            let existing = current
+                .iter()
+                .find(|local| {
+                    !matched.contains(&local.id.as_str())
+                        && match_key(&local.name) == match_key(&app.name)
+                        && match_key(&local.working_dir) == match_key(&app.working_dir)
+                })
+                .inspect(|local| matched.push(&local.id))
+                .cloned();
+            let mut item = ImportItem {
+                incoming: app,
+                existing,
+                strategy: ImportStrategy::Add,
+            };
+            if item.existing.is_some() {
+                item.strategy = ImportStrategy::Merge;
+                if item.changes().is_empty() {
+                    item.strategy = ImportStrategy::Skip;
+                }
+            }
+            item
+        })
+        .collect()
+}
+
+/// Aplica o plano à lista de aplicações.
+///
+/// Aplicações sobrescritas ou mescladas mantêm o ID e a posição na lista.
+pub fn apply_import(apps: &mut Vec<AppConfig>, plan: &[ImportItem]) -> ImportSummary {
+    let mut summary = ImportSummary::default();
+    for item in plan {
+        let Some(app) = item.resolved() else {
+            summary.skipped += 1;
+            continue;
+        };
+        let target = item
+            .existing
+            .as_ref()
+            .filter(|_| item.strategy != ImportStrategy::Add)
+            .and_then(|existing| apps.iter_mut().find(|a| a.id == existing.id));
+        match target {
+            Some(target) => {
+                *target = app;
+                match item.strategy {
+                    ImportStrategy::Overwrite => summary.overwritten += 1,
+                    _ => summary.merged += 1,
+                }
+            }
+            None => {
+                apps.push(app);
+                summary.added += 1;
+            }
+        }
+    }
+    summary
+}
+
+/// Mescla uma aplicação importada com a local (ver documentação do módulo)
+fn merge_app(existing: &AppConfig, incoming: &AppConfig) -> AppConfig {
+    let mut merged = serde_json::to_value(existing).unwrap_or(Value::Null);
+    merge_values(&mut merged, serde_json::to_value(incoming).unwrap_or(Value::Null));
+    let mut app: AppConfig = serde_json::from_value(merged).unwrap_or_else(|_| incoming.clone());
+    app.id = existing.id.clone();
+    app
+}
+
+/// Nome que identifica um item de lista (`key` das variáveis, `name` das
+/// ações, perfis e parâmetros)
+fn item_key(value: &Value) -> Option<&str> {
+    value.get("key").or_else(|| value.get("name")).and_then(Value::as_str)
+}
+
+/// Mescla `incoming` sobre `base` recursivamente
+fn merge_values(base: &mut Value, incoming: Value) {
+    match (base, incoming) {
+        (_, Value::Null) => {}
+        (Value::Object(base), Value::Object(incoming)) => {
+            for (key, value) in incoming {
+                match base.get_mut(&key) {
+                    Some(slot) => merge_values(slot, value),
+                    None => {
+                        base.insert(key, value);
+                    }
+                }
+            }
+        }
+        (Value::Array(base), Value::Array(incoming)) if incoming.iter().chain(base.iter()).all(|v| item_key(v).is_some()) => {
+            for value in incoming {
+                let key = item_key(&value).map(str::to_string);
+                match base.iter_mut().find(|v| item_key(v).map(str::to_string) == key) {
+                    Some(slot) => *slot = value,
+                    None => base.push(value),
+                }
+            }
+        }
+        (Value::String(_) | Value::Array(_), Value::String(text)) if text.is_empty() => {}
+        (Value::Array(_), Value::Array(items)) if items.is_empty() => {}
+        (base, incoming) => *base = incoming,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::models::EnvVar;
+
+    fn env(key: &str, value: &str) -> EnvVar {
+        EnvVar {
+            key: key.to_string(),
+            value: value.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_merge_keeps_local_additions() {
+        let local = AppConfig {
+            commands: vec!["npm start".into()],
+            env: vec![env("DEBUG", "1"), env("PORT", "3000")],
+            group: Some("web".into()),
+            ..AppConfig::new("Web".into())
+        };
+        let incoming = AppConfig {
+            commands: vec!["npm run dev".into()],
+            env: vec![env("PORT", "4000")],
+            ..AppConfig::new("Web".into())
+        };
+        let plan = plan_import(std::slice::from_ref(&local), vec![incoming]);
+        let merged = plan[0].resolved().unwrap();
+        assert_eq!(merged.id, local.id);
+        assert_eq!(merged.commands, vec!["npm run dev"]);
+        assert_eq!(merged.env, vec![env("DEBUG", "1"), env("PORT", "4000")]);
+        assert_eq!(merged.group.as_deref(), Some("web"));
+
+        let paths: Vec<String> = plan[0].changes().into_iter().map(|c| c.path).collect();
+        assert_eq!(paths, vec!["commands[0]", "env[1].value"]);
+    }
+
+    #[test]
+    fn test_apply_is_idempotent_for_the_same_file() {
+        let file = vec![
+            AppConfig { working_dir: "/srv/api".into(), ..AppConfig::new("API".into()) },
+            AppConfig::new("Web".into()),
+        ];
+        let mut apps = Vec::new();
+        let plan = plan_import(&apps, file.clone());
+        let first = apply_import(&mut apps, &plan);
+        assert_eq!(first.added, 2);
+
+        let reimported = file.into_iter().map(|app| AppConfig { id: "novo".into(), ..app }).collect();
+        let plan = plan_import(&apps, reimported);
+        assert!(plan.iter().all(|item| item.strategy == ImportStrategy::Skip));
+        let second = apply_import(&mut apps, &plan);
+        assert_eq!((second.skipped, apps.len()), (2, 2));
+
+        let mut plan = plan_import(&apps, vec![AppConfig { port: Some(9000), ..AppConfig::new("API".into()) }]);
+        assert_eq!(plan[0].strategy, ImportStrategy::Add);
+        plan[0].strategy = ImportStrategy::Skip;
+        assert_eq!(apply_import(&mut apps, &plan).applied(), 0);
+    }
+}
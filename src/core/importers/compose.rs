@@ -0,0 +1,172 @@
+//! # Importador de Docker Compose
+//!
+//! Lê um `docker-compose.yml` / `compose.yaml` e propõe um app por
+//! serviço (`docker compose up <serviço>`) ou um único app para o stack.
+//!
+//! - A porta vem do primeiro mapeamento de `ports:` com porta no host
+//! - Os serviços são ordenados pelas dependências (`depends_on:`), de
+//!   forma que cada um venha depois daqueles de que depende
+
+use std::fs;
+use std::path::Path;
+
+use serde_yaml::Value;
+
+use super::{dependency_order, folder_name, ImportProposal, ImportVariant};
+use crate::core::models::AppConfig;
+
+/// Nomes de arquivo que o `docker compose` encontra sem `-f`
+pub const COMPOSE_FILE_NAMES: [&str; 4] = ["compose.yaml", "compose.yml", "docker-compose.yml", "docker-compose.yaml"];
+
+/// Ícone usado nas aplicações importadas
+const DOCKER_ICON: &str = "docker";
+
+/// Serviço declarado no arquivo
+#[derive(Clone, Debug, PartialEq)]
+pub struct ComposeService {
+    /// Nome do serviço
+    pub name: String,
+    /// Primeira porta publicada no host
+    pub port: Option<u16>,
+    /// Serviços dos quais este depende
+    pub depends_on: Vec<String>,
+}
+
+/// Lê os serviços de um arquivo compose, já em ordem de dependência
+pub fn parse_compose(content: &str) -> Result<Vec<ComposeService>, String> {
+    // serde_yaml mantém a ordem das chaves (a ordem dos serviços importa)
+    let value: Value = serde_yaml::from_str(content).map_err(|e| format!("Erro ao processar YAML: {}", e))?;
+    let services = value
+        .get("services")
+        .and_then(Value::as_mapping)
+        .ok_or("O arquivo não tem a seção \"services\"")?;
+
+    let services: Vec<ComposeService> = services
+        .iter()
+        .filter_map(|(name, service)| {
+            Some(ComposeService {
+                name: name.as_str()?.to_string(),
+                port: service
+                    .get("ports")
+                    .and_then(Value::as_sequence)
+                    .and_then(|ports| ports.iter().find_map(host_port)),
+                depends_on: match service.get("depends_on") {
+                    Some(Value::Sequence(names)) => names.iter().filter_map(Value::as_str).map(str::to_string).collect(),
+                    Some(Value::Mapping(map)) => map.keys().filter_map(Value::as_str).map(str::to_string).collect(),
+                    _ => Vec::new(),
+                },
+            })
+        })
+        .collect();
+    Ok(dependency_order(services, |s| &s.name, |s| &s.depends_on))
+}
+
+/// Porta no host de uma entrada de `ports:`.
+///
+/// Formatos: `"8080:80"`, `"127.0.0.1:8080:80/tcp"`, `"8080-8081:80-81"`
+/// e a sintaxe longa (`published`). Portas apenas do contêiner (`"80"`)
+/// são publicadas em portas aleatórias e por isso ignoradas.
+fn host_port(entry: &Value) -> Option<u16> {
+    match entry {
+        Value::String(text) => {
+            let text = text.split('/').next().unwrap_or_default();
+            let parts: Vec<&str> = text.rsplitn(3, ':').collect();
+            // rsplitn: [contêiner, host, ip]
+            let host = parts.get(1)?;
+            host.split('-').next()?.trim().parse().ok()
+        }
+        Value::Mapping(map) => match map.get("published")? {
+            Value::Number(port) => port.as_u64().and_then(|port| u16::try_from(port).ok()),
+            Value::String(port) => port.split('-').next()?.trim().parse().ok(),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+/// Propõe aplicações a partir de um arquivo compose.
+///
+/// # Retorno
+/// Duas alternativas: um app por serviço (padrão) ou um app para o stack.
+pub fn propose(path: &Path) -> Result<ImportProposal, String> {
+    let content = fs::read_to_string(path)
+        .map_err(|e| format!("Erro ao ler \"{}\": {}", path.display(), e))?;
+    let services = parse_compose(&content)?;
+    if services.is_empty() {
+        return Err("O arquivo não declara nenhum serviço".to_string());
+    }
+
+    let dir = path.parent().unwrap_or(Path::new("."));
+    let file_name = path.file_name().map(|name| name.to_string_lossy().to_string()).unwrap_or_default();
+    let compose = if COMPOSE_FILE_NAMES.contains(&file_name.as_str()) {
+        "docker compose".to_string()
+    } else {
+        format!("docker compose -f {}", file_name)
+    };
+    let new_app = |name: String, command: String, port: Option<u16>| AppConfig {
+        icon_emoji: DOCKER_ICON.to_string(),
+        working_dir: dir.display().to_string(),
+        commands: vec![command],
+        port,
+        ..AppConfig::new(name)
+    };
+
+    let per_service = services
+        .iter()
+        .map(|service| new_app(service.name.clone(), format!("{} up {}", compose, service.name), service.port))
+        .collect();
+    let stack = new_app(
+        folder_name(dir),
+        format!("{} up", compose),
+        services.iter().find_map(|service| service.port),
+    );
+
+    Ok(ImportProposal {
+        source: path.display().to_string(),
+        warnings: Vec::new(),
+        variants: vec![
+            ImportVariant {
+                label: format!("Um app por serviço ({})", services.len()),
+                apps: per_service,
+            },
+            ImportVariant {
+                label: "Um app para o stack inteiro".to_string(),
+                apps: vec![stack],
+            },
+        ],
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parses_ports_and_dependency_order() {
+        let services = parse_compose(include_str!("../../../tests/fixtures/docker-compose.yml")).unwrap();
+        let names: Vec<&str> = services.iter().map(|s| s.name.as_str()).collect();
+        assert_eq!(names, vec!["db", "worker", "cache", "api", "web"]);
+
+        let port = |name: &str| services.iter().find(|s| s.name == name).unwrap().port;
+        assert_eq!(port("web"), Some(3000));
+        assert_eq!(port("api"), Some(8080));
+        assert_eq!(port("db"), Some(5433));
+        assert_eq!(port("cache"), None);
+        assert_eq!(port("worker"), None);
+    }
+
+    #[test]
+    fn test_proposes_service_and_stack_variants() {
+        let dir = std::env::temp_dir().join(format!("iris_compose_{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("compose.dev.yml");
+        fs::write(&path, "services:\n  a:\n    image: x\n    depends_on: [b]\n  b:\n    image: y\n").unwrap();
+
+        let proposal = propose(&path).unwrap();
+        let services = &proposal.variants[0].apps;
+        assert_eq!(services[0].commands, vec!["docker compose -f compose.dev.yml up b"]);
+        assert_eq!(services[1].icon_emoji, "docker");
+        assert_eq!(proposal.variants[1].apps[0].commands, vec!["docker compose -f compose.dev.yml up"]);
+        let _ = fs::remove_dir_all(&dir);
+    }
+}
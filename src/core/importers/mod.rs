@@ -0,0 +1,99 @@
+//! # Importadores
+//!
+//! Convertem arquivos de outras ferramentas em propostas de aplicações,
+//! revisadas pelo usuário antes de serem adicionadas.
+//!
+//! ## Formatos
+//! - `compose`: `docker-compose.yml` / `compose.yaml`
+//! - `procfile`: `Procfile` / `Procfile.dev` (foreman, overmind, `bin/dev`)
+//! - `vscode`: `.vscode/tasks.json`
+
+pub mod compose;
+pub mod procfile;
+pub mod vscode;
+
+use std::path::Path;
+
+use super::models::AppConfig;
+
+/// Aplicações propostas por um importador
+#[derive(Clone, Debug)]
+pub struct ImportProposal {
+    /// Arquivo de origem, para exibição
+    pub source: String,
+    /// Formas alternativas de importar (a primeira é a padrão)
+    pub variants: Vec<ImportVariant>,
+    /// Avisos para a revisão (ex: recursos sem equivalente no Iris)
+    pub warnings: Vec<String>,
+}
+
+/// Uma forma de importar o arquivo (ex: um app por serviço)
+#[derive(Clone, Debug)]
+pub struct ImportVariant {
+    /// Descrição da alternativa
+    pub label: String,
+    /// Aplicações propostas, em ordem de execução
+    pub apps: Vec<AppConfig>,
+}
+
+/// Nome da pasta (última parte do caminho), usado como nome padrão
+pub(crate) fn folder_name(dir: &Path) -> String {
+    dir.file_name()
+        .map(|name| name.to_string_lossy().to_string())
+        .unwrap_or_else(|| dir.display().to_string())
+}
+
+/// Escapa `${` como `$${`, para que o texto importado chegue ao shell sem
+/// ser tratado como variável do Iris (ex: `PORT=${PORT} node server.js`)
+pub(crate) fn escape_placeholders(text: &str) -> String {
+    text.replace("${", "$${")
+}
+
+/// Ordena os itens para que as dependências venham antes.
+///
+/// Entre os itens prontos, a ordem original é mantida; dependências
+/// circulares ou inexistentes não impedem a importação.
+pub(crate) fn dependency_order<T>(
+    mut pending: Vec<T>,
+    name: impl Fn(&T) -> &String,
+    depends_on: impl Fn(&T) -> &Vec<String>,
+) -> Vec<T> {
+    let mut ordered: Vec<T> = Vec::with_capacity(pending.len());
+    while !pending.is_empty() {
+        let is_ready = |item: &T| {
+            depends_on(item).iter().all(|dep| {
+                ordered.iter().any(|i| name(i) == dep) || !pending.iter().any(|i| name(i) == dep)
+            })
+        };
+        // Em um ciclo, nenhum está pronto: segue a ordem original
+        let index = pending.iter().position(is_ready).unwrap_or(0);
+        ordered.push(pending.remove(index));
+    }
+    ordered
+}
+
+/// Ícone provável pelo programa executado no comando (vazio se desconhecido)
+pub(crate) fn guess_icon(command: &str) -> &'static str {
+    let program = command
+        .split_whitespace()
+        .find(|word| !word.contains('=') && *word != "exec" && *word != "env")
+        .unwrap_or_default();
+    let program = program.rsplit('/').next().unwrap_or(program);
+    match program {
+        "bundle" | "rails" | "ruby" | "rake" | "puma" | "sidekiq" | "foreman" => "ruby",
+        "npm" | "npx" | "node" | "nodemon" => "nodejs",
+        "yarn" => "yarn",
+        "pnpm" => "pnpm",
+        "bun" => "bun",
+        "python" | "python3" | "gunicorn" | "uvicorn" | "celery" | "manage.py" => "python",
+        "flask" => "flask",
+        "cargo" => "rust",
+        "go" => "go",
+        "php" | "artisan" => "php",
+        "java" | "mvn" | "mvnw" => "java",
+        "gradle" | "gradlew" => "gradle",
+        "dotnet" => "dotnetcore",
+        "docker" | "docker-compose" => "docker",
+        _ => "",
+    }
+}
@@ -0,0 +1,228 @@
+//! # Importador de Procfile
+//!
+//! Lê um `Procfile` / `Procfile.dev` (formato do Heroku, usado por
+//! foreman, overmind e pelo `bin/dev` do Rails) e propõe um app por
+//! processo, executado na pasta do arquivo.
+//!
+//! ## Convenções seguidas
+//! - Linhas `nome: comando`; comentários (`#`) e linhas inválidas são ignorados
+//! - Variáveis de `.env` e `.overmind.env` na mesma pasta são aplicadas a
+//!   todos os processos
+//! - Como no foreman, cada processo recebe a porta `PORT + 100 × posição`
+//!   (base 5000, ou o `PORT` do `.env`); ela só é configurada nos
+//!   processos cujo comando usa `$PORT`
+//! - `${VAR}` de comandos e do `.env` é escapado como `$${VAR}`: quem
+//!   expande é o shell, não o Iris
+
+use std::fs;
+use std::path::Path;
+
+use super::{escape_placeholders, folder_name, guess_icon, ImportProposal, ImportVariant};
+use crate::core::models::{AppConfig, EnvVar};
+
+/// Arquivos de variáveis lidos junto com o Procfile (os últimos prevalecem)
+pub const ENV_FILE_NAMES: [&str; 2] = [".env", ".overmind.env"];
+
+/// Porta base do foreman quando `PORT` não está definido
+const BASE_PORT: u16 = 5000;
+
+/// Processo declarado no Procfile
+#[derive(Clone, Debug, PartialEq)]
+pub struct ProcfileEntry {
+    /// Nome do processo (ex: `web`)
+    pub name: String,
+    /// Comando executado
+    pub command: String,
+}
+
+/// Escapa os `${...}` dos valores lidos de um `.env`
+fn escape_env(env: Vec<EnvVar>) -> Vec<EnvVar> {
+    env.into_iter()
+        .map(|var| EnvVar { value: escape_placeholders(&var.value), ..var })
+        .collect()
+}
+
+/// Lê os processos de um Procfile, na ordem do arquivo.
+///
+/// Nomes repetidos mantêm apenas a primeira ocorrência.
+pub fn parse_procfile(content: &str) -> Vec<ProcfileEntry> {
+    let mut entries: Vec<ProcfileEntry> = Vec::new();
+    for line in content.lines().map(str::trim) {
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        let Some((name, command)) = line.split_once(':') else {
+            continue;
+        };
+        let valid_name = !name.is_empty()
+            && name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-');
+        let command = command.trim();
+        if !valid_name || command.is_empty() || entries.iter().any(|e| e.name == name) {
+            continue;
+        }
+        entries.push(ProcfileEntry {
+            name: name.to_string(),
+            command: command.to_string(),
+        });
+    }
+    entries
+}
+
+/// Lê um arquivo `.env` (`CHAVE=valor`, com `export` e aspas opcionais).
+///
+/// Linhas inválidas são ignoradas, como fazem foreman e dotenv.
+pub fn parse_env_file(content: &str) -> Vec<EnvVar> {
+    content
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .filter_map(|line| {
+            let line = line.strip_prefix("export ").unwrap_or(line);
+            let (key, value) = line.split_once('=')?;
+            let key = key.trim();
+            if key.is_empty() || key.contains(char::is_whitespace) {
+                return None;
+            }
+            let value = value.trim();
+            let unquoted = ['"', '\'']
+                .iter()
+                .find_map(|&quote| value.strip_prefix(quote)?.strip_suffix(quote))
+                .unwrap_or(value);
+            Some(EnvVar {
+                key: key.to_string(),
+                value: unquoted.to_string(),
+            })
+        })
+        .collect()
+}
+
+/// Verifica se o comando usa a variável `PORT`
+fn uses_port(command: &str) -> bool {
+    command.contains("$PORT") || command.contains("${PORT}")
+}
+
+/// Propõe aplicações a partir de um Procfile.
+///
+/// # Retorno
+/// Duas alternativas: um app por processo (padrão) ou os mesmos apps
+/// reunidos em um grupo com o nome da pasta.
+pub fn propose(path: &Path) -> Result<ImportProposal, String> {
+    let content = fs::read_to_string(path)
+        .map_err(|e| format!("Erro ao ler \"{}\": {}", path.display(), e))?;
+    let entries = parse_procfile(&content);
+    if entries.is_empty() {
+        return Err("Nenhum processo encontrado (use linhas \"nome: comando\")".to_string());
+    }
+
+    let dir = path.parent().unwrap_or(Path::new("."));
+    let env = ENV_FILE_NAMES.iter().fold(Vec::new(), |env, name| match fs::read_to_string(dir.join(name)) {
+        Ok(content) => EnvVar::merge(&env, &escape_env(parse_env_file(&content))),
+        Err(_) => env,
+    });
+    let base_port = env
+        .iter()
+        .find(|var| var.key == "PORT")
+        .and_then(|var| var.value.parse().ok())
+        .unwrap_or(BASE_PORT);
+
+    let apps: Vec<AppConfig> = entries
+        .iter()
+        .enumerate()
+        .map(|(i, entry)| {
+            let port = uses_port(&entry.command)
+                .then(|| u16::try_from(i * 100).ok().and_then(|offset| base_port.checked_add(offset)))
+                .flatten();
+            let mut app_env = env.clone();
+            if port.is_some() {
+                app_env = EnvVar::merge(&app_env, &[EnvVar {
+                    key: "PORT".to_string(),
+                    value: "${app.port}".to_string(),
+                }]);
+            }
+            let icon = match guess_icon(&entry.command) {
+                "" => "heroku",
+                icon => icon,
+            };
+            AppConfig {
+                icon_emoji: icon.to_string(),
+                working_dir: dir.display().to_string(),
+                commands: vec![escape_placeholders(&entry.command)],
+                env: app_env,
+                port,
+                ..AppConfig::new(entry.name.clone())
+            }
+        })
+        .collect();
+
+    let group = folder_name(dir);
+    let grouped = apps
+        .iter()
+        .map(|app| AppConfig {
+            group: Some(group.clone()),
+            ..app.clone()
+        })
+        .collect();
+
+    Ok(ImportProposal {
+        source: path.display().to_string(),
+        warnings: Vec::new(),
+        variants: vec![
+            ImportVariant {
+                label: format!("Um app por processo ({})", apps.len()),
+                apps,
+            },
+            ImportVariant {
+                label: format!("Um app por processo, no grupo \"{}\"", group),
+                apps: grouped,
+            },
+        ],
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::{Interpolator, WorkspaceSettings};
+
+    #[test]
+    fn test_parses_processes_and_env_files() {
+        let entries = parse_procfile(include_str!("../../../tests/fixtures/Procfile.dev"));
+        let names: Vec<&str> = entries.iter().map(|e| e.name.as_str()).collect();
+        assert_eq!(names, vec!["web", "css", "worker", "api"]);
+        assert_eq!(entries[2].command, "bundle exec sidekiq -C config/sidekiq.yml");
+
+        let env = parse_env_file("# comentário\nexport DATABASE_URL=\"postgres://localhost/app\"\nPORT=3000\ninválida\n");
+        assert_eq!(env.len(), 2);
+        assert_eq!(env[0].value, "postgres://localhost/app");
+    }
+
+    #[test]
+    fn test_assigns_foreman_ports_and_groups() {
+        let dir = std::env::temp_dir().join(format!("iris_procfile_{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("Procfile.dev");
+        fs::write(&path, include_str!("../../../tests/fixtures/Procfile.dev")).unwrap();
+        fs::write(dir.join(".env"), "PORT=3000\nRAILS_ENV=development\nDATABASE_URL=postgres://${DB_HOST}/app\n").unwrap();
+
+        let proposal = propose(&path).unwrap();
+        let apps = &proposal.variants[0].apps;
+        assert_eq!(apps[0].port, Some(3000));
+        assert_eq!(apps[1].port, None);
+        assert_eq!(apps[3].port, Some(3300));
+        assert_eq!(apps[0].icon_emoji, "ruby");
+        assert!(apps[0].env.iter().any(|v| v.key == "PORT" && v.value == "${app.port}"));
+        assert!(apps[1].env.iter().any(|v| v.key == "RAILS_ENV"));
+        assert!(proposal.variants[1].apps.iter().all(|app| app.group.is_some()));
+
+        // `${PORT}` do shell e do .env não são variáveis do Iris
+        let vars = Interpolator::new(&WorkspaceSettings::default());
+        for app in proposal.variants.iter().flat_map(|variant| &variant.apps) {
+            vars.expand_app(app).unwrap_or_else(|e| panic!("{}: {}", app.name, e));
+        }
+        let api = vars.expand_app(&apps[3]).unwrap();
+        assert_eq!(api.commands[0], "PORT=${PORT} node server.js");
+        assert!(api.env.iter().any(|v| v.key == "PORT" && v.value == "3300"));
+        assert!(api.env.iter().any(|v| v.key == "DATABASE_URL" && v.value == "postgres://${DB_HOST}/app"));
+        let _ = fs::remove_dir_all(&dir);
+    }
+}
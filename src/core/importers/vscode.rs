@@ -0,0 +1,477 @@
+//! # Importador de Tarefas do VS Code
+//!
+//! Lê um `.vscode/tasks.json` (JSON com comentários e vírgulas finais)
+//! e converte as tarefas `shell`, `process` e `npm` em aplicações ou em
+//! ações personalizadas.
+//!
+//! - `${workspaceFolder}` e afins são substituídos pela pasta do workspace,
+//!   `${userHome}` vira `${env:HOME}` (`${env:USERPROFILE}` no Windows) e
+//!   `${env:VAR}` é mantido (o Iris expande a mesma sintaxe)
+//! - Variáveis sem equivalente (`${file}`, `${input:...}`, `${config:...}`,
+//!   `${workspaceFolder:nome}`...) ficam escapadas como texto e geram um
+//!   aviso na revisão; outros `${VAR}` são do shell e também são escapados
+//! - `options.cwd`, `options.env` e as variações por sistema
+//!   (`windows`/`linux`/`osx`) são respeitados
+//! - As tarefas são ordenadas por `dependsOn`; tarefas compostas (apenas
+//!   `dependsOn`, sem comando) entram só na ordenação
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use serde_json::{Map, Value};
+
+use super::{dependency_order, escape_placeholders, folder_name, guess_icon, ImportProposal, ImportVariant};
+use crate::core::models::{AppConfig, CustomAction, EnvVar};
+
+/// Ícone usado quando o comando não indica a tecnologia
+const VSCODE_ICON: &str = "vscode";
+
+/// Prefixos das variáveis do VS Code sem equivalente no Iris
+const UNSUPPORTED_PREFIXES: [&str; 12] = [
+    "file",
+    "relativeFile",
+    "lineNumber",
+    "selectedText",
+    "execPath",
+    "defaultBuildTask",
+    "extensionInstallFolder",
+    "input:",
+    "config:",
+    "command:",
+    "workspaceFolder:",
+    "workspaceFolderBasename:",
+];
+
+/// Tarefa convertida
+#[derive(Clone, Debug, PartialEq)]
+pub struct VsCodeTask {
+    /// Nome da tarefa (`label`)
+    pub label: String,
+    /// Linha de comando completa (vazia em tarefas compostas)
+    pub command: String,
+    /// Pasta de execução
+    pub cwd: String,
+    /// Variáveis de ambiente
+    pub env: Vec<EnvVar>,
+    /// Tarefas das quais esta depende
+    pub depends_on: Vec<String>,
+    /// Se é uma tarefa contínua (servidor, watch)
+    pub is_background: bool,
+    /// Variáveis do VS Code mantidas como texto (ex: `${file}`)
+    pub unsupported: Vec<String>,
+}
+
+/// Remove comentários (`//` e `/* */`) e vírgulas finais de um JSONC,
+/// preservando o conteúdo das strings
+pub fn strip_jsonc(content: &str) -> String {
+    let mut without_comments = String::with_capacity(content.len());
+    let mut chars = content.chars().peekable();
+    let mut in_string = false;
+    while let Some(c) = chars.next() {
+        if in_string {
+            without_comments.push(c);
+            match c {
+                '\\' => without_comments.extend(chars.next()),
+                '"' => in_string = false,
+                _ => {}
+            }
+            continue;
+        }
+        match (c, chars.peek()) {
+            ('"', _) => {
+                in_string = true;
+                without_comments.push(c);
+            }
+            ('/', Some('/')) => {
+                for c in chars.by_ref() {
+                    if c == '\n' {
+                        without_comments.push('\n');
+                        break;
+                    }
+                }
+            }
+            ('/', Some('*')) => {
+                chars.next();
+                let mut previous = ' ';
+                for c in chars.by_ref() {
+                    if c == '\n' {
+                        // Mantém as linhas para as posições de erro
+                        without_comments.push('\n');
+                    }
+                    if previous == '*' && c == '/' {
+                        break;
+                    }
+                    previous = c;
+                }
+            }
+            _ => without_comments.push(c),
+        }
+    }
+
+    // Vírgulas seguidas apenas de espaços e `}` ou `]`
+    let mut result = String::with_capacity(without_comments.len());
+    let mut in_string = false;
+    let mut escaped = false;
+    for (i, c) in without_comments.char_indices() {
+        if in_string {
+            match c {
+                _ if escaped => escaped = false,
+                '\\' => escaped = true,
+                '"' => in_string = false,
+                _ => {}
+            }
+        } else if c == '"' {
+            in_string = true;
+        } else if c == ',' {
+            let next = without_comments[i + 1..].trim_start().chars().next();
+            if matches!(next, Some('}') | Some(']')) {
+                continue;
+            }
+        }
+        result.push(c);
+    }
+    result
+}
+
+/// Converte as variáveis do VS Code para o Iris.
+///
+/// As que dependem do workspace são substituídas, `${env:VAR}` é mantido e
+/// as demais são escapadas; as variáveis do VS Code sem equivalente vão
+/// para `unsupported`.
+fn substitute(text: &str, workspace: &Path, unsupported: &mut Vec<String>) -> String {
+    let mut result = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(start) = rest.find("${") {
+        result.push_str(&rest[..start]);
+        let after = &rest[start..];
+        let Some(end) = after.find('}') else {
+            result.push_str(&escape_placeholders(after));
+            return result;
+        };
+        let name = &after[2..end];
+        match name {
+            "workspaceFolder" | "workspaceRoot" | "cwd" => result.push_str(&workspace.display().to_string()),
+            "workspaceFolderBasename" => result.push_str(&folder_name(workspace)),
+            "pathSeparator" => result.push_str(std::path::MAIN_SEPARATOR_STR),
+            "userHome" if cfg!(windows) => result.push_str("${env:USERPROFILE}"),
+            "userHome" => result.push_str("${env:HOME}"),
+            _ if name.starts_with("env:") => result.push_str(&after[..=end]),
+            _ => {
+                if UNSUPPORTED_PREFIXES.iter().any(|prefix| name.starts_with(prefix)) {
+                    let variable = after[..=end].to_string();
+                    if !unsupported.contains(&variable) {
+                        unsupported.push(variable);
+                    }
+                }
+                result.push_str(&escape_placeholders(&after[..=end]));
+            }
+        }
+        rest = &after[end + 1..];
+    }
+    result.push_str(rest);
+    result
+}
+
+/// Resolve uma pasta da tarefa (relativa ao workspace, se não for absoluta)
+fn resolve_dir(dir: &str, workspace: &Path, unsupported: &mut Vec<String>) -> String {
+    let dir = substitute(dir, workspace, unsupported);
+    if dir.is_empty() {
+        workspace.display().to_string()
+    } else if Path::new(&dir).is_absolute() || dir.starts_with('$') {
+        dir
+    } else {
+        workspace.join(dir).display().to_string()
+    }
+}
+
+/// Coloca aspas em argumentos com espaços, como o VS Code faz
+fn quote(arg: &str) -> String {
+    if arg.contains(char::is_whitespace) && !arg.starts_with('"') {
+        format!("\"{}\"", arg)
+    } else {
+        arg.to_string()
+    }
+}
+
+/// Texto de um comando ou argumento (string ou `{ "value": ... }`)
+fn text_of(value: &Value) -> Option<String> {
+    match value {
+        Value::String(text) => Some(text.clone()),
+        Value::Object(map) => map.get("value").and_then(Value::as_str).map(str::to_string),
+        _ => None,
+    }
+}
+
+/// Chave das variações por sistema na tarefa
+fn platform_key() -> &'static str {
+    if cfg!(windows) {
+        "windows"
+    } else if cfg!(target_os = "macos") {
+        "osx"
+    } else {
+        "linux"
+    }
+}
+
+/// Converte uma tarefa do arquivo (None = tipo não suportado)
+fn parse_task(task: &Map<String, Value>, workspace: &Path) -> Option<VsCodeTask> {
+    // Variação do sistema atual sobrepõe os campos da tarefa
+    let mut task = task.clone();
+    if let Some(Value::Object(platform)) = task.remove(platform_key()) {
+        for (key, value) in platform {
+            match (task.get_mut(&key), value) {
+                (Some(Value::Object(base)), Value::Object(overrides)) => base.extend(overrides),
+                (_, value) => {
+                    task.insert(key, value);
+                }
+            }
+        }
+    }
+
+    let label = task.get("label").and_then(Value::as_str)?.to_string();
+    let options = task.get("options").and_then(Value::as_object);
+    let mut cwd = options.and_then(|o| o.get("cwd")).and_then(Value::as_str).unwrap_or_default().to_string();
+    let kind = task.get("type").and_then(Value::as_str).unwrap_or("shell");
+    let mut unsupported = Vec::new();
+
+    let command = match kind {
+        "npm" => {
+            let script = task.get("script").and_then(Value::as_str)?;
+            if let Some(path) = task.get("path").and_then(Value::as_str) {
+                cwd = path.to_string();
+            }
+            format!("npm run {}", script)
+        }
+        "shell" | "process" => {
+            let args = task
+                .get("args")
+                .and_then(Value::as_array)
+                .map(|args| {
+                    args.iter()
+                        .filter_map(text_of)
+                        .map(|arg| quote(&substitute(&arg, workspace, &mut unsupported)))
+                        .collect::<Vec<_>>()
+                })
+                .unwrap_or_default();
+            match task.get("command").and_then(text_of) {
+                Some(command) => {
+                    let command = substitute(&command, workspace, &mut unsupported);
+                    let command = if kind == "process" { quote(&command) } else { command };
+                    std::iter::once(command).chain(args).collect::<Vec<_>>().join(" ")
+                }
+                None => String::new(),
+            }
+        }
+        _ => return None,
+    };
+
+    let depends_on = match task.get("dependsOn") {
+        Some(Value::String(label)) => vec![label.clone()],
+        Some(Value::Array(labels)) => labels.iter().filter_map(Value::as_str).map(str::to_string).collect(),
+        _ => Vec::new(),
+    };
+    if command.is_empty() && depends_on.is_empty() {
+        return None;
+    }
+
+    let env = options
+        .and_then(|o| o.get("env"))
+        .and_then(Value::as_object)
+        .map(|env| {
+            env.iter()
+                .map(|(key, value)| EnvVar {
+                    key: key.clone(),
+                    value: substitute(value.as_str().unwrap_or_default(), workspace, &mut unsupported),
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+
+    Some(VsCodeTask {
+        label,
+        command,
+        cwd: resolve_dir(&cwd, workspace, &mut unsupported),
+        env,
+        depends_on,
+        is_background: task.get("isBackground").and_then(Value::as_bool).unwrap_or(false),
+        unsupported,
+    })
+}
+
+/// Lê as tarefas de um `tasks.json`, em ordem de dependência
+pub fn parse_tasks(content: &str, workspace: &Path) -> Result<Vec<VsCodeTask>, String> {
+    let value: Value = serde_json::from_str(&strip_jsonc(content))
+        .map_err(|e| format!("Erro ao processar tasks.json: {}", e))?;
+    let tasks = value
+        .get("tasks")
+        .and_then(Value::as_array)
+        .ok_or("O arquivo não tem a lista \"tasks\"")?;
+    let tasks = tasks
+        .iter()
+        .filter_map(Value::as_object)
+        .filter_map(|task| parse_task(task, workspace))
+        .collect();
+    Ok(dependency_order(tasks, |t| &t.label, |t| &t.depends_on))
+}
+
+/// Pasta do workspace de um `tasks.json` (a pasta acima de `.vscode`)
+fn workspace_of(path: &Path) -> PathBuf {
+    let dir = path.parent().unwrap_or(Path::new("."));
+    match dir.file_name() {
+        Some(name) if name == ".vscode" => dir.parent().unwrap_or(dir).to_path_buf(),
+        _ => dir.to_path_buf(),
+    }
+}
+
+/// Propõe aplicações a partir de um `tasks.json`.
+///
+/// # Retorno
+/// Duas alternativas: um app por tarefa (padrão), ou as tarefas contínuas
+/// como apps e as demais como ações personalizadas.
+pub fn propose(path: &Path) -> Result<ImportProposal, String> {
+    let content = fs::read_to_string(path)
+        .map_err(|e| format!("Erro ao ler \"{}\": {}", path.display(), e))?;
+    let workspace = workspace_of(path);
+    let tasks: Vec<VsCodeTask> = parse_tasks(&content, &workspace)?
+        .into_iter()
+        .filter(|task| !task.command.is_empty())
+        .collect();
+    if tasks.is_empty() {
+        return Err("Nenhuma tarefa shell, process ou npm encontrada".to_string());
+    }
+
+    let to_app = |task: &VsCodeTask| AppConfig {
+        icon_emoji: match guess_icon(&task.command) {
+            "" => VSCODE_ICON,
+            icon => icon,
+        }
+        .to_string(),
+        working_dir: task.cwd.clone(),
+        commands: vec![task.command.clone()],
+        env: task.env.clone(),
+        ..AppConfig::new(task.label.clone())
+    };
+    let per_task: Vec<AppConfig> = tasks.iter().map(to_app).collect();
+
+    let mut services: Vec<AppConfig> = tasks.iter().filter(|t| t.is_background).map(to_app).collect();
+    if services.is_empty() {
+        services.push(AppConfig {
+            icon_emoji: VSCODE_ICON.to_string(),
+            working_dir: workspace.display().to_string(),
+            ..AppConfig::new(folder_name(&workspace))
+        });
+    }
+    services[0].actions = tasks
+        .iter()
+        .filter(|t| !t.is_background)
+        .map(|task| CustomAction {
+            name: task.label.clone(),
+            commands: vec![task.command.clone()],
+            working_dir: task.cwd.clone(),
+            env: task.env.clone(),
+        })
+        .collect();
+    let action_count = services[0].actions.len();
+
+    let warnings = tasks
+        .iter()
+        .filter(|task| !task.unsupported.is_empty())
+        .map(|task| {
+            format!(
+                "{}: {} não têm equivalente no Iris e ficaram como texto; ajuste o comando",
+                task.label,
+                task.unsupported.join(", ")
+            )
+        })
+        .collect();
+
+    Ok(ImportProposal {
+        source: path.display().to_string(),
+        warnings,
+        variants: vec![
+            ImportVariant {
+                label: format!("Um app por tarefa ({})", per_task.len()),
+                apps: per_task,
+            },
+            ImportVariant {
+                label: format!(
+                    "Tarefas contínuas como apps, {} tarefas como ações de \"{}\"",
+                    action_count, services[0].name
+                ),
+                apps: services,
+            },
+        ],
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::{Interpolator, WorkspaceSettings};
+
+    #[test]
+    fn test_strips_comments_and_trailing_commas() {
+        let jsonc = "{\n  // comentário\n  \"a\": \"http://x\", /* bloco */\n  \"b\": [1, 2,],\n}";
+        let value: Value = serde_json::from_str(&strip_jsonc(jsonc)).unwrap();
+        assert_eq!(value["a"], "http://x");
+        assert_eq!(value["b"].as_array().unwrap().len(), 2);
+    }
+
+    #[test]
+    fn test_parses_tasks_in_dependency_order() {
+        let workspace = Path::new("/projetos/loja");
+        let tasks = parse_tasks(include_str!("../../../tests/fixtures/tasks.json"), workspace).unwrap();
+        let labels: Vec<&str> = tasks.iter().map(|t| t.label.as_str()).collect();
+        assert_eq!(labels, vec!["api", "dev", "test", "lint", "tudo", "url com // não é comentário"]);
+
+        let api = &tasks[0];
+        assert_eq!(api.command, "cargo run --bin \"api server\"");
+        assert_eq!(api.cwd, "/projetos/loja/api");
+        assert!(api.is_background);
+        assert_eq!(tasks[1].env[0].value, "http://localhost:8080");
+        assert_eq!(tasks[3].command, "npm run lint");
+        assert_eq!(tasks[3].cwd, workspace.join("web").display().to_string());
+        assert!(tasks[4].command.is_empty());
+    }
+
+    #[test]
+    fn test_proposes_background_tasks_with_actions() {
+        let dir = std::env::temp_dir().join(format!("iris_vscode_{}", std::process::id())).join(".vscode");
+        fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("tasks.json");
+        fs::write(&path, include_str!("../../../tests/fixtures/tasks.json")).unwrap();
+
+        let proposal = propose(&path).unwrap();
+        assert_eq!(proposal.variants[0].apps.len(), 5);
+        let services = &proposal.variants[1].apps;
+        let names: Vec<&str> = services.iter().map(|a| a.name.as_str()).collect();
+        assert_eq!(names, vec!["api", "dev"]);
+        assert_eq!(services[0].icon_emoji, "rust");
+        assert_eq!(services[0].actions.len(), 3);
+        let _ = fs::remove_dir_all(dir.parent().unwrap());
+    }
+
+    #[test]
+    fn test_imported_tasks_expand_and_flag_unsupported_variables() {
+        let dir = std::env::temp_dir().join(format!("iris_vscode_vars_{}", std::process::id())).join(".vscode");
+        fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("tasks.json");
+        let tasks = r#"{"tasks": [
+            {"label": "cache", "command": "ls ${userHome}/.cache ${HOSTNAME}"},
+            {"label": "lint", "command": "eslint", "args": ["${file}", "--rule", "${input:regra}"]}
+        ]}"#;
+        fs::write(&path, tasks).unwrap();
+
+        let proposal = propose(&path).unwrap();
+        let apps = &proposal.variants[0].apps;
+        let home = if cfg!(windows) { "${env:USERPROFILE}" } else { "${env:HOME}" };
+        assert_eq!(apps[0].commands[0], format!("ls {}/.cache $${{HOSTNAME}}", home));
+        let interpolator = Interpolator::new(&WorkspaceSettings::default());
+        for app in apps {
+            assert!(interpolator.expand_app(app).is_ok(), "{}", app.name);
+        }
+        assert_eq!(proposal.warnings.len(), 1);
+        assert!(proposal.warnings[0].starts_with("lint: ${file}, ${input:regra}"));
+        let _ = fs::remove_dir_all(dir.parent().unwrap());
+    }
+}
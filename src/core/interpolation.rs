@@ -0,0 +1,304 @@
+//! # Interpolação de Variáveis
+//!
+//! Expande marcadores `${...}` em comandos, pastas e variáveis de ambiente
+//! no momento da execução, para que as configurações funcionem em
+//! qualquer máquina.
+//!
+//! ## Variáveis Disponíveis
+//! - `${HOME}`: pasta do usuário
+//! - `${env:VAR}`: variável de ambiente do sistema
+//! - `${app.dir}`, `${app.name}`, `${app.port}`: dados da aplicação
+//! - `${workspace.root}`: pasta raiz do workspace
+//! - `${param.NOME}`: parâmetros informados ao executar
+//! - `${secret:NOME}`: segredos do cofre (apenas com o cofre desbloqueado)
+//! - `${NOME}`: variáveis globais definidas pelo usuário
+//!
+//! Use `$${...}` para escrever `${...}` literalmente (ex: variáveis do shell).
+//! Configurações antigas são migradas com o `${...}` do shell já escapado.
+//!
+//! A expansão vale para a pasta inicial, os comandos, as variáveis de
+//! ambiente e a URL de health-check.
+
+use std::collections::{BTreeMap, HashMap};
+
+use super::models::{AppConfig, EnvVar, WorkspaceSettings};
+
+/// Expansor de variáveis `${...}`.
+///
+/// # Exemplo
+/// ```rust
+/// use iris::core::{EnvVar, Interpolator, WorkspaceSettings};
+///
+/// let workspace = WorkspaceSettings {
+///     root: "/home/dev/projetos".to_string(),
+///     variables: vec![EnvVar { key: "TENANT".to_string(), value: "acme".to_string() }],
+/// };
+/// let vars = Interpolator::new(&workspace);
+/// assert_eq!(vars.expand("${workspace.root}/api").unwrap(), "/home/dev/projetos/api");
+/// assert_eq!(vars.expand("seed ${TENANT}").unwrap(), "seed acme");
+/// assert!(vars.expand("${DESCONHECIDA}").is_err());
+/// ```
+#[derive(Clone, Default)]
+pub struct Interpolator {
+    values: HashMap<String, String>,
+    secrets: SecretAccess,
+}
+
+/// Acesso aos segredos durante a expansão
+#[derive(Clone, Default)]
+enum SecretAccess {
+    /// Cofre bloqueado: `${secret:...}` é um erro
+    #[default]
+    Locked,
+    /// Validação sem o cofre: qualquer segredo é aceito
+    Placeholder,
+    /// Valores decifrados do cofre
+    Values(BTreeMap<String, String>),
+}
+
+impl Interpolator {
+    /// Cria o expansor com as variáveis embutidas e as globais do workspace
+    pub fn new(workspace: &WorkspaceSettings) -> Self {
+        let mut values = HashMap::new();
+        for var in &workspace.variables {
+            values.insert(var.key.clone(), var.value.clone());
+        }
+        if let Some(home) = dirs::home_dir() {
+            values.insert("HOME".to_string(), home.display().to_string());
+        }
+        if !workspace.root.trim().is_empty() {
+            values.insert("workspace.root".to_string(), workspace.root.trim().to_string());
+        }
+        Self {
+            values,
+            secrets: SecretAccess::Locked,
+        }
+    }
+
+    /// Disponibiliza os segredos do cofre como `${secret:NOME}`
+    pub fn with_secrets(mut self, secrets: &BTreeMap<String, String>) -> Self {
+        self.secrets = SecretAccess::Values(secrets.clone());
+        self
+    }
+
+    /// Aceita qualquer `${secret:NOME}` sem resolver o valor.
+    ///
+    /// Usado para validar configurações com o cofre bloqueado.
+    pub fn with_secret_placeholders(mut self) -> Self {
+        self.secrets = SecretAccess::Placeholder;
+        self
+    }
+
+    /// Adiciona (ou substitui) uma variável
+    pub fn with_value(mut self, name: &str, value: &str) -> Self {
+        self.values.insert(name.to_string(), value.to_string());
+        self
+    }
+
+    /// Expande todos os marcadores de um texto.
+    ///
+    /// # Retorno
+    /// O texto expandido, ou uma mensagem indicando a variável desconhecida.
+    pub fn expand(&self, text: &str) -> Result<String, String> {
+        let mut result = String::with_capacity(text.len());
+        let mut rest = text;
+        while let Some(start) = rest.find('$') {
+            result.push_str(&rest[..start]);
+            let after = &rest[start..];
+
+            // "$${" é escrito literalmente como "${"
+            if let Some(literal) = after.strip_prefix("$${") {
+                result.push_str("${");
+                rest = literal;
+                continue;
+            }
+            if !after.starts_with("${") {
+                result.push('$');
+                rest = &after[1..];
+                continue;
+            }
+
+            let Some(end) = after.find('}') else {
+                return Err(format!("Marcador sem fechamento em \"{}\"", after));
+            };
+            let name = after[2..end].trim();
+            result.push_str(&self.resolve(name)?);
+            rest = &after[end + 1..];
+        }
+        result.push_str(rest);
+        Ok(result)
+    }
+
+    /// Resolve o valor de uma variável pelo nome
+    fn resolve(&self, name: &str) -> Result<String, String> {
+        if let Some(var) = name.strip_prefix("env:") {
+            return std::env::var(var)
+                .map_err(|_| format!("Variável de ambiente não definida: ${{env:{}}}", var));
+        }
+        if let Some(secret) = name.strip_prefix("secret:") {
+            return match &self.secrets {
+                SecretAccess::Values(secrets) => secrets
+                    .get(secret)
+                    .cloned()
+                    .ok_or_else(|| format!("Segredo não encontrado no cofre: ${{secret:{}}}", secret)),
+                SecretAccess::Placeholder => Ok(String::new()),
+                SecretAccess::Locked => Err(format!(
+                    "Cofre de segredos bloqueado: desbloqueie para usar ${{secret:{}}}",
+                    secret
+                )),
+            };
+        }
+        if let Some(value) = self.values.get(name) {
+            return Ok(value.clone());
+        }
+        Err(match name {
+            "" => "Marcador vazio: ${}".to_string(),
+            "workspace.root" => "Pasta raiz do workspace não configurada: ${workspace.root}".to_string(),
+            "app.port" => "A aplicação não tem porta configurada: ${app.port}".to_string(),
+            _ if name.starts_with("param.") => format!("Parâmetro não declarado: ${{{}}}", name),
+            _ => format!("Variável desconhecida: ${{{}}}", name),
+        })
+    }
+
+    /// Retorna uma cópia da aplicação com pasta, comandos e variáveis expandidos.
+    ///
+    /// `${app.dir}` corresponde à pasta inicial já expandida.
+    pub fn expand_app(&self, app: &AppConfig) -> Result<AppConfig, String> {
+        let working_dir = self
+            .expand(&app.working_dir)
+            .map_err(|e| format!("Pasta inicial: {}", e))?;
+
+        let mut scope = self
+            .clone()
+            .with_value("app.dir", &working_dir)
+            .with_value("app.name", &app.name);
+        if let Some(port) = app.port {
+            scope = scope.with_value("app.port", &port.to_string());
+        }
+        for param in &app.params {
+            scope = scope.with_value(&format!("param.{}", param.name), &app.param_value(param));
+        }
+
+        let commands = app
+            .commands
+            .iter()
+            .enumerate()
+            .map(|(i, cmd)| scope.expand(cmd).map_err(|e| format!("Comando {}: {}", i + 1, e)))
+            .collect::<Result<Vec<_>, _>>()?;
+        let env = app
+            .env
+            .iter()
+            .map(|var| {
+                scope
+                    .expand(&var.value)
+                    .map(|value| EnvVar { key: var.key.clone(), value })
+                    .map_err(|e| format!("Variável {}: {}", var.key, e))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        let health_check_url = scope
+            .expand(&app.health_check_url)
+            .map_err(|e| format!("Health-check: {}", e))?;
+
+        Ok(AppConfig {
+            working_dir,
+            commands,
+            env,
+            health_check_url,
+            ..app.clone()
+        })
+    }
+
+    /// Verifica se a aplicação, seus perfis e suas ações expandem sem erros
+    pub fn check_app(&self, app: &AppConfig) -> Result<(), String> {
+        self.expand_app(app)?;
+        for profile in &app.profiles {
+            let (resolved, _) = app.with_profile(Some(&profile.name));
+            self.expand_app(&resolved)
+                .map_err(|e| format!("Perfil \"{}\": {}", profile.name, e))?;
+        }
+        for action in &app.actions {
+            self.expand_app(&app.for_action(action))
+                .map_err(|e| format!("Ação \"{}\": {}", action.name, e))?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_expand_app_fields() {
+        let vars = Interpolator::new(&WorkspaceSettings {
+            root: "/ws".to_string(),
+            variables: Vec::new(),
+        });
+        let app = AppConfig {
+            name: "api".to_string(),
+            working_dir: "${workspace.root}/api".to_string(),
+            commands: vec!["serve --port ${app.port} --root ${app.dir}".to_string()],
+            env: vec![EnvVar { key: "DATA".to_string(), value: "${app.dir}/data".to_string() }],
+            port: Some(8080),
+            health_check_url: "http://localhost:${app.port}/health".to_string(),
+            ..Default::default()
+        };
+
+        let expanded = vars.expand_app(&app).unwrap();
+        assert_eq!(expanded.working_dir, "/ws/api");
+        assert_eq!(expanded.commands[0], "serve --port 8080 --root /ws/api");
+        assert_eq!(expanded.env[0].value, "/ws/api/data");
+        assert_eq!(expanded.health_check_url, "http://localhost:8080/health");
+
+        let broken = AppConfig { health_check_url: "http://${HOSTNAME}/health".to_string(), ..app };
+        assert!(vars.expand_app(&broken).unwrap_err().starts_with("Health-check:"));
+    }
+
+    #[test]
+    fn test_params_use_last_value_or_default() {
+        use crate::core::LaunchParam;
+
+        let mut app = AppConfig {
+            commands: vec!["seed --tenant ${param.tenant} --verbose=${param.verbose}".to_string()],
+            params: vec![
+                LaunchParam { name: "tenant".to_string(), default: "acme".to_string(), ..Default::default() },
+                LaunchParam { name: "verbose".to_string(), default: "false".to_string(), ..Default::default() },
+            ],
+            ..Default::default()
+        };
+        let vars = Interpolator::default();
+        assert_eq!(vars.expand_app(&app).unwrap().commands[0], "seed --tenant acme --verbose=false");
+
+        app.param_values.insert("tenant".to_string(), "globex".to_string());
+        assert_eq!(vars.expand_app(&app).unwrap().commands[0], "seed --tenant globex --verbose=false");
+    }
+
+    #[test]
+    fn test_secrets_require_unlocked_store() {
+        let text = "deploy --token ${secret:TOKEN}";
+        assert!(Interpolator::default().expand(text).unwrap_err().contains("bloqueado"));
+        assert!(Interpolator::default().with_secret_placeholders().expand(text).is_ok());
+
+        let secrets = BTreeMap::from([("TOKEN".to_string(), "s3cr3t".to_string())]);
+        let vars = Interpolator::default().with_secrets(&secrets);
+        assert_eq!(vars.expand(text).unwrap(), "deploy --token s3cr3t");
+        assert!(vars.expand("${secret:OUTRO}").is_err());
+    }
+
+    #[test]
+    fn test_escapes_and_errors() {
+        let vars = Interpolator::default();
+        assert_eq!(vars.expand("echo $${PATH} $HOME").unwrap(), "echo ${PATH} $HOME");
+        assert!(vars.expand("${app.port}").unwrap_err().contains("porta"));
+        assert!(vars.expand("${aberto").is_err());
+
+        let app = AppConfig {
+            commands: vec!["ok".to_string(), "run ${nao_existe}".to_string()],
+            ..Default::default()
+        };
+        assert_eq!(
+            vars.expand_app(&app).unwrap_err(),
+            "Comando 2: Variável desconhecida: ${nao_existe}"
+        );
+    }
+}
@@ -0,0 +1,223 @@
+//! # Mesclagem de Configurações
+//!
+//! Mescla alterações externas do `config.json` (ex: editadas no editor
+//! ou sincronizadas com dotfiles) com as alterações feitas na interface.
+//!
+//! A mesclagem é em três vias, por aplicação (associada pelo ID):
+//! - `base`: última versão lida ou gravada pelo Iris
+//! - `local`: estado atual da interface
+//! - `remote`: conteúdo atual do arquivo
+//!
+//! Se apenas um dos lados mudou uma aplicação, a mudança é aceita.
+//! Se os dois mudaram de formas diferentes, há um conflito.
+
+use super::models::{AppConfig, AppState};
+
+/// Aplicação alterada dos dois lados de formas diferentes
+#[derive(Clone, Debug, PartialEq)]
+pub struct MergeConflict {
+    /// ID da aplicação
+    pub app_id: String,
+    /// Versão da interface (`None` = excluída na interface)
+    pub local: Option<AppConfig>,
+    /// Versão do arquivo (`None` = excluída no arquivo)
+    pub remote: Option<AppConfig>,
+}
+
+impl MergeConflict {
+    /// Nome para exibição (de qualquer um dos lados)
+    pub fn name(&self) -> &str {
+        self.local
+            .as_ref()
+            .or(self.remote.as_ref())
+            .map(|app| app.name.as_str())
+            .unwrap_or_default()
+    }
+}
+
+/// Resultado da mesclagem
+#[derive(Clone, Debug)]
+pub struct MergeResult {
+    /// Estado mesclado; nos conflitos, a versão da interface é mantida
+    pub state: AppState,
+    /// Conflitos por aplicação
+    pub conflicts: Vec<MergeConflict>,
+    /// Se as configurações do workspace mudaram dos dois lados
+    pub workspace_conflict: bool,
+}
+
+impl MergeResult {
+    /// Aplica a escolha de um conflito: a versão do arquivo (`use_remote`)
+    /// ou a da interface (já presente no estado mesclado)
+    pub fn resolve(&mut self, conflict: &MergeConflict, use_remote: bool) {
+        if !use_remote {
+            return;
+        }
+        let position = self.state.apps.iter().position(|a| a.id == conflict.app_id);
+        match (&conflict.remote, position) {
+            (Some(remote), Some(index)) => self.state.apps[index] = remote.clone(),
+            (Some(remote), None) => self.state.apps.push(remote.clone()),
+            (None, Some(index)) => {
+                self.state.apps.remove(index);
+            }
+            (None, None) => {}
+        }
+    }
+}
+
+/// Mescla em três vias as alterações da interface e do arquivo.
+///
+/// A ordem das aplicações segue o arquivo; aplicações adicionadas
+/// apenas na interface vão para o final.
+///
+/// # Exemplo
+/// ```rust
+/// use iris::core::{merge_states, AppConfig, AppState};
+///
+/// let app = |name: &str| AppConfig { id: "1".into(), name: name.into(), ..Default::default() };
+/// let state = |apps: Vec<AppConfig>| AppState { apps, ..Default::default() };
+///
+/// let base = state(vec![app("API")]);
+/// let local = state(vec![app("API")]);
+/// let remote = state(vec![app("API renomeada")]);
+///
+/// let merged = merge_states(&base, &local, &remote);
+/// assert!(merged.conflicts.is_empty());
+/// assert_eq!(merged.state.apps[0].name, "API renomeada");
+/// ```
+pub fn merge_states(base: &AppState, local: &AppState, remote: &AppState) -> MergeResult {
+    let find = |state: &AppState, id: &str| state.apps.iter().find(|a| a.id == id).cloned();
+    let mut apps = Vec::new();
+    let mut conflicts = Vec::new();
+
+    let remote_ids = remote.apps.iter().map(|a| a.id.as_str());
+    let local_only = local
+        .apps
+        .iter()
+        .map(|a| a.id.as_str())
+        .filter(|id| find(remote, id).is_none());
+    let mut seen = Vec::new();
+    for id in remote_ids.chain(local_only).chain(base.apps.iter().map(|a| a.id.as_str())) {
+        if seen.contains(&id) {
+            continue;
+        }
+        seen.push(id);
+
+        let (b, l, r) = (find(base, id), find(local, id), find(remote, id));
+        let chosen = if l == r || r == b {
+            l
+        } else if l == b {
+            r
+        } else {
+            conflicts.push(MergeConflict {
+                app_id: id.to_string(),
+                local: l.clone(),
+                remote: r,
+            });
+            l
+        };
+        apps.extend(chosen);
+    }
+
+    let (workspace, workspace_conflict) = if local.workspace == remote.workspace || remote.workspace == base.workspace {
+        (local.workspace.clone(), false)
+    } else if local.workspace == base.workspace {
+        (remote.workspace.clone(), false)
+    } else {
+        (local.workspace.clone(), true)
+    };
+
+    // Pastas de projeto: vale o lado que mudou (a interface, se os dois mudaram)
+    let projects = if local.projects == base.projects {
+        remote.projects.clone()
+    } else {
+        local.projects.clone()
+    };
+
+    MergeResult {
+        state: AppState {
+            apps,
+            workspace,
+            projects,
+            ..AppState::default()
+        },
+        conflicts,
+        workspace_conflict,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::ProjectLink;
+
+    fn app(id: &str, name: &str) -> AppConfig {
+        AppConfig {
+            id: id.to_string(),
+            name: name.to_string(),
+            ..Default::default()
+        }
+    }
+
+    fn state(apps: Vec<AppConfig>) -> AppState {
+        AppState {
+            apps,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_merges_independent_changes() {
+        let base = state(vec![app("1", "API"), app("2", "Web"), app("3", "Worker")]);
+        // Interface: renomeou Web e adicionou Docs
+        let local = state(vec![app("1", "API"), app("2", "Web app"), app("3", "Worker"), app("4", "Docs")]);
+        // Arquivo: excluiu Worker e adicionou Banco
+        let remote = state(vec![app("1", "API"), app("2", "Web"), app("5", "Banco")]);
+
+        let merged = merge_states(&base, &local, &remote);
+        assert!(merged.conflicts.is_empty());
+        let names: Vec<&str> = merged.state.apps.iter().map(|a| a.name.as_str()).collect();
+        assert_eq!(names, vec!["API", "Web app", "Banco", "Docs"]);
+    }
+
+    #[test]
+    fn test_reports_conflicts_and_resolves_them() {
+        let base = state(vec![app("1", "API"), app("2", "Web")]);
+        let local = state(vec![app("1", "API local"), app("2", "Web local")]);
+        let remote = state(vec![app("1", "API arquivo")]);
+
+        let mut merged = merge_states(&base, &local, &remote);
+        assert_eq!(merged.conflicts.len(), 2);
+        assert_eq!(merged.state.apps[0].name, "API local");
+
+        let conflicts = merged.conflicts.clone();
+        merged.resolve(&conflicts[0], true);
+        merged.resolve(&conflicts[1], true);
+        let names: Vec<&str> = merged.state.apps.iter().map(|a| a.name.as_str()).collect();
+        assert_eq!(names, vec!["API arquivo"]);
+    }
+
+    #[test]
+    fn test_reload_picks_up_external_project_changes() {
+        let dir = std::env::temp_dir().join(format!("iris_merge_projects_{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        std::fs::create_dir_all(&dir).unwrap();
+        let manager = crate::core::ConfigManager::with_path(dir.join("config.json"));
+
+        let mut base = state(vec![app("1", "API")]);
+        base.projects.push(ProjectLink { root: "/srv/antigo".to_string(), ..Default::default() });
+        manager.save(&base).unwrap();
+        let synced = manager.try_load().unwrap();
+
+        // Editor externo: desvincula um projeto e vincula outro
+        let content = std::fs::read_to_string(dir.join("config.json")).unwrap();
+        std::fs::write(dir.join("config.json"), content.replace("/srv/antigo", "/srv/novo")).unwrap();
+        let remote = manager.try_load().unwrap();
+
+        let merged = merge_states(&synced, &synced, &remote);
+        assert!(merged.conflicts.is_empty());
+        let roots: Vec<&str> = merged.state.projects.iter().map(|link| link.root.as_str()).collect();
+        assert_eq!(roots, vec!["/srv/novo"]);
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+}
@@ -0,0 +1,234 @@
+//! # Migrações de Configuração
+//!
+//! Atualiza arquivos de configuração antigos para o formato atual.
+//! As migrações trabalham sobre o JSON bruto (`serde_json::Value`),
+//! antes da desserialização, para que nenhum dado seja perdido ou
+//! ignorado quando os modelos mudam.
+//!
+//! ## Versões
+//! - `0`: arquivos sem `schema_version` (até a introdução do versionamento)
+//! - `1`: `schema_version` explícito, todas as aplicações com ID e
+//!   `${...}` do shell escapado como `$${...}` (interpolação do Iris)
+//!
+//! Para mudar o formato, incremente `CURRENT_SCHEMA_VERSION` e adicione
+//! a função de migração correspondente em `MIGRATIONS`.
+
+use serde_json::Value;
+
+/// Versão do formato gravada pelo Iris atual
+pub const CURRENT_SCHEMA_VERSION: u32 = 1;
+
+/// Migração de uma versão para a seguinte
+type Migration = fn(&mut Value) -> Result<(), String>;
+
+/// Migrações em ordem: `MIGRATIONS[n]` leva da versão `n` para `n + 1`
+const MIGRATIONS: [Migration; CURRENT_SCHEMA_VERSION as usize] = [migrate_v0_to_v1];
+
+/// Retorna a versão do formato de um arquivo (0 se não declarada)
+pub fn schema_version(value: &Value) -> Result<u32, String> {
+    match value.get("schema_version") {
+        None => Ok(0),
+        Some(version) => version
+            .as_u64()
+            .and_then(|v| u32::try_from(v).ok())
+            .ok_or_else(|| format!("schema_version inválido: {}", version)),
+    }
+}
+
+/// Aplica todas as migrações necessárias até a versão atual.
+///
+/// # Retorno
+/// A versão original do arquivo, ou erro se ele for de uma versão
+/// mais nova do Iris ou não puder ser migrado.
+///
+/// # Exemplo
+/// ```rust
+/// use iris::core::{migrate, CURRENT_SCHEMA_VERSION};
+///
+/// let mut value = serde_json::json!({ "apps": [{ "name": "api", "commands": "npm start" }] });
+/// assert_eq!(migrate(&mut value).unwrap(), 0);
+/// assert_eq!(value["schema_version"], CURRENT_SCHEMA_VERSION);
+/// assert_eq!(value["apps"][0]["commands"][0], "npm start");
+/// ```
+pub fn migrate(value: &mut Value) -> Result<u32, String> {
+    if !value.is_object() {
+        return Err("Configuração inválida: o conteúdo deve ser um objeto JSON".to_string());
+    }
+
+    let original = schema_version(value)?;
+    if original > CURRENT_SCHEMA_VERSION {
+        return Err(format!(
+            "Configuração criada por uma versão mais nova do Iris (formato {}, suportado até {})",
+            original, CURRENT_SCHEMA_VERSION
+        ));
+    }
+
+    for (version, migration) in MIGRATIONS.iter().enumerate().skip(original as usize) {
+        migration(value).map_err(|e| format!("Migração v{} → v{}: {}", version, version + 1, e))?;
+        value["schema_version"] = Value::from(version as u32 + 1);
+    }
+    Ok(original)
+}
+
+/// v0 → v1: normaliza arquivos escritos antes do versionamento.
+///
+/// - `apps` ausente vira uma lista vazia
+/// - aplicações sem ID recebem um novo
+/// - `commands` escrito como texto único vira uma lista
+/// - `${VAR}` do shell em comandos e variáveis de ambiente vira `$${VAR}`,
+///   para não ser tratado como variável desconhecida do Iris
+fn migrate_v0_to_v1(value: &mut Value) -> Result<(), String> {
+    let known: Vec<String> = value
+        .pointer("/workspace/variables")
+        .and_then(Value::as_array)
+        .into_iter()
+        .flatten()
+        .filter_map(|var| var.get("key").and_then(Value::as_str).map(str::to_string))
+        .collect();
+
+    let apps = value
+        .as_object_mut()
+        .ok_or("o conteúdo deve ser um objeto")?
+        .entry("apps")
+        .or_insert_with(|| Value::Array(Vec::new()));
+    let apps = apps.as_array_mut().ok_or("\"apps\" deve ser uma lista")?;
+
+    for (i, app) in apps.iter_mut().enumerate() {
+        let app = app
+            .as_object_mut()
+            .ok_or_else(|| format!("aplicação {} não é um objeto", i + 1))?;
+
+        let has_id = app.get("id").and_then(Value::as_str).is_some_and(|id| !id.is_empty());
+        if !has_id {
+            app.insert("id".to_string(), Value::from(crate::utils::uuid_simple()));
+        }
+
+        if let Some(Value::String(command)) = app.get("commands") {
+            let commands = if command.trim().is_empty() {
+                Vec::new()
+            } else {
+                vec![Value::from(command.clone())]
+            };
+            app.insert("commands".to_string(), Value::Array(commands));
+        }
+
+        escape_shell_vars(app, &known);
+        for key in ["actions", "profiles"] {
+            if let Some(Value::Array(items)) = app.get_mut(key) {
+                for item in items.iter_mut().filter_map(Value::as_object_mut) {
+                    escape_shell_vars(item, &known);
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Escapa os `${VAR}` do shell na pasta, nos comandos e nas variáveis de
+/// ambiente de um objeto (aplicação, ação ou perfil)
+fn escape_shell_vars(object: &mut serde_json::Map<String, Value>, known: &[String]) {
+    if let Some(Value::String(dir)) = object.get_mut("working_dir") {
+        *dir = escape_unknown(dir, known);
+    }
+    if let Some(Value::Array(commands)) = object.get_mut("commands") {
+        for command in commands.iter_mut() {
+            if let Value::String(text) = command {
+                *text = escape_unknown(text, known);
+            }
+        }
+    }
+    if let Some(Value::Array(env)) = object.get_mut("env") {
+        for var in env.iter_mut() {
+            if let Some(Value::String(text)) = var.get_mut("value") {
+                *text = escape_unknown(text, known);
+            }
+        }
+    }
+}
+
+/// Troca `${NOME}` por `$${NOME}` quando `NOME` não é uma variável do Iris
+fn escape_unknown(text: &str, known: &[String]) -> String {
+    const NAMESPACES: [&str; 5] = ["env:", "secret:", "app.", "workspace.", "param."];
+    let mut result = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(start) = rest.find("${") {
+        let escaped = rest[..start].ends_with('$');
+        result.push_str(&rest[..start]);
+        let after = &rest[start..];
+        let name = after.find('}').map(|end| after[2..end].trim());
+        let is_iris = name.is_some_and(|name| {
+            name == "HOME" || known.iter().any(|k| k == name) || NAMESPACES.iter().any(|ns| name.starts_with(ns))
+        });
+        if !escaped && !is_iris {
+            result.push('$');
+        }
+        result.push_str("${");
+        rest = &after[2..];
+    }
+    result.push_str(rest);
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::AppState;
+
+    /// Arquivos de exemplo de cada versão histórica do formato
+    const FIXTURES: [(u32, &str); 2] = [
+        (0, include_str!("../../tests/fixtures/config_v0.json")),
+        (1, include_str!("../../tests/fixtures/config_v1.json")),
+    ];
+
+    #[test]
+    fn test_every_fixture_migrates_to_current() {
+        assert_eq!(FIXTURES.len(), CURRENT_SCHEMA_VERSION as usize + 1);
+        for (version, content) in FIXTURES {
+            let mut value: Value = serde_json::from_str(content).unwrap();
+            assert_eq!(migrate(&mut value).unwrap(), version);
+            assert_eq!(schema_version(&value).unwrap(), CURRENT_SCHEMA_VERSION);
+
+            let state: AppState = serde_json::from_value(value)
+                .unwrap_or_else(|e| panic!("fixture v{}: {}", version, e));
+            assert!(!state.apps.is_empty());
+            assert!(state.apps.iter().all(|app| !app.id.is_empty()));
+        }
+    }
+
+    #[test]
+    fn test_v0_normalizes_legacy_fields() {
+        let mut value: Value = serde_json::from_str(FIXTURES[0].1).unwrap();
+        migrate(&mut value).unwrap();
+        let state: AppState = serde_json::from_value(value).unwrap();
+
+        assert_eq!(state.apps[0].id, "1700000000000");
+        assert_eq!(state.apps[1].commands, vec!["python manage.py runserver"]);
+        assert!(state.apps[2].commands.is_empty());
+
+        // O ${...} do shell continua chegando ao shell após a interpolação
+        assert_eq!(state.apps[0].commands[1], "npm run dev -- --host $${HOSTNAME}");
+        assert_eq!(state.apps[0].env[0].value, "${HOME}/.cache/$${USER}");
+        let app = crate::core::Interpolator::new(&state.workspace)
+            .expand_app(&state.apps[0])
+            .unwrap();
+        assert_eq!(app.commands[1], "npm run dev -- --host ${HOSTNAME}");
+        assert!(app.env[0].value.ends_with("/.cache/${USER}"));
+        assert_eq!(app.working_dir, "C:\\projetos\\${TEAM}\\frontend");
+    }
+
+    #[test]
+    fn test_current_version_is_untouched() {
+        let original: Value = serde_json::from_str(FIXTURES[1].1).unwrap();
+        let mut value = original.clone();
+        migrate(&mut value).unwrap();
+        assert_eq!(value, original);
+    }
+
+    #[test]
+    fn test_rejects_newer_or_invalid_files() {
+        let mut newer = serde_json::json!({ "schema_version": CURRENT_SCHEMA_VERSION + 1, "apps": [] });
+        assert!(migrate(&mut newer).unwrap_err().contains("mais nova"));
+        assert!(migrate(&mut serde_json::json!([])).is_err());
+        assert!(migrate(&mut serde_json::json!({ "apps": {} })).is_err());
+    }
+}
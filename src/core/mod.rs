@@ -6,9 +6,42 @@
 //! ## Componentes
 //! - `models`: Definições de estruturas de dados (AppConfig, AppState, etc.)
 //! - `config`: Gerenciamento de configurações e persistência
+//! - `interpolation`: Expansão de variáveis `${...}` na execução
+//! - `migrations`: Atualização de arquivos de configuração antigos
+//! - `undo`: Desfazer/refazer alterações na lista de aplicações
+//! - `trash`: Lixeira de aplicações excluídas
+//! - `history`: Histórico de versões salvas, com diferenças por aplicação
+//! - `merge`: Mesclagem em três vias com alterações externas do arquivo
+//! - `import_plan`: Associação e estratégias ao importar configurações
+//! - `portable`: Exportação com caminhos relativos a `${workspace.root}`
+//! - `formats`: Leitura e escrita em JSON, TOML e YAML
+//! - `importers`: Importação de docker-compose e outros formatos
+//! - `detect`: Detecção do tipo de projeto e sugestão de comandos
+//! - `project`: Arquivos de projeto (`.iris.toml`) versionados nos repositórios
 
 pub mod models;
 pub mod config;
+pub mod interpolation;
+pub mod migrations;
+pub mod undo;
+pub mod trash;
+pub mod history;
+pub mod merge;
+pub mod import_plan;
+pub mod portable;
+pub mod formats;
+pub mod project;
+pub mod importers;
+pub mod detect;
 
 pub use models::*;
 pub use config::*;
+pub use interpolation::*;
+pub use undo::*;
+pub use trash::*;
+pub use history::*;
+pub use merge::*;
+pub use import_plan::*;
+pub use formats::ConfigFormat;
+pub use detect::{detect_project, scan_projects, ProjectDetection, ProjectScan};
+pub use migrations::{migrate, schema_version, CURRENT_SCHEMA_VERSION};
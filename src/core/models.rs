@@ -4,6 +4,7 @@
 //! Estas estruturas são serializáveis para persistência em JSON.
 
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 use std::time::Instant;
 use std::process::Child;
 
@@ -14,15 +15,18 @@ use std::process::Child;
 /// 
 /// # Exemplo
 /// ```rust
+/// use iris::AppConfig;
+///
 /// let app = AppConfig {
 ///     id: "123456789".to_string(),
 ///     name: "Minha App React".to_string(),
 ///     icon_emoji: "react".to_string(),
 ///     working_dir: "C:\\projetos\\minha-app".to_string(),
 ///     commands: vec!["npm install".to_string(), "npm run dev".to_string()],
+///     ..Default::default()
 /// };
 /// ```
-#[derive(Clone, Serialize, Deserialize, Default, Debug)]
+#[derive(Clone, Serialize, Deserialize, Default, Debug, PartialEq)]
 pub struct AppConfig {
     /// Identificador único da aplicação (gerado automaticamente)
     pub id: String,
@@ -38,6 +42,439 @@ pub struct AppConfig {
     
     /// Lista de comandos a serem executados em sequência
     pub commands: Vec<String>,
+    
+    /// Configuração de reinício automático ao alterar arquivos
+    #[serde(default)]
+    pub watch: WatchConfig,
+    
+    /// Modo de execução (terminal externo ou em segundo plano)
+    #[serde(default)]
+    pub run_mode: RunMode,
+    
+    /// Regras avaliadas sobre a saída capturada da aplicação
+    #[serde(default)]
+    pub log_rules: Vec<LogRule>,
+    
+    /// Ações personalizadas executadas sob demanda (ex: testes, lint)
+    #[serde(default)]
+    pub actions: Vec<CustomAction>,
+    
+    /// Variáveis de ambiente aplicadas a todas as execuções
+    #[serde(default)]
+    pub env: Vec<EnvVar>,
+    
+    /// Perfis de execução (ex: dev, prod, debug)
+    #[serde(default)]
+    pub profiles: Vec<LaunchProfile>,
+    
+    /// Porta usada pela aplicação (disponível como `${app.port}`)
+    #[serde(default)]
+    pub port: Option<u16>,
+    
+    /// URL consultada para saber quando a aplicação está pronta
+    /// (aceita `${...}`, ex: `http://localhost:${app.port}/health`)
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub health_check_url: String,
+    
+    /// Parâmetros perguntados antes de cada execução (`${param.NOME}`)
+    #[serde(default)]
+    pub params: Vec<LaunchParam>,
+    
+    /// Últimos valores informados para os parâmetros (nome -> valor)
+    #[serde(default)]
+    pub param_values: BTreeMap<String, String>,
+    
+    /// Projeto (repositório) que define esta aplicação, se houver
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub project: Option<ProjectSource>,
+    
+    /// Grupo em que a aplicação é exibida (ex: processos de um Procfile)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub group: Option<String>,
+    
+    /// Repositório clonado na pasta de trabalho quando ela não existe
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub repository: Option<Repository>,
+    
+    /// Comandos executados uma vez, após clonar o repositório
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub setup: Vec<String>,
+}
+
+/// Repositório git de uma aplicação.
+#[derive(Clone, Serialize, Deserialize, Default, Debug, PartialEq)]
+pub struct Repository {
+    /// URL do repositório (aceita `${...}`, ex: `${secret:TOKEN}`)
+    pub url: String,
+    
+    /// Branch a clonar (vazio = branch padrão do repositório)
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub branch: String,
+}
+
+impl Repository {
+    /// Argumentos do `git clone` para a pasta `dir`
+    pub fn clone_args(&self, dir: &str) -> Vec<String> {
+        let mut args = vec!["clone".to_string()];
+        if !self.branch.trim().is_empty() {
+            args.push("--branch".to_string());
+            args.push(self.branch.trim().to_string());
+        }
+        args.push(self.url.trim().to_string());
+        args.push(dir.to_string());
+        args
+    }
+}
+
+/// Origem de uma aplicação definida no arquivo de projeto de um repositório.
+#[derive(Clone, Serialize, Deserialize, Default, Debug, PartialEq)]
+pub struct ProjectSource {
+    /// Pasta raiz do repositório
+    pub root: String,
+    
+    /// Chave da aplicação no arquivo de projeto
+    pub key: String,
+}
+
+/// Pasta de projeto adicionada pelo usuário.
+/// 
+/// As aplicações vêm do arquivo de projeto (ver `core::project`); aqui
+/// ficam apenas os ajustes locais, que nunca são gravados no repositório.
+#[derive(Clone, Serialize, Deserialize, Default, Debug, PartialEq)]
+pub struct ProjectLink {
+    /// Pasta raiz do repositório
+    pub root: String,
+    
+    /// Ajustes locais por aplicação (chave -> campos substituídos)
+    #[serde(default)]
+    pub overrides: BTreeMap<String, serde_json::Map<String, serde_json::Value>>,
+}
+
+/// Tipo de um parâmetro de execução.
+#[derive(Clone, Copy, Serialize, Deserialize, Debug, Default, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum ParamKind {
+    /// Texto livre
+    #[default]
+    Text,
+    /// Escolha entre opções fixas
+    Choice,
+    /// Sim/não (`true`/`false`)
+    Boolean,
+}
+
+impl ParamKind {
+    /// Todos os tipos, na ordem exibida na interface
+    pub const ALL: [ParamKind; 3] = [ParamKind::Text, ParamKind::Choice, ParamKind::Boolean];
+    
+    /// Retorna o nome do tipo exibido na interface
+    pub fn label(&self) -> &'static str {
+        match self {
+            ParamKind::Text => "Texto",
+            ParamKind::Choice => "Lista",
+            ParamKind::Boolean => "Sim/Não",
+        }
+    }
+}
+
+/// Parâmetro perguntado ao usuário antes de executar a aplicação
+/// (ex: tenant a popular, branch, arquivo `.env`).
+/// 
+/// # Exemplo
+/// ```rust
+/// use iris::core::{LaunchParam, ParamKind};
+///
+/// let param = LaunchParam {
+///     name: "tenant".to_string(),
+///     kind: ParamKind::Choice,
+///     choices: vec!["acme".to_string(), "globex".to_string()],
+///     default: "acme".to_string(),
+///     ..Default::default()
+/// };
+/// ```
+#[derive(Clone, Serialize, Deserialize, Default, Debug, PartialEq)]
+pub struct LaunchParam {
+    /// Nome usado no marcador `${param.NOME}`
+    pub name: String,
+    
+    /// Texto exibido no diálogo (vazio = nome)
+    #[serde(default)]
+    pub label: String,
+    
+    /// Tipo do parâmetro
+    #[serde(default)]
+    pub kind: ParamKind,
+    
+    /// Opções disponíveis (para `ParamKind::Choice`)
+    #[serde(default)]
+    pub choices: Vec<String>,
+    
+    /// Valor inicial quando não há valor anterior
+    #[serde(default)]
+    pub default: String,
+}
+
+impl LaunchParam {
+    /// Texto exibido no diálogo
+    pub fn display_label(&self) -> &str {
+        if self.label.trim().is_empty() {
+            &self.name
+        } else {
+            &self.label
+        }
+    }
+}
+
+/// Perfil de execução de uma aplicação (ex: "dev", "prod", "debug").
+/// 
+/// Um perfil sem comandos reaproveita os comandos da aplicação, alterando
+/// apenas as variáveis de ambiente (ex: `NODE_OPTIONS=--inspect`).
+/// 
+/// # Exemplo
+/// ```rust
+/// use iris::core::LaunchProfile;
+///
+/// let profile = LaunchProfile {
+///     name: "prod".to_string(),
+///     commands: vec!["npm run build".to_string(), "npm start".to_string()],
+///     ..Default::default()
+/// };
+/// ```
+#[derive(Clone, Serialize, Deserialize, Default, Debug, PartialEq)]
+pub struct LaunchProfile {
+    /// Nome exibido no menu "Executar"
+    pub name: String,
+    
+    /// Comandos executados em sequência (vazio = comandos da aplicação)
+    #[serde(default)]
+    pub commands: Vec<String>,
+    
+    /// Variáveis que sobrescrevem as da aplicação
+    #[serde(default)]
+    pub env: Vec<EnvVar>,
+    
+    /// Se é o perfil usado pelo botão "Executar"
+    #[serde(default)]
+    pub is_default: bool,
+}
+
+/// Variável de ambiente definida pelo usuário.
+#[derive(Clone, Serialize, Deserialize, Debug, PartialEq, Eq)]
+pub struct EnvVar {
+    /// Nome da variável
+    pub key: String,
+    
+    /// Valor da variável
+    pub value: String,
+}
+
+impl EnvVar {
+    /// Interpreta um texto com uma variável `CHAVE=valor` por linha.
+    /// 
+    /// Linhas vazias e iniciadas por `#` são ignoradas.
+    /// 
+    /// # Exemplo
+    /// ```rust
+    /// use iris::core::EnvVar;
+    ///
+    /// let vars = EnvVar::parse_lines("PORT=3000\n# comentário\nDEBUG=1").unwrap();
+    /// assert_eq!(vars[0].key, "PORT");
+    /// assert_eq!(vars[1].value, "1");
+    /// ```
+    pub fn parse_lines(text: &str) -> Result<Vec<EnvVar>, String> {
+        let mut vars = Vec::new();
+        for line in text.lines().map(str::trim) {
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let Some((key, value)) = line.split_once('=') else {
+                return Err(format!("Variável \"{}\" inválida: use CHAVE=valor", line));
+            };
+            let key = key.trim();
+            if key.is_empty() || key.contains(char::is_whitespace) {
+                return Err(format!("Nome de variável inválido: \"{}\"", key));
+            }
+            vars.push(EnvVar {
+                key: key.to_string(),
+                value: value.trim().to_string(),
+            });
+        }
+        Ok(vars)
+    }
+    
+    /// Combina duas listas, com `overrides` substituindo variáveis de mesmo nome
+    pub fn merge(base: &[EnvVar], overrides: &[EnvVar]) -> Vec<EnvVar> {
+        let mut merged: Vec<EnvVar> = base
+            .iter()
+            .filter(|var| !overrides.iter().any(|o| o.key == var.key))
+            .cloned()
+            .collect();
+        merged.extend(overrides.iter().cloned());
+        merged
+    }
+    
+    /// Formata as variáveis como texto `CHAVE=valor`, uma por linha
+    pub fn format_lines(vars: &[EnvVar]) -> String {
+        vars.iter()
+            .map(|var| format!("{}={}", var.key, var.value))
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
+
+/// Ação personalizada de uma aplicação (ex: "Testes", "Lint", "Migrar").
+/// 
+/// É executada como uma tarefa avulsa, com saída capturada, sem alterar
+/// o estado de execução da aplicação.
+/// 
+/// # Exemplo
+/// ```rust
+/// use iris::core::CustomAction;
+///
+/// let action = CustomAction {
+///     name: "Testes".to_string(),
+///     commands: vec!["npm test".to_string()],
+///     ..Default::default()
+/// };
+/// ```
+#[derive(Clone, Serialize, Deserialize, Default, Debug, PartialEq)]
+pub struct CustomAction {
+    /// Nome exibido no menu do card
+    pub name: String,
+    
+    /// Comandos executados em sequência
+    pub commands: Vec<String>,
+    
+    /// Pasta onde os comandos são executados (vazio = pasta da aplicação)
+    #[serde(default)]
+    pub working_dir: String,
+    
+    /// Variáveis de ambiente adicionais
+    #[serde(default)]
+    pub env: Vec<EnvVar>,
+}
+
+/// Modo de execução de uma aplicação.
+#[derive(Clone, Copy, Serialize, Deserialize, Debug, Default, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum RunMode {
+    /// Abre um terminal externo com os comandos (comportamento original)
+    #[default]
+    External,
+    /// Executa em segundo plano, capturando a saída para o visualizador de logs
+    Headless,
+    /// Executa em um terminal embutido (pseudo-terminal) com entrada interativa
+    Terminal,
+}
+
+impl RunMode {
+    /// Retorna o nome do modo exibido na interface
+    pub fn label(&self) -> &'static str {
+        match self {
+            RunMode::External => "Terminal externo",
+            RunMode::Headless => "Segundo plano (captura logs)",
+            RunMode::Terminal => "Terminal embutido",
+        }
+    }
+    
+    /// Verifica se o modo captura a saída dos comandos
+    pub fn captures_output(&self) -> bool {
+        matches!(self, RunMode::Headless | RunMode::Terminal)
+    }
+}
+
+/// Tipo de uma regra de log.
+#[derive(Clone, Copy, Serialize, Deserialize, Debug, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum LogRuleKind {
+    /// Marca a aplicação como pronta
+    Ready,
+    /// Incrementa o contador de erros
+    Error,
+    /// Extrai a URL da aplicação (primeiro grupo de captura ou o match inteiro)
+    ExtractUrl,
+}
+
+impl LogRuleKind {
+    /// Todos os tipos de regra, na ordem exibida na interface
+    pub const ALL: [LogRuleKind; 3] = [LogRuleKind::Ready, LogRuleKind::Error, LogRuleKind::ExtractUrl];
+    
+    /// Retorna o nome do tipo exibido na interface
+    pub fn label(&self) -> &'static str {
+        match self {
+            LogRuleKind::Ready => "✔ Pronto",
+            LogRuleKind::Error => "⚠ Erro",
+            LogRuleKind::ExtractUrl => "🔗 URL",
+        }
+    }
+}
+
+/// Regra de log: uma expressão regular avaliada em cada linha de saída.
+/// 
+/// # Exemplo
+/// ```rust
+/// use iris::core::{LogRule, LogRuleKind};
+///
+/// let rule = LogRule {
+///     kind: LogRuleKind::Ready,
+///     pattern: r"Local:\s+http://".to_string(),
+/// };
+/// ```
+#[derive(Clone, Serialize, Deserialize, Debug, PartialEq)]
+pub struct LogRule {
+    /// Efeito da regra quando a linha casa com o padrão
+    pub kind: LogRuleKind,
+    
+    /// Expressão regular
+    pub pattern: String,
+}
+
+/// Configuração de observação de arquivos de uma aplicação.
+/// 
+/// Os padrões são globs relativos ao `working_dir` da aplicação.
+/// Quando um arquivo que casa com `patterns` (e não com `ignore`)
+/// é alterado, a aplicação é reiniciada após o intervalo de debounce.
+#[derive(Clone, Serialize, Deserialize, Debug, PartialEq)]
+#[serde(default)]
+pub struct WatchConfig {
+    /// Se a observação de arquivos está habilitada
+    pub enabled: bool,
+    
+    /// Globs observados (ex: "**/*.py", "src/**/*.go")
+    pub patterns: Vec<String>,
+    
+    /// Globs ignorados (ex: "**/node_modules/**")
+    pub ignore: Vec<String>,
+    
+    /// Tempo de espera após a última alteração antes de reiniciar (ms)
+    pub debounce_ms: u64,
+}
+
+impl Default for WatchConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            patterns: Vec::new(),
+            ignore: Vec::new(),
+            debounce_ms: 500,
+        }
+    }
+}
+
+impl WatchConfig {
+    /// Globs ignorados sugeridos ao habilitar a observação
+    pub const DEFAULT_IGNORE: [&'static str; 5] = [
+        "**/.git/**",
+        "**/node_modules/**",
+        "**/target/**",
+        "**/__pycache__/**",
+        "**/*.log",
+    ];
+    
+    /// Verifica se a observação está habilitada e tem algo a observar
+    pub fn is_active(&self) -> bool {
+        self.enabled && !self.patterns.is_empty()
+    }
 }
 
 impl AppConfig {
@@ -50,30 +487,147 @@ impl AppConfig {
         }
     }
     
+    /// Verifica se a aplicação ainda precisa ser clonada: tem repositório
+    /// e a pasta de trabalho (já expandida) não existe ou está vazia
+    pub fn needs_clone(&self, working_dir: &str) -> bool {
+        self.repository.as_ref().is_some_and(|repo| !repo.url.trim().is_empty())
+            && !working_dir.trim().is_empty()
+            && std::fs::read_dir(working_dir).map_or(true, |mut entries| entries.next().is_none())
+    }
+    
     /// Verifica se a aplicação tem comandos configurados
     pub fn has_commands(&self) -> bool {
-        !self.commands.is_empty()
+        !self.commands.is_empty() || self.profiles.iter().any(|p| !p.commands.is_empty())
     }
     
     /// Retorna o número de comandos configurados
     pub fn command_count(&self) -> usize {
         self.commands.len()
     }
+    
+    /// Retorna uma cópia da aplicação que executa os passos de uma ação.
+    /// 
+    /// Usada para reaproveitar a geração de scripts: os comandos e a pasta
+    /// de trabalho passam a ser os da ação.
+    pub fn for_action(&self, action: &CustomAction) -> AppConfig {
+        let working_dir = if action.working_dir.trim().is_empty() {
+            self.working_dir.clone()
+        } else {
+            action.working_dir.clone()
+        };
+        AppConfig {
+            working_dir,
+            commands: action.commands.clone(),
+            env: EnvVar::merge(&self.env, &action.env),
+            ..self.clone()
+        }
+    }
+    
+    /// Retorna o valor de um parâmetro: o último informado ou o padrão
+    pub fn param_value(&self, param: &LaunchParam) -> String {
+        self.param_values
+            .get(&param.name)
+            .cloned()
+            .unwrap_or_else(|| param.default.clone())
+    }
+    
+    /// Retorna o perfil padrão (o marcado como padrão ou o primeiro)
+    pub fn default_profile(&self) -> Option<&LaunchProfile> {
+        self.profiles
+            .iter()
+            .find(|p| p.is_default)
+            .or_else(|| self.profiles.first())
+    }
+    
+    /// Retorna uma cópia da aplicação com os comandos e variáveis de um perfil.
+    /// 
+    /// # Argumentos
+    /// * `name` - Nome do perfil (`None` = perfil padrão)
+    /// 
+    /// # Retorno
+    /// A configuração resolvida e o nome do perfil usado, se houver.
+    /// 
+    /// # Exemplo
+    /// ```rust
+    /// use iris::core::{AppConfig, LaunchProfile};
+    ///
+    /// let app = AppConfig {
+    ///     commands: vec!["npm run dev".to_string()],
+    ///     profiles: vec![LaunchProfile {
+    ///         name: "prod".to_string(),
+    ///         commands: vec!["npm start".to_string()],
+    ///         ..Default::default()
+    ///     }],
+    ///     ..Default::default()
+    /// };
+    /// let (resolved, profile) = app.with_profile(None);
+    /// assert_eq!(resolved.commands, vec!["npm start".to_string()]);
+    /// assert_eq!(profile.as_deref(), Some("prod"));
+    /// ```
+    pub fn with_profile(&self, name: Option<&str>) -> (AppConfig, Option<String>) {
+        let profile = match name {
+            Some(name) => self.profiles.iter().find(|p| p.name == name),
+            None => self.default_profile(),
+        };
+        let Some(profile) = profile else {
+            return (self.clone(), None);
+        };
+        
+        let commands = if profile.commands.is_empty() {
+            self.commands.clone()
+        } else {
+            profile.commands.clone()
+        };
+        let resolved = AppConfig {
+            commands,
+            env: EnvVar::merge(&self.env, &profile.env),
+            ..self.clone()
+        };
+        (resolved, Some(profile.name.clone()))
+    }
 }
 
 /// Estado global da aplicação.
 /// 
 /// Contém a lista de todas as aplicações configuradas pelo usuário.
 /// Este estado é persistido em disco automaticamente.
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Clone, Serialize, Deserialize, Debug, PartialEq)]
 pub struct AppState {
+    /// Versão do formato do arquivo (ver `core::migrations`)
+    #[serde(default)]
+    pub schema_version: u32,
+    
     /// Lista de aplicações configuradas
     pub apps: Vec<AppConfig>,
+    
+    /// Configurações compartilhadas entre as aplicações
+    #[serde(default)]
+    pub workspace: WorkspaceSettings,
+    
+    /// Pastas de projeto com arquivo `.iris.toml`
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub projects: Vec<ProjectLink>,
+}
+
+/// Configurações do workspace usadas na interpolação de variáveis.
+#[derive(Clone, Serialize, Deserialize, Default, Debug, PartialEq)]
+#[serde(default)]
+pub struct WorkspaceSettings {
+    /// Pasta raiz dos projetos (`${workspace.root}`)
+    pub root: String,
+    
+    /// Variáveis globais definidas pelo usuário (`${NOME}`)
+    pub variables: Vec<EnvVar>,
 }
 
 impl Default for AppState {
     fn default() -> Self {
-        Self { apps: Vec::new() }
+        Self {
+            schema_version: super::migrations::CURRENT_SCHEMA_VERSION,
+            apps: Vec::new(),
+            workspace: WorkspaceSettings::default(),
+            projects: Vec::new(),
+        }
     }
 }
 
@@ -120,6 +674,9 @@ pub struct RunningProcess {
     /// PID do console Windows (cmd.exe)
     pub console_pid: Option<u32>,
     
+    /// Modo em que o processo foi iniciado
+    pub mode: RunMode,
+    
     /// Momento em que o processo foi iniciado
     #[allow(dead_code)]
     pub started_at: Instant,
@@ -127,10 +684,11 @@ pub struct RunningProcess {
 
 impl RunningProcess {
     /// Cria um novo registro de processo em execução
-    pub fn new(child: Child, console_pid: Option<u32>) -> Self {
+    pub fn new(child: Child, console_pid: Option<u32>, mode: RunMode) -> Self {
         Self {
             child,
             console_pid,
+            mode,
             started_at: Instant::now(),
         }
     }
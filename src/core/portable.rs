@@ -0,0 +1,251 @@
+//! # Exportação Portável
+//!
+//! Troca os caminhos absolutos de uma máquina por `${workspace.root}` ao
+//! exportar, e faz o caminho inverso ao importar, com os separadores do
+//! sistema local.
+//!
+//! ```rust
+//! use iris::core::portable::{localize_text, portable_text};
+//!
+//! let portable = portable_text("C:\\Projetos\\gateway\\src", "c:\\projetos");
+//! assert_eq!(portable, "${workspace.root}/gateway/src");
+//! assert_eq!(localize_text(&portable, "/home/ana/code", '/'), "/home/ana/code/gateway/src");
+//! ```
+
+use std::path::Path;
+
+use super::config::redact_strings;
+use super::models::{AppConfig, AppState, EnvVar, WorkspaceSettings};
+
+/// Marcador da pasta raiz nos arquivos portáveis
+pub const ROOT_PLACEHOLDER: &str = "${workspace.root}";
+
+/// Caracteres que encerram um caminho dentro de um comando
+fn ends_path(c: char) -> bool {
+    c.is_whitespace() || c == '"' || c == '\''
+}
+
+/// Caracteres que, antes da base, indicam que ela é parte de outro caminho
+fn continues_path(c: char) -> bool {
+    c.is_alphanumeric() || matches!(c, '/' | '\\' | '.' | '_' | '-' | ':')
+}
+
+/// Remove separadores finais (mantendo a raiz `/` ou `C:\`)
+fn trim_separators(path: &str) -> &str {
+    let trimmed = path.trim().trim_end_matches(['/', '\\']);
+    if trimmed.is_empty() || trimmed.ends_with(':') {
+        path.trim()
+    } else {
+        trimmed
+    }
+}
+
+/// Troca as ocorrências de `base` em um texto por `${workspace.root}`.
+///
+/// A comparação aceita `\` e `/` como equivalentes e, em caminhos do
+/// Windows (`C:\...`), ignora maiúsculas. O restante do caminho passa a
+/// usar `/`.
+pub fn portable_text(text: &str, base: &str) -> String {
+    let base = trim_separators(base);
+    if base.is_empty() {
+        return text.to_string();
+    }
+    let windows = base.as_bytes().get(1) == Some(&b':');
+    let normalize = |s: &str| {
+        let s = s.replace('\\', "/");
+        if windows {
+            s.to_ascii_lowercase()
+        } else {
+            s
+        }
+    };
+    // As duas normalizações preservam as posições em bytes
+    let haystack = normalize(text);
+    let needle = normalize(base);
+
+    let mut result = String::with_capacity(text.len());
+    let mut copied = 0;
+    let mut search = 0;
+    while let Some(found) = haystack[search..].find(&needle).map(|i| i + search) {
+        let end = found + needle.len();
+        let before_ok = text[..found].chars().next_back().is_none_or(|c| !continues_path(c));
+        let after_ok = text[end..].chars().next().is_none_or(|c| c == '/' || c == '\\' || ends_path(c));
+        if !before_ok || !after_ok {
+            search = end;
+            continue;
+        }
+        let rest_len = text[end..].find(ends_path).unwrap_or(text.len() - end);
+        result.push_str(&text[copied..found]);
+        result.push_str(ROOT_PLACEHOLDER);
+        result.push_str(&text[end..end + rest_len].replace('\\', "/"));
+        copied = end + rest_len;
+        search = copied;
+    }
+    result.push_str(&text[copied..]);
+    result
+}
+
+/// Troca `${workspace.root}` em um texto por `root`, usando `separator`
+/// no restante do caminho
+pub fn localize_text(text: &str, root: &str, separator: char) -> String {
+    let root = trim_separators(root);
+    let mut result = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(found) = rest.find(ROOT_PLACEHOLDER) {
+        result.push_str(&rest[..found]);
+        result.push_str(root);
+        rest = &rest[found + ROOT_PLACEHOLDER.len()..];
+        let path_len = rest.find(ends_path).unwrap_or(rest.len());
+        let path: String = rest[..path_len]
+            .chars()
+            .map(|c| if c == '/' || c == '\\' { separator } else { c })
+            .collect();
+        result.push_str(&path);
+        rest = &rest[path_len..];
+    }
+    result.push_str(rest);
+    result
+}
+
+/// Aplica uma transformação a todos os textos de uma aplicação
+fn map_strings(app: &AppConfig, transform: impl Fn(&str) -> String) -> AppConfig {
+    let Ok(mut value) = serde_json::to_value(app) else {
+        return app.clone();
+    };
+    redact_strings(&mut value, &transform);
+    serde_json::from_value(value).unwrap_or_else(|_| app.clone())
+}
+
+/// Versão portável de uma aplicação (caminhos sob `base` viram
+/// `${workspace.root}`)
+pub fn make_portable(app: &AppConfig, base: &str) -> AppConfig {
+    map_strings(app, |text| portable_text(text, base))
+}
+
+/// Versão local de uma aplicação portável (`${workspace.root}` vira `root`,
+/// com o separador do sistema)
+pub fn localize(app: &AppConfig, root: &str) -> AppConfig {
+    map_strings(app, |text| localize_text(text, root, std::path::MAIN_SEPARATOR))
+}
+
+/// Estado a exportar com as aplicações escolhidas.
+///
+/// Com `base`, os caminhos viram `${workspace.root}` e o que só faz
+/// sentido nesta máquina (pasta raiz e pastas de projeto) fica de fora.
+pub fn export_state(state: &AppState, ids: &[String], base: Option<&str>) -> AppState {
+    let apps = state.apps.iter().filter(|app| ids.contains(&app.id));
+    let Some(base) = base else {
+        return AppState {
+            apps: apps.cloned().collect(),
+            workspace: state.workspace.clone(),
+            ..Default::default()
+        };
+    };
+    AppState {
+        apps: apps
+            .map(|app| AppConfig {
+                project: None,
+                ..make_portable(app, base)
+            })
+            .collect(),
+        workspace: WorkspaceSettings {
+            root: String::new(),
+            variables: state
+                .workspace
+                .variables
+                .iter()
+                .map(|var| EnvVar {
+                    key: var.key.clone(),
+                    value: portable_text(&var.value, base),
+                })
+                .collect(),
+        },
+        ..Default::default()
+    }
+}
+
+/// Verifica se alguma aplicação usa `${workspace.root}`
+pub fn uses_root(apps: &[AppConfig]) -> bool {
+    apps.iter()
+        .filter_map(|app| serde_json::to_string(app).ok())
+        .any(|json| json.contains(ROOT_PLACEHOLDER))
+}
+
+/// Pasta comum às pastas de trabalho das aplicações (sugestão de base
+/// para a exportação)
+pub fn common_base(apps: &[AppConfig]) -> Option<String> {
+    let mut dirs = apps
+        .iter()
+        .map(|app| app.working_dir.trim())
+        .filter(|dir| !dir.is_empty() && !dir.contains("${"))
+        .map(Path::new);
+    let first = dirs.next()?;
+    let mut common = if apps.len() == 1 { first.parent()? } else { first };
+    for dir in dirs {
+        while !dir.starts_with(common) {
+            common = common.parent()?;
+        }
+    }
+    // A raiz do disco não serve como base
+    common.parent()?;
+    Some(common.display().to_string())
+}
+
+/// Pasta de trabalho que não existe nesta máquina (None = existe, está
+/// vazia ou depende de outras variáveis)
+pub fn missing_dir(app: &AppConfig) -> Option<&str> {
+    let dir = app.working_dir.trim();
+    (!dir.is_empty() && !dir.contains("${") && !Path::new(dir).is_dir()).then_some(dir)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::models::CustomAction;
+
+    #[test]
+    fn test_portable_text_only_replaces_whole_paths() {
+        assert_eq!(portable_text("/srv/app", "/srv/"), "${workspace.root}/app");
+        assert_eq!(portable_text("/data/srv/app", "/srv"), "/data/srv/app");
+        assert_eq!(portable_text("/srv2/app", "/srv"), "/srv2/app");
+        assert_eq!(portable_text("/SRV/app", "/srv"), "/SRV/app");
+        assert_eq!(
+            portable_text("docker compose -f \"C:\\proj\\api\\compose.yml\" up", "C:\\Proj"),
+            "docker compose -f \"${workspace.root}/api/compose.yml\" up"
+        );
+    }
+
+    #[test]
+    fn test_round_trip_between_machines() {
+        let app = AppConfig {
+            working_dir: "C:\\projetos\\gateway".into(),
+            commands: vec!["dotnet run --project C:\\projetos\\gateway\\src\\Api.csproj".into()],
+            actions: vec![CustomAction {
+                name: "Testes".into(),
+                working_dir: "C:\\projetos\\gateway\\tests".into(),
+                ..Default::default()
+            }],
+            ..AppConfig::new("Gateway".into())
+        };
+        let portable = make_portable(&app, "C:\\projetos");
+        assert_eq!(portable.working_dir, "${workspace.root}/gateway");
+        assert!(uses_root(std::slice::from_ref(&portable)));
+
+        let local = map_strings(&portable, |text| localize_text(text, "/home/ana/code/", '/'));
+        assert_eq!(local.working_dir, "/home/ana/code/gateway");
+        assert_eq!(local.commands[0], "dotnet run --project /home/ana/code/gateway/src/Api.csproj");
+        assert_eq!(local.actions[0].working_dir, "/home/ana/code/gateway/tests");
+        assert_eq!(missing_dir(&local), Some("/home/ana/code/gateway"));
+    }
+
+    #[test]
+    fn test_common_base() {
+        let app = |dir: &str| AppConfig {
+            working_dir: dir.into(),
+            ..Default::default()
+        };
+        assert_eq!(common_base(&[app("/home/ana/code/api"), app("/home/ana/code/web/app")]).as_deref(), Some("/home/ana/code"));
+        assert_eq!(common_base(&[app("/home/ana/code/api")]).as_deref(), Some("/home/ana/code"));
+        assert_eq!(common_base(&[app("/api"), app("/web")]), None);
+    }
+}
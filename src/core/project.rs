@@ -0,0 +1,299 @@
+//! # Arquivos de Projeto
+//!
+//! Permite versionar a receita de execução junto com o código: um arquivo
+//! `.iris.toml` (ou `.iris.json`) na raiz do repositório descreve uma ou
+//! mais aplicações, com pastas relativas à raiz.
+//!
+//! ## Exemplo de `.iris.toml`
+//! ```toml
+//! [[apps]]
+//! key = "api"
+//! name = "API"
+//! icon_emoji = "rust"
+//! working_dir = "services/api"
+//! commands = ["cargo run"]
+//! port = 8080
+//!
+//! [[apps]]
+//! name = "Web"
+//! working_dir = "web"
+//! commands = ["npm run dev"]
+//! ```
+//!
+//! Os campos são os mesmos do `config.json`. `key` identifica a aplicação
+//! dentro do projeto (padrão: derivada do nome). Ajustes feitos na
+//! interface ficam no `config.json` do usuário como substituições de
+//! campos, e o arquivo do repositório nunca é modificado.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use serde_json::{Map, Value};
+
+use super::formats::ConfigFormat;
+use super::history::content_hash;
+use super::models::{AppConfig, AppState, ProjectLink, ProjectSource};
+
+/// Nomes de arquivo de projeto reconhecidos, em ordem de preferência
+pub const PROJECT_FILE_NAMES: [&str; 2] = [".iris.toml", ".iris.json"];
+
+/// Campos que não podem ser substituídos localmente
+const FIXED_FIELDS: [&str; 2] = ["id", "project"];
+
+/// Procura o arquivo de projeto na raiz informada
+pub fn find_project_file(root: &Path) -> Option<PathBuf> {
+    PROJECT_FILE_NAMES
+        .iter()
+        .map(|name| root.join(name))
+        .find(|path| path.is_file())
+}
+
+/// ID estável de uma aplicação de projeto (mesmo projeto e chave = mesmo ID)
+pub fn project_app_id(root: &str, key: &str) -> String {
+    format!("project-{}-{}", &content_hash(root)[..12], key)
+}
+
+/// Carrega as aplicações definidas no arquivo de projeto de uma pasta.
+///
+/// As pastas relativas são resolvidas a partir da raiz, e cada aplicação
+/// recebe um ID estável e a marcação de origem.
+pub fn load_project(root: &Path) -> Result<Vec<AppConfig>, String> {
+    let path = find_project_file(root).ok_or_else(|| {
+        format!(
+            "Nenhum arquivo de projeto ({}) em \"{}\"",
+            PROJECT_FILE_NAMES.join(", "),
+            root.display()
+        )
+    })?;
+    let content = fs::read_to_string(&path)
+        .map_err(|e| format!("Erro ao ler \"{}\": {}", path.display(), e))?;
+    let value = ConfigFormat::for_file(&path, &content)
+        .parse(&content)
+        .map_err(|e| format!("Erro em \"{}\": {}", path.display(), e))?;
+    parse_project(root, value)
+}
+
+/// Converte o conteúdo de um arquivo de projeto em aplicações
+fn parse_project(root: &Path, value: Value) -> Result<Vec<AppConfig>, String> {
+    let root_text = root.display().to_string();
+    let apps = value
+        .get("apps")
+        .and_then(Value::as_array)
+        .ok_or("O arquivo de projeto deve ter uma lista \"apps\"")?;
+
+    let mut result: Vec<AppConfig> = Vec::new();
+    for (i, app) in apps.iter().enumerate() {
+        let mut fields = app
+            .as_object()
+            .cloned()
+            .ok_or_else(|| format!("Aplicação {} não é uma tabela", i + 1))?;
+        let name = fields
+            .get("name")
+            .and_then(Value::as_str)
+            .map(str::trim)
+            .filter(|name| !name.is_empty())
+            .ok_or_else(|| format!("Aplicação {} sem \"name\"", i + 1))?
+            .to_string();
+        let key = match fields.remove("key") {
+            Some(Value::String(key)) if !key.trim().is_empty() => key.trim().to_string(),
+            Some(_) => return Err(format!("Aplicação \"{}\": \"key\" inválida", name)),
+            None => slug(&name),
+        };
+        if result.iter().any(|app| app.project.as_ref().is_some_and(|p| p.key == key)) {
+            return Err(format!("Chave repetida no arquivo de projeto: \"{}\"", key));
+        }
+
+        let working_dir = fields.get("working_dir").and_then(Value::as_str).unwrap_or("");
+        let working_dir = resolve_dir(root, working_dir);
+        fields.insert("working_dir".to_string(), Value::from(working_dir));
+        fields.insert("id".to_string(), Value::from(project_app_id(&root_text, &key)));
+        fields.entry("icon_emoji").or_insert_with(|| Value::from(""));
+        fields.entry("commands").or_insert_with(|| Value::Array(Vec::new()));
+
+        let mut app: AppConfig = serde_json::from_value(Value::Object(fields))
+            .map_err(|e| format!("Aplicação \"{}\": {}", name, e))?;
+        app.project = Some(ProjectSource {
+            root: root_text.clone(),
+            key,
+        });
+        result.push(app);
+    }
+    Ok(result)
+}
+
+/// Resolve a pasta de uma aplicação relativa à raiz do projeto
+fn resolve_dir(root: &Path, dir: &str) -> String {
+    let dir = dir.trim();
+    if dir.is_empty() || dir == "." {
+        root.display().to_string()
+    } else if Path::new(dir).is_absolute() || dir.starts_with("${") {
+        dir.to_string()
+    } else {
+        root.join(dir).display().to_string()
+    }
+}
+
+/// Chave derivada do nome (ex: "Minha API" -> "minha-api")
+fn slug(name: &str) -> String {
+    let mut slug = String::new();
+    for c in name.to_lowercase().chars() {
+        if c.is_ascii_alphanumeric() {
+            slug.push(c);
+        } else if !slug.ends_with('-') {
+            slug.push('-');
+        }
+    }
+    slug.trim_matches('-').to_string()
+}
+
+/// Aplica os ajustes locais a uma aplicação do projeto
+pub fn apply_overrides(app: &AppConfig, overrides: &Map<String, Value>) -> AppConfig {
+    if overrides.is_empty() {
+        return app.clone();
+    }
+    let Ok(Value::Object(mut fields)) = serde_json::to_value(app) else {
+        return app.clone();
+    };
+    for (field, value) in overrides {
+        if !FIXED_FIELDS.contains(&field.as_str()) {
+            fields.insert(field.clone(), value.clone());
+        }
+    }
+    serde_json::from_value(Value::Object(fields)).unwrap_or_else(|_| app.clone())
+}
+
+/// Calcula os campos em que a versão editada difere da definida no projeto
+pub fn compute_overrides(project_app: &AppConfig, edited: &AppConfig) -> Map<String, Value> {
+    let (Ok(Value::Object(base)), Ok(Value::Object(edited))) =
+        (serde_json::to_value(project_app), serde_json::to_value(edited))
+    else {
+        return Map::new();
+    };
+    edited
+        .into_iter()
+        .filter(|(field, value)| !FIXED_FIELDS.contains(&field.as_str()) && base.get(field) != Some(value))
+        .collect()
+}
+
+/// Atualiza as aplicações de um projeto no estado a partir do arquivo.
+///
+/// Aplicações existentes mantêm a posição (e o ID, usado pelos processos
+/// em execução); novas vão para o final; as que saíram do arquivo são
+/// removidas.
+///
+/// # Retorno
+/// `true` se a lista de aplicações mudou.
+pub fn sync_project(state: &mut AppState, root: &str) -> Result<bool, String> {
+    let project_apps = load_project(Path::new(root))?;
+    let overrides = state
+        .projects
+        .iter()
+        .find(|link| link.root == root)
+        .map(|link| link.overrides.clone())
+        .unwrap_or_default();
+    let effective: Vec<AppConfig> = project_apps
+        .iter()
+        .map(|app| {
+            let key = app.project.as_ref().map(|p| p.key.as_str()).unwrap_or_default();
+            overrides.get(key).map_or_else(|| app.clone(), |o| apply_overrides(app, o))
+        })
+        .collect();
+
+    let before = state.apps.clone();
+    state
+        .apps
+        .retain(|app| !is_from(app, root) || effective.iter().any(|e| e.id == app.id));
+    for app in effective {
+        match state.apps.iter().position(|a| a.id == app.id) {
+            Some(index) => {
+                // Valores lembrados dos parâmetros são da máquina, não do repositório
+                let param_values = std::mem::take(&mut state.apps[index].param_values);
+                state.apps[index] = AppConfig { param_values, ..app };
+            }
+            None => state.apps.push(app),
+        }
+    }
+    if !state.projects.iter().any(|link| link.root == root) {
+        state.projects.push(ProjectLink {
+            root: root.to_string(),
+            overrides: Default::default(),
+        });
+    }
+    Ok(state.apps != before)
+}
+
+/// Verifica se a aplicação pertence ao projeto da raiz informada
+pub fn is_from(app: &AppConfig, root: &str) -> bool {
+    app.project.as_ref().is_some_and(|p| p.root == root)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn temp_project(name: &str, content: &str) -> PathBuf {
+        let root = std::env::temp_dir().join(format!("iris_project_{}_{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&root);
+        fs::create_dir_all(&root).unwrap();
+        fs::write(root.join(".iris.toml"), content).unwrap();
+        root
+    }
+
+    #[test]
+    fn test_loads_toml_with_relative_dirs() {
+        let root = temp_project(
+            "load",
+            r#"
+[[apps]]
+name = "Minha API"
+working_dir = "services/api"
+commands = ["cargo run"]
+port = 8080
+
+[[apps]]
+key = "web"
+name = "Web"
+"#,
+        );
+        let apps = load_project(&root).unwrap();
+        assert_eq!(apps.len(), 2);
+        assert_eq!(apps[0].project.as_ref().unwrap().key, "minha-api");
+        assert_eq!(apps[0].working_dir, root.join("services/api").display().to_string());
+        assert_eq!(apps[0].port, Some(8080));
+        assert_eq!(apps[1].working_dir, root.display().to_string());
+        assert_eq!(apps[1].id, project_app_id(&root.display().to_string(), "web"));
+        let _ = fs::remove_dir_all(&root);
+    }
+
+    #[test]
+    fn test_sync_keeps_positions_and_local_overrides() {
+        let root = temp_project("sync", "[[apps]]\nname = \"API\"\ncommands = [\"cargo run\"]\n");
+        let root_text = root.display().to_string();
+        let mut state = AppState::default();
+        state.add_app(AppConfig::new("Local".to_string()));
+
+        assert!(sync_project(&mut state, &root_text).unwrap());
+        assert_eq!(state.apps[1].name, "API");
+
+        // Ajuste local: comando diferente
+        let mut edited = state.apps[1].clone();
+        edited.commands = vec!["cargo run --release".to_string()];
+        let project_app = load_project(&root).unwrap().remove(0);
+        let overrides = compute_overrides(&project_app, &edited);
+        assert_eq!(overrides.keys().collect::<Vec<_>>(), vec!["commands"]);
+        state.projects[0].overrides.insert("api".to_string(), overrides);
+
+        // O repositório muda o nome e adiciona uma aplicação
+        fs::write(
+            root.join(".iris.toml"),
+            "[[apps]]\nkey = \"api\"\nname = \"API v2\"\ncommands = [\"cargo run\"]\n\n[[apps]]\nname = \"Worker\"\n",
+        )
+        .unwrap();
+        assert!(sync_project(&mut state, &root_text).unwrap());
+        let names: Vec<&str> = state.apps.iter().map(|a| a.name.as_str()).collect();
+        assert_eq!(names, vec!["Local", "API v2", "Worker"]);
+        assert_eq!(state.apps[1].commands, vec!["cargo run --release"]);
+        assert!(!sync_project(&mut state, &root_text).unwrap());
+        let _ = fs::remove_dir_all(&root);
+    }
+}
@@ -0,0 +1,122 @@
+//! # Lixeira
+//!
+//! Aplicações excluídas ficam na lixeira (`trash.json`, ao lado do
+//! `config.json`) até serem restauradas ou excluídas definitivamente.
+
+use std::fs;
+use std::path::PathBuf;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use serde::{Deserialize, Serialize};
+
+use super::config::write_atomic;
+use super::models::AppConfig;
+
+/// Aplicação na lixeira
+#[derive(Clone, Serialize, Deserialize, Debug)]
+pub struct TrashedApp {
+    /// Configuração da aplicação excluída
+    pub app: AppConfig,
+    /// Momento da exclusão (segundos desde UNIX epoch)
+    pub deleted_at: u64,
+}
+
+/// Lixeira persistida em disco
+pub struct Trash {
+    /// Caminho do arquivo da lixeira
+    path: PathBuf,
+    /// Itens, do mais antigo para o mais recente
+    items: Vec<TrashedApp>,
+}
+
+impl Trash {
+    /// Carrega a lixeira do arquivo (vazia se não existir ou for inválido)
+    pub fn load(path: PathBuf) -> Self {
+        let items = fs::read_to_string(&path)
+            .ok()
+            .and_then(|content| serde_json::from_str(&content).ok())
+            .unwrap_or_default();
+        Self { path, items }
+    }
+
+    /// Itens da lixeira, do mais antigo para o mais recente
+    pub fn items(&self) -> &[TrashedApp] {
+        &self.items
+    }
+
+    /// Move aplicações para a lixeira
+    pub fn push(&mut self, apps: Vec<AppConfig>) -> Result<(), String> {
+        if apps.is_empty() {
+            return Ok(());
+        }
+        let deleted_at = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_secs();
+        self.items.extend(apps.into_iter().map(|app| TrashedApp { app, deleted_at }));
+        self.save()
+    }
+
+    /// Retira um item da lixeira, retornando a aplicação para ser restaurada
+    pub fn take(&mut self, index: usize) -> Result<AppConfig, String> {
+        if index >= self.items.len() {
+            return Err("Item não encontrado na lixeira".to_string());
+        }
+        let item = self.items.remove(index);
+        self.save()?;
+        Ok(item.app)
+    }
+
+    /// Remove da lixeira as aplicações com os IDs informados
+    /// (ex: aplicações que voltaram à lista ao desfazer uma exclusão)
+    pub fn forget(&mut self, app_ids: &[&str]) -> Result<(), String> {
+        let before = self.items.len();
+        self.items.retain(|item| !app_ids.contains(&item.app.id.as_str()));
+        if self.items.len() == before {
+            return Ok(());
+        }
+        self.save()
+    }
+
+    /// Exclui um item definitivamente
+    pub fn purge(&mut self, index: usize) -> Result<(), String> {
+        self.take(index).map(|_| ())
+    }
+
+    /// Esvazia a lixeira
+    pub fn empty(&mut self) -> Result<(), String> {
+        self.items.clear();
+        self.save()
+    }
+
+    /// Grava a lixeira em disco
+    fn save(&self) -> Result<(), String> {
+        let json = serde_json::to_string_pretty(&self.items)
+            .map_err(|e| format!("Erro ao serializar lixeira: {}", e))?;
+        write_atomic(&self.path, &json).map_err(|e| format!("Erro ao salvar lixeira: {}", e))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_trash_persists_and_restores() {
+        let path = std::env::temp_dir().join(format!("iris_trash_{}.json", std::process::id()));
+        let _ = fs::remove_file(&path);
+
+        let api = AppConfig::new("API".to_string());
+        let web = AppConfig::new("Web".to_string());
+        let mut trash = Trash::load(path.clone());
+        trash.push(vec![api.clone(), web.clone()]).unwrap();
+
+        let mut reloaded = Trash::load(path.clone());
+        assert_eq!(reloaded.items().len(), 2);
+        assert_eq!(reloaded.take(0).unwrap().id, api.id);
+
+        reloaded.forget(&[web.id.as_str()]).unwrap();
+        assert!(Trash::load(path.clone()).items().is_empty());
+        let _ = fs::remove_file(&path);
+    }
+}
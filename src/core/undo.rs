@@ -0,0 +1,160 @@
+//! # Desfazer/Refazer
+//!
+//! Pilha de alterações da configuração (edições, exclusões, reordenações,
+//! importações, projetos e variáveis), guardadas como cópias do estado
+//! completo: aplicações, workspace e projetos.
+
+use super::models::AppState;
+
+/// Quantidade máxima de alterações guardadas
+pub const MAX_UNDO: usize = 50;
+
+/// Cópia do estado antes (ou depois) de uma alteração
+struct Snapshot {
+    /// Descrição da alteração (ex: "Excluir API")
+    label: String,
+    /// Estado completo
+    state: AppState,
+}
+
+/// Pilha de desfazer/refazer da sessão.
+///
+/// # Exemplo
+/// ```rust
+/// use iris::core::{AppConfig, AppState, UndoStack};
+///
+/// let mut state = AppState::default();
+/// state.apps.push(AppConfig::new("API".to_string()));
+/// let mut undo = UndoStack::default();
+///
+/// undo.record("Excluir API", &state);
+/// state.apps.clear();
+///
+/// let (label, restored) = undo.undo(&state).unwrap();
+/// assert_eq!(label, "Excluir API");
+/// assert_eq!(restored.apps.len(), 1);
+/// ```
+#[derive(Default)]
+pub struct UndoStack {
+    undo: Vec<Snapshot>,
+    redo: Vec<Snapshot>,
+}
+
+impl UndoStack {
+    /// Registra uma alteração a partir do estado anterior a ela.
+    ///
+    /// Descarta o que havia para refazer.
+    pub fn record(&mut self, label: &str, before: &AppState) {
+        self.undo.push(Snapshot {
+            label: label.to_string(),
+            state: before.clone(),
+        });
+        if self.undo.len() > MAX_UNDO {
+            self.undo.remove(0);
+        }
+        self.redo.clear();
+    }
+
+    /// Desfaz a última alteração.
+    ///
+    /// # Argumentos
+    /// * `current` - Estado atual, guardado para refazer
+    ///
+    /// # Retorno
+    /// A descrição da alteração e o estado a restaurar.
+    pub fn undo(&mut self, current: &AppState) -> Option<(String, AppState)> {
+        let snapshot = self.undo.pop()?;
+        self.redo.push(Snapshot {
+            label: snapshot.label.clone(),
+            state: current.clone(),
+        });
+        Some((snapshot.label, snapshot.state))
+    }
+
+    /// Refaz a última alteração desfeita.
+    ///
+    /// # Argumentos
+    /// * `current` - Estado atual, guardado para desfazer novamente
+    pub fn redo(&mut self, current: &AppState) -> Option<(String, AppState)> {
+        let snapshot = self.redo.pop()?;
+        self.undo.push(Snapshot {
+            label: snapshot.label.clone(),
+            state: current.clone(),
+        });
+        Some((snapshot.label, snapshot.state))
+    }
+
+    /// Descrição da alteração que seria desfeita
+    pub fn undo_label(&self) -> Option<&str> {
+        self.undo.last().map(|s| s.label.as_str())
+    }
+
+    /// Descrição da alteração que seria refeita
+    pub fn redo_label(&self) -> Option<&str> {
+        self.redo.last().map(|s| s.label.as_str())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::{AppConfig, ProjectLink};
+
+    fn state(names: &[&str]) -> AppState {
+        AppState {
+            apps: names.iter().map(|name| AppConfig::new(name.to_string())).collect(),
+            ..AppState::default()
+        }
+    }
+
+    #[test]
+    fn test_undo_redo_roundtrip_and_new_change_clears_redo() {
+        let mut stack = UndoStack::default();
+        let v1 = state(&["API"]);
+        let v2 = state(&["API", "Web"]);
+
+        stack.record("Adicionar Web", &v1);
+        let (_, restored) = stack.undo(&v2).unwrap();
+        assert_eq!(restored.apps.len(), 1);
+        assert_eq!(stack.redo_label(), Some("Adicionar Web"));
+
+        let (_, redone) = stack.redo(&restored).unwrap();
+        assert_eq!(redone.apps.len(), 2);
+        assert!(stack.redo(&redone).is_none());
+
+        stack.undo(&redone).unwrap();
+        stack.record("Excluir API", &restored);
+        assert!(stack.redo_label().is_none());
+        assert_eq!(stack.undo_label(), Some("Excluir API"));
+    }
+
+    #[test]
+    fn test_keeps_only_the_latest_changes() {
+        let mut stack = UndoStack::default();
+        for i in 0..MAX_UNDO + 5 {
+            stack.record(&format!("Alteração {}", i), &AppState::default());
+        }
+        let mut count = 0;
+        while stack.undo(&AppState::default()).is_some() {
+            count += 1;
+        }
+        assert_eq!(count, MAX_UNDO);
+    }
+
+    #[test]
+    fn test_restores_workspace_and_projects() {
+        let mut stack = UndoStack::default();
+        let before = state(&["API"]);
+        let mut after = before.clone();
+        after.workspace.root = "/srv".to_string();
+        after.projects.push(ProjectLink { root: "/srv/loja".to_string(), ..Default::default() });
+
+        stack.record("Adicionar projeto loja", &before);
+        let (_, restored) = stack.undo(&after).unwrap();
+        assert!(restored.projects.is_empty());
+        assert!(restored.workspace.root.is_empty());
+
+        let (_, redone) = stack.redo(&restored).unwrap();
+        assert_eq!(redone, after);
+    }
+}
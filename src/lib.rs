@@ -12,11 +12,17 @@
 //! Contém as estruturas de dados fundamentais:
 //! - `models`: Definições de `AppConfig`, `AppState`, `RunningProcess`, etc.
 //! - `config`: Gerenciamento de configurações e persistência em JSON
+//! - `interpolation`: Variáveis `${...}` expandidas na execução
 //! 
 //! ### Services (`services/`)
 //! Serviços de negócio independentes:
 //! - `process_manager`: Gerenciamento do ciclo de vida de processos
 //! - `icon_service`: Carregamento e cache de ícones SVG
+//! - `file_watcher`: Observação de arquivos e reinício automático
+//! - `log_monitor`: Captura de saída e regras de log (pronto, erro, URL)
+//! - `pty_session`: Terminal embutido interativo
+//! - `task_runner`: Ações personalizadas (testes, lint, migrações)
+//! - `secret_store`: Segredos cifrados referenciados como `${secret:NOME}`
 //! 
 //! ### UI (`ui/`)
 //! Componentes de interface gráfica:
@@ -17,14 +17,8 @@
 use std::sync::Arc;
 use eframe::egui;
 
-// Importa os modulos da aplicacao
-mod core;
-mod services;
-mod ui;
-mod utils;
-
-/// Versao atual da aplicacao
-pub const VERSION: &str = "1.0.0";
+// Importa os modulos da biblioteca
+use iris::ui;
 
 /// Carrega o icone da janela a partir do arquivo PNG embutido.
 fn load_icon() -> Option<egui::IconData> {
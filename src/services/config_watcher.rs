@@ -0,0 +1,94 @@
+//! # Config Watcher Service
+//!
+//! Observa o `config.json` para recarregar alterações feitas fora do
+//! Iris (editor de texto, sincronização de dotfiles).
+//!
+//! A pasta do arquivo é observada (e não o arquivo em si), pois
+//! gravações atômicas substituem o arquivo por renomeação.
+
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use eframe::egui;
+use notify::{EventKind, RecommendedWatcher, RecursiveMode, Watcher};
+
+/// Tempo sem novas alterações antes de recarregar
+const RELOAD_DEBOUNCE: Duration = Duration::from_millis(300);
+
+/// Observador do arquivo de configuração
+pub struct ConfigWatcher {
+    /// Mantém o watcher vivo (None se não foi possível observar)
+    _watcher: Option<RecommendedWatcher>,
+    /// Momento da última alteração ainda não tratada
+    changed: Arc<Mutex<Option<Instant>>>,
+}
+
+impl ConfigWatcher {
+    /// Começa a observar o arquivo de configuração.
+    ///
+    /// Falhas ao criar o observador são registradas e o recarregamento
+    /// automático fica desabilitado.
+    pub fn new(config_path: &Path, ctx: egui::Context) -> Self {
+        let changed = Arc::new(Mutex::new(None));
+        let watcher = Self::create_watcher(config_path.to_path_buf(), Arc::clone(&changed), ctx)
+            .map_err(|e| eprintln!("Recarregamento automático desabilitado: {}", e))
+            .ok();
+        Self {
+            _watcher: watcher,
+            changed,
+        }
+    }
+
+    /// Cria o watcher da pasta do arquivo
+    fn create_watcher(
+        config_path: PathBuf,
+        changed: Arc<Mutex<Option<Instant>>>,
+        ctx: egui::Context,
+    ) -> Result<RecommendedWatcher, String> {
+        let dir = config_path
+            .parent()
+            .ok_or("Caminho de configuração sem pasta")?
+            .to_path_buf();
+        let file_name = config_path.file_name().map(|name| name.to_os_string());
+
+        let mut watcher = notify::recommended_watcher(move |res: notify::Result<notify::Event>| {
+            let Ok(event) = res else {
+                return;
+            };
+            if matches!(event.kind, EventKind::Access(_) | EventKind::Other) {
+                return;
+            }
+            let relevant = event
+                .paths
+                .iter()
+                .any(|path| path.file_name().map(|name| name.to_os_string()) == file_name);
+            if relevant {
+                *changed.lock().unwrap() = Some(Instant::now());
+                ctx.request_repaint_after(RELOAD_DEBOUNCE);
+            }
+        })
+        .map_err(|e| format!("Erro ao criar observador: {}", e))?;
+
+        watcher
+            .watch(&dir, RecursiveMode::NonRecursive)
+            .map_err(|e| format!("Erro ao observar \"{}\": {}", dir.display(), e))?;
+        Ok(watcher)
+    }
+
+    /// Retorna `true` (uma vez) quando o arquivo mudou e o intervalo de
+    /// debounce já passou.
+    ///
+    /// Inclui as gravações do próprio Iris; quem chama deve comparar o
+    /// conteúdo com o último gravado.
+    pub fn take_changed(&self) -> bool {
+        let mut changed = self.changed.lock().unwrap();
+        match *changed {
+            Some(at) if at.elapsed() >= RELOAD_DEBOUNCE => {
+                *changed = None;
+                true
+            }
+            _ => false,
+        }
+    }
+}
@@ -0,0 +1,298 @@
+//! # File Watcher Service
+//!
+//! Serviço responsável por observar alterações de arquivos nos
+//! diretórios das aplicações e sinalizar quando elas devem ser reiniciadas.
+//!
+//! ## Funcionalidades
+//! - Observação recursiva do `working_dir` via inotify/FSEvents/ReadDirectoryChanges
+//! - Filtro por globs de inclusão e exclusão relativos ao `working_dir`
+//! - Debounce das alterações para evitar reinícios em rajada
+//! - Pausa temporária da observação por aplicação
+
+use std::collections::{HashMap, HashSet};
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use eframe::egui;
+use globset::{Glob, GlobSet, GlobSetBuilder};
+use notify::{EventKind, RecommendedWatcher, RecursiveMode, Watcher};
+
+use crate::core::{AppConfig, WatchConfig};
+
+/// Estado de observação de uma aplicação, usado pela interface.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum WatchStatus {
+    /// Observação desabilitada ou não configurada
+    Off,
+    /// Observando alterações
+    Active,
+    /// Observação pausada temporariamente
+    Paused,
+}
+
+/// Filtro de caminhos baseado em globs de inclusão e exclusão.
+pub struct WatchMatcher {
+    include: GlobSet,
+    exclude: GlobSet,
+}
+
+impl WatchMatcher {
+    /// Compila os globs de inclusão e exclusão.
+    ///
+    /// # Retorno
+    /// Retorna o filtro ou uma mensagem indicando o glob inválido.
+    pub fn new(patterns: &[String], ignore: &[String]) -> Result<Self, String> {
+        Ok(Self {
+            include: build_glob_set(patterns)?,
+            exclude: build_glob_set(ignore)?,
+        })
+    }
+
+    /// Verifica se um caminho relativo ao `working_dir` deve disparar reinício
+    pub fn matches(&self, relative_path: &Path) -> bool {
+        self.include.is_match(relative_path) && !self.exclude.is_match(relative_path)
+    }
+}
+
+fn build_glob_set(patterns: &[String]) -> Result<GlobSet, String> {
+    let mut builder = GlobSetBuilder::new();
+    for pattern in patterns.iter().map(|p| p.trim()).filter(|p| !p.is_empty()) {
+        let glob = Glob::new(pattern)
+            .map_err(|e| format!("Glob inválido \"{}\": {}", pattern, e))?;
+        builder.add(glob);
+    }
+    builder
+        .build()
+        .map_err(|e| format!("Erro ao compilar globs: {}", e))
+}
+
+/// Observador ativo de uma aplicação
+struct ActiveWatch {
+    /// Mantém o watcher vivo enquanto a observação estiver ativa
+    _watcher: RecommendedWatcher,
+    /// Configuração usada para criar o watcher (para detectar mudanças)
+    working_dir: String,
+    config: WatchConfig,
+}
+
+/// Observador de arquivos das aplicações.
+///
+/// Mantém um watcher por aplicação com observação habilitada e acumula
+/// as alterações detectadas até que o intervalo de debounce expire.
+///
+/// # Exemplo
+/// ```rust,no_run
+/// use iris::services::FileWatcher;
+///
+/// let mut watcher = FileWatcher::new(eframe::egui::Context::default());
+/// watcher.sync(&[]);
+/// for app_id in watcher.take_due() {
+///     println!("Reiniciar {}", app_id);
+/// }
+/// ```
+pub struct FileWatcher {
+    /// Watchers ativos (app_id -> ActiveWatch)
+    watchers: HashMap<String, ActiveWatch>,
+
+    /// Aplicações com observação pausada
+    paused: Arc<Mutex<HashSet<String>>>,
+
+    /// Alterações pendentes (app_id -> (última alteração, debounce))
+    pending: Arc<Mutex<HashMap<String, (Instant, Duration)>>>,
+
+    /// Contexto usado para acordar a interface quando há alterações
+    ctx: egui::Context,
+}
+
+impl FileWatcher {
+    /// Cria um novo observador sem nenhuma aplicação registrada
+    pub fn new(ctx: egui::Context) -> Self {
+        Self {
+            watchers: HashMap::new(),
+            paused: Arc::new(Mutex::new(HashSet::new())),
+            pending: Arc::new(Mutex::new(HashMap::new())),
+            ctx,
+        }
+    }
+
+    /// Sincroniza os watchers com a lista de aplicações.
+    ///
+    /// Cria watchers para aplicações com observação habilitada, recria
+    /// os que tiveram a configuração alterada e remove os obsoletos.
+    ///
+    /// # Retorno
+    /// Lista de erros `(app_id, mensagem)` das aplicações que não puderam ser observadas.
+    pub fn sync(&mut self, apps: &[AppConfig]) -> Vec<(String, String)> {
+        let mut errors = Vec::new();
+        let wanted: HashSet<&str> = apps
+            .iter()
+            .filter(|app| app.watch.is_active() && !app.working_dir.is_empty())
+            .map(|app| app.id.as_str())
+            .collect();
+
+        self.watchers.retain(|id, _| wanted.contains(id.as_str()));
+
+        for app in apps.iter().filter(|app| wanted.contains(app.id.as_str())) {
+            let up_to_date = self.watchers.get(&app.id).is_some_and(|active| {
+                active.working_dir == app.working_dir && active.config == app.watch
+            });
+            if up_to_date {
+                continue;
+            }
+
+            self.watchers.remove(&app.id);
+            match self.create_watch(app) {
+                Ok(active) => {
+                    self.watchers.insert(app.id.clone(), active);
+                }
+                Err(e) => errors.push((app.id.clone(), e)),
+            }
+        }
+
+        let mut pending = self.pending.lock().unwrap();
+        pending.retain(|id, _| self.watchers.contains_key(id));
+
+        errors
+    }
+
+    /// Cria o watcher de uma aplicação
+    fn create_watch(&self, app: &AppConfig) -> Result<ActiveWatch, String> {
+        let matcher = WatchMatcher::new(&app.watch.patterns, &app.watch.ignore)?;
+        let root = PathBuf::from(&app.working_dir);
+        let app_id = app.id.clone();
+        let debounce = Duration::from_millis(app.watch.debounce_ms);
+        let pending = Arc::clone(&self.pending);
+        let paused = Arc::clone(&self.paused);
+        let ctx = self.ctx.clone();
+        let watch_root = root.clone();
+
+        let mut watcher = notify::recommended_watcher(move |res: notify::Result<notify::Event>| {
+            let Ok(event) = res else {
+                return;
+            };
+            if matches!(event.kind, EventKind::Access(_) | EventKind::Other) {
+                return;
+            }
+            if paused.lock().unwrap().contains(&app_id) {
+                return;
+            }
+
+            let relevant = event.paths.iter().any(|path| {
+                path.strip_prefix(&watch_root)
+                    .map(|relative| matcher.matches(relative))
+                    .unwrap_or(false)
+            });
+
+            if relevant {
+                pending
+                    .lock()
+                    .unwrap()
+                    .insert(app_id.clone(), (Instant::now(), debounce));
+                ctx.request_repaint_after(debounce);
+            }
+        })
+        .map_err(|e| format!("Erro ao criar observador: {}", e))?;
+
+        watcher
+            .watch(&root, RecursiveMode::Recursive)
+            .map_err(|e| format!("Erro ao observar \"{}\": {}", root.display(), e))?;
+
+        Ok(ActiveWatch {
+            _watcher: watcher,
+            working_dir: app.working_dir.clone(),
+            config: app.watch.clone(),
+        })
+    }
+
+    /// Retorna as aplicações cujo debounce expirou e devem ser reiniciadas.
+    ///
+    /// As aplicações retornadas são removidas da lista de pendentes.
+    pub fn take_due(&self) -> Vec<String> {
+        self.take_due_at(Instant::now())
+    }
+
+    fn take_due_at(&self, now: Instant) -> Vec<String> {
+        let mut pending = self.pending.lock().unwrap();
+        let due: Vec<String> = pending
+            .iter()
+            .filter(|(_, (last_change, debounce))| now.duration_since(*last_change) >= *debounce)
+            .map(|(id, _)| id.clone())
+            .collect();
+
+        for id in &due {
+            pending.remove(id);
+        }
+
+        due
+    }
+
+    /// Verifica se há alterações aguardando o debounce
+    pub fn has_pending(&self) -> bool {
+        !self.pending.lock().unwrap().is_empty()
+    }
+
+    /// Retorna o estado de observação de uma aplicação
+    pub fn status(&self, app_id: &str) -> WatchStatus {
+        if !self.watchers.contains_key(app_id) {
+            WatchStatus::Off
+        } else if self.paused.lock().unwrap().contains(app_id) {
+            WatchStatus::Paused
+        } else {
+            WatchStatus::Active
+        }
+    }
+
+    /// Alterna a pausa da observação de uma aplicação.
+    ///
+    /// Alterações pendentes são descartadas ao pausar.
+    pub fn toggle_pause(&self, app_id: &str) {
+        let mut paused = self.paused.lock().unwrap();
+        if !paused.remove(app_id) {
+            paused.insert(app_id.to_string());
+            self.pending.lock().unwrap().remove(app_id);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn strings(values: &[&str]) -> Vec<String> {
+        values.iter().map(|v| v.to_string()).collect()
+    }
+
+    #[test]
+    fn test_matcher_include_and_ignore() {
+        let matcher = WatchMatcher::new(
+            &strings(&["**/*.py"]),
+            &strings(&["**/__pycache__/**"]),
+        ).unwrap();
+
+        assert!(matcher.matches(Path::new("main.py")));
+        assert!(matcher.matches(Path::new("app/worker.py")));
+        assert!(!matcher.matches(Path::new("app/__pycache__/worker.py")));
+        assert!(!matcher.matches(Path::new("README.md")));
+    }
+
+    #[test]
+    fn test_matcher_invalid_glob() {
+        let result = WatchMatcher::new(&strings(&["src/[*.go"]), &[]);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_take_due_respects_debounce() {
+        let watcher = FileWatcher::new(egui::Context::default());
+        let start = Instant::now();
+        watcher.pending.lock().unwrap().insert(
+            "app".to_string(),
+            (start, Duration::from_millis(500)),
+        );
+
+        assert!(watcher.take_due_at(start + Duration::from_millis(100)).is_empty());
+        assert_eq!(watcher.take_due_at(start + Duration::from_millis(600)), vec!["app".to_string()]);
+        assert!(!watcher.has_pending());
+    }
+}
@@ -0,0 +1,148 @@
+//! # Health Check Service
+//!
+//! Consulta a URL de health-check de uma aplicação (GET HTTP simples, sem
+//! dependências externas) e a marca como pronta quando ela responde.
+//!
+//! - Apenas `http://` é suportado (health-checks costumam ser locais)
+//! - Respostas 2xx e 3xx contam como sucesso
+//! - A verificação começa depois que a execução anterior terminou e pode
+//!   ser cancelada ao parar a aplicação
+
+use std::io::{Read, Write};
+use std::net::{TcpStream, ToSocketAddrs};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use super::log_monitor::LogMonitor;
+
+/// Intervalo entre as tentativas
+const PROBE_INTERVAL: Duration = Duration::from_secs(1);
+
+/// Tempo máximo de cada tentativa (conexão e leitura)
+const PROBE_TIMEOUT: Duration = Duration::from_secs(2);
+
+/// Tempo máximo aguardando a aplicação responder
+const READY_TIMEOUT: Duration = Duration::from_secs(120);
+
+/// Verificação pendente de uma execução
+pub struct HealthCheck {
+    /// URL já expandida
+    pub url: String,
+    /// ID da aplicação (para o monitor de logs)
+    pub app_id: String,
+    /// Monitor que recebe o estado "pronta"
+    pub logs: LogMonitor,
+    /// Marcado ao parar ou reiniciar a aplicação
+    pub cancel: Arc<AtomicBool>,
+}
+
+impl HealthCheck {
+    /// Consulta a URL em uma thread separada até a aplicação responder,
+    /// o tempo acabar ou a verificação ser cancelada
+    pub fn spawn(self) {
+        std::thread::spawn(move || self.run());
+    }
+
+    fn run(self) {
+        let started = Instant::now();
+        while !self.cancel.load(Ordering::Relaxed) {
+            match probe(&self.url) {
+                Ok(status) if status < 400 => {
+                    self.logs.mark_ready(&self.app_id);
+                    return;
+                }
+                Err(e) if e.starts_with("URL") => {
+                    self.logs.push_line(&self.app_id, &format!("[IRIS] Health-check: {}", e));
+                    return;
+                }
+                _ => {}
+            }
+            if started.elapsed() >= READY_TIMEOUT {
+                self.logs.push_line(
+                    &self.app_id,
+                    &format!("[IRIS] Health-check sem sucesso após {}s: {}", READY_TIMEOUT.as_secs(), self.url),
+                );
+                return;
+            }
+            std::thread::sleep(PROBE_INTERVAL);
+        }
+    }
+}
+
+/// Faz um GET na URL e retorna o código de status HTTP.
+///
+/// Erros de URL começam com "URL" (não adianta tentar de novo).
+pub fn probe(url: &str) -> Result<u16, String> {
+    let rest = url
+        .trim()
+        .strip_prefix("http://")
+        .ok_or_else(|| format!("URL não suportada (use http://): {}", url))?;
+    let (authority, path) = match rest.find('/') {
+        Some(index) => (&rest[..index], &rest[index..]),
+        None => (rest, "/"),
+    };
+    if authority.is_empty() {
+        return Err(format!("URL sem servidor: {}", url));
+    }
+    let address = if authority.rsplit_once(':').is_some_and(|(_, port)| port.parse::<u16>().is_ok()) {
+        authority.to_string()
+    } else {
+        format!("{}:80", authority)
+    };
+
+    let socket = address
+        .to_socket_addrs()
+        .map_err(|e| format!("Erro ao resolver {}: {}", authority, e))?
+        .next()
+        .ok_or_else(|| format!("Erro ao resolver {}", authority))?;
+    let mut stream = TcpStream::connect_timeout(&socket, PROBE_TIMEOUT).map_err(|e| e.to_string())?;
+    stream.set_read_timeout(Some(PROBE_TIMEOUT)).map_err(|e| e.to_string())?;
+    stream.set_write_timeout(Some(PROBE_TIMEOUT)).map_err(|e| e.to_string())?;
+    let request = format!("GET {} HTTP/1.0\r\nHost: {}\r\nConnection: close\r\n\r\n", path, authority);
+    stream.write_all(request.as_bytes()).map_err(|e| e.to_string())?;
+
+    // Só a linha de status interessa ("HTTP/1.1 200 OK")
+    let mut head = [0u8; 64];
+    let read = stream.read(&mut head).map_err(|e| e.to_string())?;
+    String::from_utf8_lossy(&head[..read])
+        .split_whitespace()
+        .nth(1)
+        .and_then(|code| code.parse().ok())
+        .ok_or_else(|| "Resposta HTTP inválida".to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::net::TcpListener;
+
+    #[test]
+    fn test_probe_reads_status_and_marks_ready() {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let port = listener.local_addr().unwrap().port();
+        std::thread::spawn(move || {
+            for (i, stream) in listener.incoming().enumerate() {
+                let mut stream = stream.unwrap();
+                let mut request = [0u8; 256];
+                let _ = stream.read(&mut request);
+                let status = if i == 0 { "503 Service Unavailable" } else { "200 OK" };
+                let _ = stream.write_all(format!("HTTP/1.1 {}\r\n\r\n", status).as_bytes());
+            }
+        });
+
+        let url = format!("http://127.0.0.1:{}/health", port);
+        assert_eq!(probe(&url), Ok(503));
+        assert!(probe("https://localhost/health").unwrap_err().starts_with("URL"));
+
+        let logs = LogMonitor::new();
+        HealthCheck {
+            url,
+            app_id: "api".to_string(),
+            logs: logs.clone(),
+            cancel: Arc::new(AtomicBool::new(false)),
+        }
+        .run();
+        assert!(logs.status("api").ready);
+    }
+}
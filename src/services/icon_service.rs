@@ -22,7 +22,7 @@ include!(concat!(env!("OUT_DIR"), "/embedded_icons.rs"));
 static ICONS: OnceLock<HashMap<&'static str, &'static str>> = OnceLock::new();
 
 fn get_icons() -> &'static HashMap<&'static str, &'static str> {
-    ICONS.get_or_init(|| get_embedded_icons())
+    ICONS.get_or_init(get_embedded_icons)
 }
 
 /// Cache de texturas dos ícones.
@@ -54,7 +54,7 @@ impl IconCache {
     /// # Retorno
     /// `Some(TextureHandle)` se o ícone foi carregado com sucesso,
     /// `None` se o ícone não existe ou falhou ao carregar.
-        pub fn get_or_load(&mut self, ctx: &egui::Context, icon_name: &str) -> Option<TextureHandle> {
+    pub fn get_or_load(&mut self, ctx: &egui::Context, icon_name: &str) -> Option<TextureHandle> {
         // Verifica se já está no cache
         if let Some(texture) = self.textures.get(icon_name) {
             return Some(texture.clone());
@@ -150,6 +150,8 @@ pub fn render_svg_to_image(svg_data: &str, width: u32, height: u32) -> Option<Co
 /// 
 /// # Exemplo
 /// ```rust
+/// use iris::services::load_available_icons;
+///
 /// let icons = load_available_icons();
 /// for icon in icons {
 ///     println!("Ícone disponível: {}", icon.name);
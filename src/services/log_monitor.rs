@@ -0,0 +1,325 @@
+//! # Log Monitor Service
+//!
+//! Serviço responsável por armazenar a saída capturada das aplicações
+//! executadas em segundo plano e avaliar as regras de log sobre ela.
+//!
+//! ## Funcionalidades
+//! - Buffer circular das últimas linhas de cada aplicação
+//! - Remoção de códigos de cor ANSI
+//! - Regras "pronto", "erro" e "extrair URL" baseadas em regex
+//! - Mascaramento dos valores de segredos
+
+use std::collections::{HashMap, VecDeque};
+use std::sync::{Arc, Mutex};
+
+use regex::Regex;
+
+use crate::core::{LogRule, LogRuleKind};
+use super::secret_store::mask_secrets;
+
+/// Número máximo de linhas mantidas por aplicação
+pub const MAX_LOG_LINES: usize = 2000;
+
+/// Resultado da avaliação das regras em uma linha
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct LineMatch {
+    /// Alguma regra "pronto" casou
+    pub ready: bool,
+    /// Alguma regra "erro" casou
+    pub error: bool,
+    /// URL extraída por uma regra "extrair URL"
+    pub url: Option<String>,
+}
+
+impl LineMatch {
+    /// Verifica se alguma regra casou com a linha
+    pub fn any(&self) -> bool {
+        self.ready || self.error || self.url.is_some()
+    }
+}
+
+/// Conjunto de regras de log compiladas.
+#[derive(Clone, Default)]
+pub struct RuleSet {
+    rules: Vec<(LogRuleKind, Regex)>,
+}
+
+impl RuleSet {
+    /// Compila as regras de uma aplicação.
+    ///
+    /// # Retorno
+    /// Retorna o conjunto compilado ou a mensagem da primeira regra inválida.
+    pub fn compile(rules: &[LogRule]) -> Result<Self, String> {
+        let mut compiled = Vec::new();
+        for rule in rules.iter().filter(|r| !r.pattern.is_empty()) {
+            compiled.push((rule.kind, compile_rule(rule)?));
+        }
+        Ok(Self { rules: compiled })
+    }
+
+    /// Avalia todas as regras em uma linha
+    pub fn evaluate(&self, line: &str) -> LineMatch {
+        let mut result = LineMatch::default();
+        for (kind, regex) in &self.rules {
+            match kind {
+                LogRuleKind::Ready => result.ready |= regex.is_match(line),
+                LogRuleKind::Error => result.error |= regex.is_match(line),
+                LogRuleKind::ExtractUrl => {
+                    if result.url.is_none() {
+                        result.url = extract(regex, line);
+                    }
+                }
+            }
+        }
+        result
+    }
+
+    /// Verifica se há alguma regra do tipo informado
+    pub fn has_kind(&self, kind: LogRuleKind) -> bool {
+        self.rules.iter().any(|(k, _)| *k == kind)
+    }
+}
+
+/// Compila uma única regra, com mensagem de erro amigável
+pub fn compile_rule(rule: &LogRule) -> Result<Regex, String> {
+    Regex::new(&rule.pattern)
+        .map_err(|e| format!("Regra \"{}\" inválida: {}", rule.pattern, e))
+}
+
+/// Extrai o primeiro grupo de captura, ou o match inteiro se não houver grupos
+fn extract(regex: &Regex, line: &str) -> Option<String> {
+    let captures = regex.captures(line)?;
+    captures
+        .get(1)
+        .or_else(|| captures.get(0))
+        .map(|m| m.as_str().trim().to_string())
+}
+
+/// Remove sequências de escape ANSI (cores, cursor) de uma linha
+pub fn strip_ansi(line: &str) -> String {
+    let mut result = String::with_capacity(line.len());
+    let mut chars = line.chars().peekable();
+    while let Some(c) = chars.next() {
+        if c == '\u{1b}' {
+            if chars.peek() == Some(&'[') {
+                chars.next();
+                // Consome parâmetros até o byte final (0x40..=0x7E)
+                for next in chars.by_ref() {
+                    if ('@'..='~').contains(&next) {
+                        break;
+                    }
+                }
+            }
+            continue;
+        }
+        if c != '\r' {
+            result.push(c);
+        }
+    }
+    result
+}
+
+/// Estado do log de uma aplicação, exibido no card
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct LogStatus {
+    /// Se a aplicação já sinalizou que está pronta
+    pub ready: bool,
+    /// Número de linhas que casaram com regras de erro
+    pub error_count: usize,
+    /// URL extraída da saída
+    pub url: Option<String>,
+    /// Se há saída capturada disponível
+    pub has_output: bool,
+}
+
+/// Log capturado de uma aplicação
+#[derive(Default)]
+struct AppLog {
+    lines: VecDeque<String>,
+    status: LogStatus,
+    rules: RuleSet,
+    /// Segredos mascarados desde o início da execução, mantidos mesmo
+    /// depois que o cofre é bloqueado
+    masked: Vec<String>,
+}
+
+/// Monitor de logs das aplicações.
+///
+/// Thread-safe: pode ser clonado e compartilhado com as threads
+/// que leem a saída dos processos.
+#[derive(Clone, Default)]
+pub struct LogMonitor {
+    logs: Arc<Mutex<HashMap<String, AppLog>>>,
+    /// Valores de segredos mascarados em todas as linhas
+    masked: Arc<Mutex<Vec<String>>>,
+}
+
+impl LogMonitor {
+    /// Cria um monitor vazio
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Inicia um novo log para uma execução, descartando o anterior.
+    ///
+    /// Os segredos mascarados no momento continuam mascarados nesta
+    /// execução, mesmo que o cofre seja bloqueado com o processo rodando.
+    pub fn start(&self, app_id: &str, rules: RuleSet) {
+        let masked = self.masked.lock().unwrap().clone();
+        let mut logs = self.logs.lock().unwrap();
+        logs.insert(
+            app_id.to_string(),
+            AppLog {
+                rules,
+                masked,
+                ..Default::default()
+            },
+        );
+    }
+
+    /// Define os valores de segredos que nunca devem aparecer nos logs
+    pub fn set_masked_values(&self, values: Vec<String>) {
+        *self.masked.lock().unwrap() = values;
+    }
+    
+    /// Adiciona uma linha de saída ao log da aplicação e avalia as regras
+    pub fn push_line(&self, app_id: &str, line: &str) {
+        let line = mask_secrets(&strip_ansi(line), &self.masked.lock().unwrap());
+        let mut logs = self.logs.lock().unwrap();
+        let log = logs.entry(app_id.to_string()).or_default();
+        let line = mask_secrets(&line, &log.masked);
+
+        let matched = log.rules.evaluate(&line);
+        if matched.ready {
+            log.status.ready = true;
+        }
+        if matched.error {
+            log.status.error_count += 1;
+        }
+        if matched.url.is_some() {
+            log.status.url = matched.url;
+        }
+
+        if log.lines.len() >= MAX_LOG_LINES {
+            log.lines.pop_front();
+        }
+        log.lines.push_back(line);
+        log.status.has_output = true;
+    }
+
+    /// Marca a aplicação como não pronta (ex: ao parar ou reiniciar)
+    pub fn mark_stopped(&self, app_id: &str) {
+        if let Some(log) = self.logs.lock().unwrap().get_mut(app_id) {
+            log.status.ready = false;
+        }
+    }
+
+    /// Marca a aplicação como pronta (ex: o health-check respondeu)
+    pub fn mark_ready(&self, app_id: &str) {
+        let mut logs = self.logs.lock().unwrap();
+        logs.entry(app_id.to_string()).or_default().status.ready = true;
+    }
+
+    /// Zera o contador de erros da aplicação
+    pub fn reset_errors(&self, app_id: &str) {
+        if let Some(log) = self.logs.lock().unwrap().get_mut(app_id) {
+            log.status.error_count = 0;
+        }
+    }
+
+    /// Retorna o estado do log da aplicação
+    pub fn status(&self, app_id: &str) -> LogStatus {
+        self.logs
+            .lock()
+            .unwrap()
+            .get(app_id)
+            .map(|log| log.status.clone())
+            .unwrap_or_default()
+    }
+
+    /// Retorna as últimas `max` linhas capturadas da aplicação
+    pub fn recent_lines(&self, app_id: &str, max: usize) -> Vec<String> {
+        self.logs
+            .lock()
+            .unwrap()
+            .get(app_id)
+            .map(|log| {
+                let skip = log.lines.len().saturating_sub(max);
+                log.lines.iter().skip(skip).cloned().collect()
+            })
+            .unwrap_or_default()
+    }
+
+    /// Descarta as linhas capturadas da aplicação
+    pub fn clear(&self, app_id: &str) {
+        if let Some(log) = self.logs.lock().unwrap().get_mut(app_id) {
+            log.lines.clear();
+            log.status.has_output = false;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rule(kind: LogRuleKind, pattern: &str) -> LogRule {
+        LogRule {
+            kind,
+            pattern: pattern.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_rules_ready_error_and_url() {
+        let rules = RuleSet::compile(&[
+            rule(LogRuleKind::Ready, r"Local:\s+http://"),
+            rule(LogRuleKind::Error, r"(?i)error"),
+            rule(LogRuleKind::ExtractUrl, r"Local:\s+(http://\S+)"),
+        ]).unwrap();
+
+        let matched = rules.evaluate("  ➜  Local:   http://localhost:5173/");
+        assert!(matched.ready);
+        assert!(!matched.error);
+        assert_eq!(matched.url.as_deref(), Some("http://localhost:5173/"));
+
+        assert!(rules.evaluate("TypeError: x is undefined").error);
+    }
+
+    #[test]
+    fn test_invalid_rule() {
+        assert!(RuleSet::compile(&[rule(LogRuleKind::Ready, "(unclosed")]).is_err());
+    }
+
+    #[test]
+    fn test_strip_ansi() {
+        assert_eq!(strip_ansi("\u{1b}[32mVITE\u{1b}[0m ready\r"), "VITE ready");
+    }
+
+    #[test]
+    fn test_monitor_applies_rules() {
+        let monitor = LogMonitor::new();
+        monitor.start("app", RuleSet::compile(&[rule(LogRuleKind::Ready, "listening")]).unwrap());
+        monitor.push_line("app", "starting...");
+        assert!(!monitor.status("app").ready);
+
+        monitor.push_line("app", "listening on :8080");
+        assert!(monitor.status("app").ready);
+        assert_eq!(monitor.recent_lines("app", 1), vec!["listening on :8080".to_string()]);
+    }
+
+    #[test]
+    fn test_keeps_masking_after_lock() {
+        let monitor = LogMonitor::new();
+        monitor.set_masked_values(vec!["s3cr3t".to_string()]);
+        monitor.start("app", RuleSet::default());
+        // Bloquear o cofre limpa a lista global, mas a execução continua mascarada
+        monitor.set_masked_values(Vec::new());
+        monitor.push_line("app", "token=s3cr3t");
+        assert!(!monitor.recent_lines("app", 1)[0].contains("s3cr3t"));
+
+        monitor.start("app", RuleSet::default());
+        monitor.push_line("app", "token=s3cr3t");
+        assert_eq!(monitor.recent_lines("app", 1), vec!["token=s3cr3t".to_string()]);
+    }
+}
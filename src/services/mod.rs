@@ -6,9 +6,33 @@
 //! ## Serviços Disponíveis
 //! - `process_manager`: Gerenciamento de processos (start, stop, restart)
 //! - `icon_service`: Carregamento e cache de ícones SVG
+//! - `file_watcher`: Observação de arquivos para reinício automático
+//! - `log_monitor`: Captura de saída e regras de log
+//! - `pty_session`: Terminal embutido (pseudo-terminal + grade VT100)
+//! - `task_runner`: Ações personalizadas executadas como tarefas avulsas
+//! - `secret_store`: Cofre local de segredos cifrado com senha
+//! - `config_watcher`: Recarregamento do `config.json` editado externamente
+//! - `script_export`: Exportação como scripts, Procfile, Makefile ou units do systemd
+//! - `health_check`: Verificação da URL de health-check das aplicações
 
 pub mod process_manager;
 pub mod icon_service;
+pub mod file_watcher;
+pub mod log_monitor;
+pub mod pty_session;
+pub mod task_runner;
+pub mod secret_store;
+pub mod config_watcher;
+pub mod script_export;
+pub mod health_check;
 
 pub use process_manager::*;
 pub use icon_service::*;
+pub use file_watcher::*;
+pub use log_monitor::*;
+pub use pty_session::*;
+pub use task_runner::*;
+pub use secret_store::*;
+pub use config_watcher::*;
+pub use script_export::*;
+pub use health_check::*;
@@ -5,6 +5,9 @@
 //! 
 //! ## Funcionalidades
 //! - Iniciar processos em terminais Windows
+//! - Iniciar processos em segundo plano com captura de saída
+//! - Iniciar processos em um terminal embutido (pseudo-terminal)
+//! - Enviar entrada (stdin) e sinais ao grupo de processos
 //! - Parar processos em execução
 //! - Reiniciar processos
 //! - Monitorar estado dos processos
@@ -12,31 +15,90 @@
 
 use std::collections::{HashMap, HashSet};
 use std::fs;
-use std::process::{Child, Command};
+use std::io::{BufRead, BufReader, Read, Write};
+use std::path::{Path, PathBuf};
+use std::process::{Child, Command, Stdio};
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::{Arc, Mutex};
+use std::thread::JoinHandle;
 use std::time::Duration;
 
 #[cfg(windows)]
 use std::os::windows::process::CommandExt;
+#[cfg(unix)]
+use std::os::unix::process::CommandExt as UnixCommandExt;
 
-use crate::core::{AppConfig, RunningProcess};
+use portable_pty::ChildKiller;
+
+use crate::core::{AppConfig, EnvVar, Interpolator, RunMode, RunningProcess};
+use super::health_check::HealthCheck;
+use super::log_monitor::{LogMonitor, RuleSet};
+use super::pty_session::PtySession;
 
 /// Flags de criação do Windows para ocultar janelas de comando
 #[cfg(windows)]
 const CREATE_NO_WINDOW: u32 = 0x08000000;
 
+/// Sinais que podem ser enviados a uma aplicação em execução.
+/// 
+/// São aplicados ao grupo de processos da aplicação, atingindo também
+/// os processos filhos (ex: `node` iniciado por `npm run dev`).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum AppSignal {
+    /// SIGHUP: recarregar configuração
+    Hup,
+    /// SIGUSR1: sinal definido pela aplicação
+    Usr1,
+    /// SIGINT: "Ctrl+C educado"
+    Int,
+    /// SIGSTOP: pausar a execução
+    Stop,
+    /// SIGCONT: retomar a execução
+    Cont,
+}
+
+impl AppSignal {
+    /// Sinais exibidos nos menus, na ordem de exibição
+    pub const MENU: [AppSignal; 3] = [AppSignal::Hup, AppSignal::Usr1, AppSignal::Int];
+    
+    /// Nome do sinal aceito pelo comando `kill -s`
+    pub fn name(&self) -> &'static str {
+        match self {
+            AppSignal::Hup => "HUP",
+            AppSignal::Usr1 => "USR1",
+            AppSignal::Int => "INT",
+            AppSignal::Stop => "STOP",
+            AppSignal::Cont => "CONT",
+        }
+    }
+    
+    /// Texto exibido na interface
+    pub fn label(&self) -> &'static str {
+        match self {
+            AppSignal::Hup => "↻ Recarregar (SIGHUP)",
+            AppSignal::Usr1 => "✉ SIGUSR1",
+            AppSignal::Int => "✋ Ctrl+C (SIGINT)",
+            AppSignal::Stop => "⏸ Pausar (SIGSTOP)",
+            AppSignal::Cont => "▶ Retomar (SIGCONT)",
+        }
+    }
+}
+
 /// Gerenciador de Processos.
 /// 
 /// Controla o ciclo de vida de todos os processos das aplicações.
 /// Thread-safe através de Arc<Mutex>.
 /// 
 /// # Exemplo
-/// ```rust
+/// ```rust,no_run
+/// use iris::{AppConfig, ProcessManager};
+///
+/// let app_config = AppConfig::new("Gateway".to_string());
 /// let manager = ProcessManager::new();
 /// manager.launch_app(&app_config);
 /// 
 /// if manager.is_running(&app_config.id) {
-///     manager.stop_app(&app_config.id);
+///     manager.stop_app(&app_config.id, Some(&app_config.name), Some(&app_config.commands));
 /// }
 /// ```
 pub struct ProcessManager {
@@ -45,6 +107,24 @@ pub struct ProcessManager {
     
     /// Conjunto de aplicações em processo de inicialização
     loading_apps: Arc<Mutex<HashSet<String>>>,
+    
+    /// Saída capturada das aplicações em segundo plano
+    logs: LogMonitor,
+    
+    /// Sessões de terminal embutido (app_id -> PtySession)
+    terminals: Arc<Mutex<HashMap<String, PtySession>>>,
+    
+    /// Aplicações pausadas com SIGSTOP
+    paused_apps: Arc<Mutex<HashSet<String>>>,
+    
+    /// Perfil usado na última execução (app_id -> nome do perfil)
+    active_profiles: Arc<Mutex<HashMap<String, String>>>,
+    
+    /// Encerramentos em andamento (app_id -> thread que mata o processo)
+    stopping: Arc<Mutex<HashMap<String, JoinHandle<()>>>>,
+    
+    /// Health-checks em andamento (app_id -> sinal de cancelamento)
+    health_checks: Arc<Mutex<HashMap<String, Arc<AtomicBool>>>>,
 }
 
 impl ProcessManager {
@@ -53,9 +133,20 @@ impl ProcessManager {
         Self {
             running_apps: Arc::new(Mutex::new(HashMap::new())),
             loading_apps: Arc::new(Mutex::new(HashSet::new())),
+            logs: LogMonitor::new(),
+            terminals: Arc::new(Mutex::new(HashMap::new())),
+            paused_apps: Arc::new(Mutex::new(HashSet::new())),
+            active_profiles: Arc::new(Mutex::new(HashMap::new())),
+            stopping: Arc::new(Mutex::new(HashMap::new())),
+            health_checks: Arc::new(Mutex::new(HashMap::new())),
         }
     }
     
+    /// Retorna o monitor de logs das aplicações em segundo plano
+    pub fn logs(&self) -> &LogMonitor {
+        &self.logs
+    }
+    
     /// Retorna uma referência Arc para os processos em execução
     pub fn running_apps(&self) -> Arc<Mutex<HashMap<String, RunningProcess>>> {
         Arc::clone(&self.running_apps)
@@ -95,35 +186,131 @@ impl ProcessManager {
         let app_clone = app.clone();
         let running_apps = Arc::clone(&self.running_apps);
         let loading_apps = Arc::clone(&self.loading_apps);
+        let pending_stop = self.stopping.lock().unwrap().remove(&app.id);
+        let health = self.health_check(app);
+
+        if app.run_mode.captures_output() {
+            // Regra inválida (config editada à mão ou importada): a execução
+            // segue sem regras e o motivo aparece no log
+            match RuleSet::compile(&app.log_rules) {
+                Ok(rules) => self.logs.start(&app.id, rules),
+                Err(e) => {
+                    self.logs.start(&app.id, RuleSet::default());
+                    self.logs.push_line(&app.id, &format!("[IRIS] Regras de log ignoradas: {}", e));
+                }
+            }
+        }
+        
+        if app.run_mode == RunMode::Terminal {
+            let terminals = Arc::clone(&self.terminals);
+            let logs = self.logs.clone();
+            std::thread::spawn(move || {
+                Self::wait_stopped(pending_stop, health);
+                Self::launch_terminal(&app_clone, &terminals, &logs);
+                loading_apps.lock().unwrap().remove(&app_clone.id);
+            });
+            return;
+        }
+
+        if app.run_mode == RunMode::Headless {
+            let logs = self.logs.clone();
+            std::thread::spawn(move || {
+                Self::wait_stopped(pending_stop, health);
+                Self::launch_headless_in_thread(app_clone, running_apps, loading_apps, logs);
+            });
+            return;
+        }
 
         // Executa em uma thread separada para não bloquear a UI
         std::thread::spawn(move || {
+            Self::wait_stopped(pending_stop, health);
             Self::launch_in_thread(app_clone, running_apps, loading_apps);
         });
     }
     
+    /// Aguarda o encerramento anterior da aplicação, se houver, antes de
+    /// iniciá-la de novo (dá tempo para o terminal antigo fechar).
+    /// 
+    /// O health-check só começa depois, para não consultar a execução antiga.
+    fn wait_stopped(pending: Option<JoinHandle<()>>, health: Option<HealthCheck>) {
+        if let Some(handle) = pending {
+            let _ = handle.join();
+            std::thread::sleep(Duration::from_millis(200));
+        }
+        if let Some(check) = health {
+            check.spawn();
+        }
+    }
+    
+    /// Prepara o health-check da execução, cancelando o anterior
+    fn health_check(&self, app: &AppConfig) -> Option<HealthCheck> {
+        let mut checks = self.health_checks.lock().unwrap();
+        if let Some(previous) = checks.remove(&app.id) {
+            previous.store(true, Ordering::Relaxed);
+        }
+        if app.health_check_url.trim().is_empty() {
+            return None;
+        }
+        let cancel = Arc::new(AtomicBool::new(false));
+        checks.insert(app.id.clone(), Arc::clone(&cancel));
+        Some(HealthCheck {
+            url: app.health_check_url.trim().to_string(),
+            app_id: app.id.clone(),
+            logs: self.logs.clone(),
+            cancel,
+        })
+    }
+    
+    /// Inicia uma aplicação com um perfil de execução.
+    /// 
+    /// As variáveis `${...}` são expandidas antes da execução; em caso de
+    /// erro a aplicação não é iniciada e a mensagem vai para o log.
+    /// 
+    /// # Argumentos
+    /// * `app` - Configuração da aplicação
+    /// * `profile` - Nome do perfil (`None` = perfil padrão)
+    /// * `vars` - Variáveis disponíveis para interpolação
+    pub fn launch_profile(&self, app: &AppConfig, profile: Option<&str>, vars: &Interpolator) -> Result<(), String> {
+        let (resolved, profile) = app.with_profile(profile);
+        let resolved = vars.expand_app(&resolved).inspect_err(|e| {
+            self.logs.push_line(&app.id, &format!("[IRIS] {}", e));
+        })?;
+        self.launch_app(&resolved);
+        if let Some(profile) = profile {
+            self.active_profiles.lock().unwrap().insert(app.id.clone(), profile);
+        }
+        Ok(())
+    }
+    
+    /// Retorna o perfil da execução atual da aplicação
+    pub fn active_profile(&self, app_id: &str) -> Option<String> {
+        self.active_profiles.lock().unwrap().get(app_id).cloned()
+    }
+    
     /// Lógica de inicialização executada em thread separada
     fn launch_in_thread(
         app: AppConfig,
         running_apps: Arc<Mutex<HashMap<String, RunningProcess>>>,
         loading_apps: Arc<Mutex<HashSet<String>>>,
     ) {
-        // Cria um arquivo batch temporário
-        let temp_dir = std::env::temp_dir();
-        let batch_file = temp_dir.join(format!("iris_{}.bat", app.id));
-        
-        // Monta o conteúdo do batch
-        let batch_content = Self::build_batch_content(&app);
-        
-        if fs::write(&batch_file, &batch_content).is_err() {
+        // Cria um arquivo batch temporário (as variáveis de ambiente vão
+        // pelo processo, não pelo arquivo)
+        let batch_file = Self::script_dir()
+            .map(|dir| dir.join(format!("iris_{}.bat", app.id)))
+            .and_then(|batch_file| {
+                let batch_content = Self::build_batch_content(&Self::without_env(&app));
+                Self::write_private(&batch_file, &batch_content).map(|_| batch_file)
+            });
+        let Ok(batch_file) = batch_file else {
             let mut loading = loading_apps.lock().unwrap();
             loading.remove(&app.id);
             return;
-        }
+        };
 
         // Executa o batch
         let child = Command::new("cmd")
             .args(["/C", "start", "", &batch_file.to_string_lossy()])
+            .envs(app.env.iter().map(|var| (&var.key, &var.value)))
             .spawn();
 
         // Aguarda um pouco para o console abrir
@@ -134,7 +321,7 @@ impl ProcessManager {
             let console_pid = Self::find_console_pid(&app.name);
             
             let mut running = running_apps.lock().unwrap();
-            running.insert(app.id.clone(), RunningProcess::new(child, console_pid));
+            running.insert(app.id.clone(), RunningProcess::new(child, console_pid, RunMode::External));
         }
         
         // Remove do loading
@@ -142,19 +329,260 @@ impl ProcessManager {
         loading.remove(&app.id);
     }
     
+    /// Lógica de inicialização em segundo plano, com captura de stdout/stderr.
+    /// 
+    /// Os comandos são gravados em um script temporário (`.bat` no Windows,
+    /// `.sh` nos demais sistemas) executado sem janela. No Unix o processo
+    /// recebe seu próprio grupo para que possa ser encerrado com os filhos.
+    fn launch_headless_in_thread(
+        app: AppConfig,
+        running_apps: Arc<Mutex<HashMap<String, RunningProcess>>>,
+        loading_apps: Arc<Mutex<HashSet<String>>>,
+        logs: LogMonitor,
+    ) {
+        let spawned = Self::write_script(&app, &format!("iris_{}", app.id))
+            .and_then(|script_file| Self::spawn_headless(&script_file, &app.env));
+        
+        match spawned {
+            Ok(mut child) => {
+                if let Some(stdout) = child.stdout.take() {
+                    Self::pipe_output(stdout, app.id.clone(), logs.clone());
+                }
+                if let Some(stderr) = child.stderr.take() {
+                    Self::pipe_output(stderr, app.id.clone(), logs.clone());
+                }
+                
+                let mut running = running_apps.lock().unwrap();
+                running.insert(app.id.clone(), RunningProcess::new(child, None, RunMode::Headless));
+            }
+            Err(e) => logs.push_line(&app.id, &format!("[IRIS] {}", e)),
+        }
+        
+        let mut loading = loading_apps.lock().unwrap();
+        loading.remove(&app.id);
+    }
+    
+    /// Inicia a aplicação em um terminal embutido (executado fora da
+    /// thread da UI, depois que a execução anterior terminou).
+    /// 
+    /// A sessão anterior (se houver) é substituída; a nova sessão fica
+    /// disponível para a interface até a próxima execução.
+    fn launch_terminal(app: &AppConfig, terminals: &Mutex<HashMap<String, PtySession>>, logs: &LogMonitor) {
+        let session = Self::write_script(app, &format!("iris_{}", app.id))
+            .and_then(|script_file| PtySession::spawn(&script_file, &app.env, &app.id, logs.clone()));
+        
+        match session {
+            Ok(session) => {
+                terminals.lock().unwrap().insert(app.id.clone(), session);
+            }
+            Err(e) => logs.push_line(&app.id, &format!("[IRIS] {}", e)),
+        }
+    }
+    
+    /// Executa uma função com a sessão de terminal embutido da aplicação
+    pub fn with_terminal<R>(&self, app_id: &str, f: impl FnOnce(&mut PtySession) -> R) -> Option<R> {
+        let mut terminals = self.terminals.lock().unwrap();
+        terminals.get_mut(app_id).map(f)
+    }
+    
+    /// Verifica se a aplicação tem uma sessão de terminal embutido
+    pub fn has_terminal(&self, app_id: &str) -> bool {
+        self.terminals.lock().unwrap().contains_key(app_id)
+    }
+    
+    /// Grava o script sem janela da aplicação na pasta de scripts do usuário.
+    /// 
+    /// Gera um `.bat` no Windows e um `.sh` nos demais sistemas. As
+    /// variáveis de ambiente ficam fora do arquivo (veja `spawn_headless`),
+    /// e o script se apaga ao iniciar (no Windows, ao terminar), já que os
+    /// comandos podem conter segredos expandidos.
+    /// 
+    /// # Argumentos
+    /// * `app` - Aplicação cujos comandos serão gravados
+    /// * `file_stem` - Nome do arquivo, sem extensão
+    pub(crate) fn write_script(app: &AppConfig, file_stem: &str) -> Result<PathBuf, String> {
+        let dir = Self::script_dir()?;
+        let app = Self::without_env(app);
+        
+        #[cfg(windows)]
+        let (script_file, script_content) = (
+            dir.join(format!("{}.bat", file_stem)),
+            Self::build_headless_batch_content(&app) + "(goto) 2>nul & del \"%~f0\"\n",
+        );
+        #[cfg(not(windows))]
+        let (script_file, script_content) = (
+            dir.join(format!("{}.sh", file_stem)),
+            Self::build_shell_content(&app).replacen('\n', "\nrm -f -- \"$0\"\n", 1),
+        );
+        
+        Self::write_private(&script_file, &script_content)?;
+        Ok(script_file)
+    }
+    
+    /// Pasta dos scripts temporários, acessível apenas pelo usuário
+    fn script_dir() -> Result<PathBuf, String> {
+        let dir = dirs::cache_dir()
+            .unwrap_or_else(std::env::temp_dir)
+            .join("iris")
+            .join("scripts");
+        fs::create_dir_all(&dir).map_err(|e| format!("Erro ao criar a pasta de scripts: {}", e))?;
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            fs::set_permissions(&dir, fs::Permissions::from_mode(0o700))
+                .map_err(|e| format!("Erro ao proteger a pasta de scripts: {}", e))?;
+        }
+        Ok(dir)
+    }
+    
+    /// Grava um script legível apenas pelo usuário (no Unix, criado com 0600)
+    fn write_private(path: &Path, content: &str) -> Result<(), String> {
+        let mut options = fs::OpenOptions::new();
+        options.write(true).create(true).truncate(true);
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::OpenOptionsExt;
+            options.mode(0o600);
+        }
+        let _ = fs::remove_file(path);
+        options
+            .open(path)
+            .and_then(|mut file| file.write_all(content.as_bytes()))
+            .map_err(|e| format!("Erro ao criar script: {}", e))
+    }
+    
+    /// Cópia da aplicação sem as variáveis de ambiente, para gravar em script
+    fn without_env(app: &AppConfig) -> AppConfig {
+        AppConfig {
+            env: Vec::new(),
+            ..app.clone()
+        }
+    }
+    
+    /// Inicia o script em segundo plano com stdout/stderr redirecionados.
+    /// 
+    /// As variáveis de ambiente são passadas ao processo, sem passar por arquivo.
+    pub(crate) fn spawn_headless(script_file: &Path, env: &[EnvVar]) -> Result<Child, String> {
+        #[cfg(windows)]
+        let mut command = {
+            let mut command = Command::new("cmd");
+            command.arg("/C").arg(script_file).creation_flags(CREATE_NO_WINDOW);
+            command
+        };
+        
+        #[cfg(not(windows))]
+        let mut command = {
+            let mut command = Command::new("sh");
+            command.arg(script_file);
+            command
+        };
+        
+        #[cfg(unix)]
+        command.process_group(0);
+        
+        command
+            .envs(env.iter().map(|var| (&var.key, &var.value)))
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .spawn()
+            .map_err(|e| format!("Erro ao iniciar processo: {}", e))
+    }
+    
+    /// Lê a saída de um processo linha a linha em uma thread dedicada
+    pub(crate) fn pipe_output<R: Read + Send + 'static>(
+        reader: R,
+        app_id: String,
+        logs: LogMonitor,
+    ) -> JoinHandle<()> {
+        std::thread::spawn(move || {
+            let mut reader = BufReader::new(reader);
+            let mut buffer = Vec::new();
+            while let Ok(read) = reader.read_until(b'\n', &mut buffer) {
+                if read == 0 {
+                    break;
+                }
+                let line = String::from_utf8_lossy(&buffer);
+                logs.push_line(&app_id, line.trim_end_matches(['\n', '\r']));
+                buffer.clear();
+            }
+        })
+    }
+    
     /// Constrói o conteúdo do arquivo batch para execução.
     /// 
     /// Trata comandos especiais como npm, yarn, cargo e scripts .bat.
     /// Também detecta automaticamente inputs para scripts interativos.
     fn build_batch_content(app: &AppConfig) -> String {
+        let title = format!("[IRIS] {}", app.name);
+        let mut batch_content = String::new();
+        batch_content.push_str("@echo off\n");
+        batch_content.push_str(&format!("title {}\n", title));
+        
+        if !app.working_dir.is_empty() {
+            batch_content.push_str(&format!("cd /d \"{}\"\n", app.working_dir));
+        }
+        
+        for var in &app.env {
+            batch_content.push_str(&format!("set \"{}={}\"\n", var.key, var.value));
+        }
+        
+        batch_content.push_str(&Self::build_batch_commands(app, Some(&title)));
+        
+        batch_content.push_str(&format!("title {}\n", title));
+        // Apaga o próprio batch (pode conter segredos) e mantém a janela aberta
+        batch_content.push_str("del \"%~f0\" & cmd /k\n");
+        
+        batch_content
+    }
+    
+    /// Constrói o batch para execução em segundo plano (sem título nem `cmd /k`).
+    ///
+    /// Também usado na exportação de scripts, que devem terminar com os comandos.
+    pub(crate) fn build_headless_batch_content(app: &AppConfig) -> String {
         let mut batch_content = String::new();
         batch_content.push_str("@echo off\n");
-        batch_content.push_str(&format!("title [IRIS] {}\n", app.name));
         
         if !app.working_dir.is_empty() {
             batch_content.push_str(&format!("cd /d \"{}\"\n", app.working_dir));
         }
         
+        for var in &app.env {
+            batch_content.push_str(&format!("set \"{}={}\"\n", var.key, var.value));
+        }
+        
+        batch_content.push_str(&Self::build_batch_commands(app, None));
+        batch_content
+    }
+    
+    /// Constrói um script POSIX `sh` equivalente ao batch.
+    /// 
+    /// Falha se não conseguir entrar no diretório de trabalho.
+    pub fn build_shell_content(app: &AppConfig) -> String {
+        let mut script = String::new();
+        script.push_str("#!/bin/sh\n");
+        
+        if !app.working_dir.is_empty() {
+            script.push_str(&format!("cd {} || exit 1\n", crate::utils::shell_quote(&app.working_dir)));
+        }
+        
+        for var in &app.env {
+            script.push_str(&format!("export {}={}\n", var.key, crate::utils::shell_quote(&var.value)));
+        }
+        
+        for cmd in &app.commands {
+            script.push_str(cmd);
+            script.push('\n');
+        }
+        
+        script
+    }
+    
+    /// Gera as linhas de comando do batch.
+    /// 
+    /// Se `title` for informado, restaura o título da janela após cada comando.
+    fn build_batch_commands(app: &AppConfig, title: Option<&str>) -> String {
+        let mut batch_content = String::new();
         let commands = &app.commands;
         let mut i = 0;
         while i < commands.len() {
@@ -180,7 +608,9 @@ impl ProcessManager {
                 batch_content.push_str(&format!("call {} < %TEMP%\\{}\n", cmd, input_file));
                 batch_content.push_str(&format!("del %TEMP%\\{} 2>nul\n", input_file));
                 i += 2;
-                batch_content.push_str(&format!("title [IRIS] {}\n", app.name));
+                if let Some(title) = title {
+                    batch_content.push_str(&format!("title {}\n", title));
+                }
                 continue;
             }
             
@@ -200,17 +630,17 @@ impl ProcessManager {
                 batch_content.push_str(&format!("{}\n", cmd));
             }
             
-            batch_content.push_str(&format!("title [IRIS] {}\n", app.name));
+            if let Some(title) = title {
+                batch_content.push_str(&format!("title {}\n", title));
+            }
             i += 1;
         }
         
-        batch_content.push_str(&format!("title [IRIS] {}\n", app.name));
-        batch_content.push_str("cmd /k\n");
-        
         batch_content
     }
     
     /// Encontra o PID do console Windows pelo título da janela.
+    #[cfg_attr(not(windows), allow(unused_variables))]
     fn find_console_pid(title: &str) -> Option<u32> {
         let search_title = format!("[IRIS] {}", title);
         
@@ -285,86 +715,313 @@ impl ProcessManager {
     /// * `app_id` - ID da aplicação a ser parada
     /// * `app_name` - Nome opcional da aplicação (para busca por título)
     /// * `commands` - Comandos opcionais (para busca por título)
+    /// 
+    /// O processo sai do registro imediatamente e é encerrado em uma thread
+    /// separada, para não bloquear a interface enquanto aguarda a saída.
     pub fn stop_app(&self, app_id: &str, app_name: Option<&str>, commands: Option<&Vec<String>>) {
-        let mut running = self.running_apps.lock().unwrap();
+        let process = self.running_apps.lock().unwrap().remove(app_id);
+        if let Some(check) = self.health_checks.lock().unwrap().remove(app_id) {
+            check.store(true, Ordering::Relaxed);
+        }
+        self.logs.mark_stopped(app_id);
+        self.paused_apps.lock().unwrap().remove(app_id);
+        self.active_profiles.lock().unwrap().remove(app_id);
         
-        if let Some(mut process) = running.remove(app_id) {
-            // Estratégia 1: Mata pelo título do comando
-            if let Some(cmds) = commands {
-                for cmd in cmds {
-                    #[cfg(windows)]
-                    {
-                        let _ = Command::new("taskkill")
-                            .args(["/F", "/FI", &format!("WINDOWTITLE eq {}", cmd)])
-                            .creation_flags(CREATE_NO_WINDOW)
-                            .output();
-                        
-                        let _ = Command::new("taskkill")
-                            .args(["/F", "/FI", &format!("WINDOWTITLE eq {}*", cmd)])
-                            .creation_flags(CREATE_NO_WINDOW)
-                            .output();
-                    }
-                }
+        // Terminal embutido: a sessão continua registrada, mantendo a tela visível
+        let terminal = self
+            .terminals
+            .lock()
+            .unwrap()
+            .get(app_id)
+            .filter(|session| session.is_running())
+            .map(|session| (session.process_id(), session.killer()));
+        
+        if process.is_none() && terminal.is_none() {
+            return;
+        }
+        let terminals = Arc::clone(&self.terminals);
+        let id = app_id.to_string();
+        let name = app_name.map(str::to_string);
+        let commands = commands.cloned();
+        let handle = std::thread::spawn(move || {
+            if let Some((pid, killer)) = terminal {
+                Self::kill_terminal(&terminals, &id, pid, killer);
             }
-
-            // Estratégia 2: Pelo título [IRIS] Nome
-            if let Some(name) = app_name {
-                #[cfg(windows)]
-                let _ = Command::new("taskkill")
-                    .args(["/F", "/FI", &format!("WINDOWTITLE eq [IRIS] {}", name)])
-                    .creation_flags(CREATE_NO_WINDOW)
-                    .output();
+            if let Some(process) = process {
+                Self::kill_running(process, &id, name.as_deref(), commands.as_deref());
             }
-
-            // Estratégia 3: Pela árvore de processos
-            if let Some(pid) = process.console_pid {
+        });
+        self.stopping.lock().unwrap().insert(app_id.to_string(), handle);
+    }
+    
+    /// Encerra o grupo do processo de um terminal embutido e aguarda a
+    /// saída (executado fora da thread da UI)
+    #[cfg_attr(not(unix), allow(unused_variables))]
+    fn kill_terminal(
+        terminals: &Mutex<HashMap<String, PtySession>>,
+        app_id: &str,
+        pid: Option<u32>,
+        mut killer: Box<dyn ChildKiller + Send + Sync>,
+    ) {
+        #[cfg(unix)]
+        if let Some(pid) = pid {
+            let _ = Self::signal_group(pid, AppSignal::Hup);
+            let _ = Self::signal_group(pid, AppSignal::Cont);
+        }
+        let _ = killer.kill();
+        if Self::wait_terminal_exit(terminals, app_id, 20) {
+            return;
+        }
+        
+        // Ignorou o SIGHUP: força o encerramento do grupo
+        #[cfg(unix)]
+        if let Some(pid) = pid {
+            let _ = Command::new("kill").args(["-KILL", "--", &format!("-{}", pid)]).output();
+        }
+        Self::wait_terminal_exit(terminals, app_id, 20);
+    }
+    
+    /// Aguarda (em passos de 50ms) o processo do terminal embutido terminar
+    fn wait_terminal_exit(terminals: &Mutex<HashMap<String, PtySession>>, app_id: &str, attempts: u32) -> bool {
+        for _ in 0..attempts {
+            let exited = terminals.lock().unwrap().get_mut(app_id).is_none_or(|session| {
+                session.poll();
+                !session.is_running()
+            });
+            if exited {
+                return true;
+            }
+            std::thread::sleep(Duration::from_millis(50));
+        }
+        false
+    }
+    
+    /// Encerra um processo retirado do registro (executado fora da thread da UI)
+    #[cfg_attr(not(windows), allow(unused_variables))]
+    fn kill_running(mut process: RunningProcess, app_id: &str, app_name: Option<&str>, commands: Option<&[String]>) {
+        // Processos em segundo plano: mata a árvore/grupo do processo
+        if process.mode == RunMode::Headless {
+            Self::kill_process_tree(&mut process.child);
+            return;
+        }
+        
+        // Estratégia 1: Mata pelo título do comando
+        if let Some(cmds) = commands {
+            for cmd in cmds {
                 #[cfg(windows)]
-                let _ = Command::new("taskkill")
-                    .args(["/F", "/T", "/PID", &pid.to_string()])
-                    .creation_flags(CREATE_NO_WINDOW)
-                    .output();
+                {
+                    let _ = Command::new("taskkill")
+                        .args(["/F", "/FI", &format!("WINDOWTITLE eq {}", cmd)])
+                        .creation_flags(CREATE_NO_WINDOW)
+                        .output();
+                    
+                    let _ = Command::new("taskkill")
+                        .args(["/F", "/FI", &format!("WINDOWTITLE eq {}*", cmd)])
+                        .creation_flags(CREATE_NO_WINDOW)
+                        .output();
+                }
             }
+        }
 
-            // Estratégia 4: WMIC pelo CommandLine
+        // Estratégia 2: Pelo título [IRIS] Nome
+        if let Some(name) = app_name {
             #[cfg(windows)]
-            {
-                let batch_name = format!("iris_{}.bat", app_id);
-                let _ = Command::new("cmd")
-                    .args(["/C", &format!(
-                        "wmic process where \"CommandLine like '%{}%'\" call terminate 2>nul",
-                        batch_name
-                    )])
-                    .creation_flags(CREATE_NO_WINDOW)
-                    .output();
-            }
+            let _ = Command::new("taskkill")
+                .args(["/F", "/FI", &format!("WINDOWTITLE eq [IRIS] {}", name)])
+                .creation_flags(CREATE_NO_WINDOW)
+                .output();
+        }
 
-            // Estratégia 5: Mata padrões comuns
+        // Estratégia 3: Pela árvore de processos
+        if let Some(pid) = process.console_pid {
             #[cfg(windows)]
-            for pattern in ["npm*", "node*", "vite*", "yarn*", "pnpm*"] {
-                let _ = Command::new("taskkill")
-                    .args(["/F", "/FI", &format!("WINDOWTITLE eq {}", pattern)])
-                    .creation_flags(CREATE_NO_WINDOW)
-                    .output();
+            let _ = Command::new("taskkill")
+                .args(["/F", "/T", "/PID", &pid.to_string()])
+                .creation_flags(CREATE_NO_WINDOW)
+                .output();
+        }
+
+        // Estratégia 4: WMIC pelo CommandLine
+        #[cfg(windows)]
+        {
+            let batch_name = format!("iris_{}.bat", app_id);
+            let _ = Command::new("cmd")
+                .args(["/C", &format!(
+                    "wmic process where \"CommandLine like '%{}%'\" call terminate 2>nul",
+                    batch_name
+                )])
+                .creation_flags(CREATE_NO_WINDOW)
+                .output();
+        }
+
+        // Estratégia 5: Mata padrões comuns
+        #[cfg(windows)]
+        for pattern in ["npm*", "node*", "vite*", "yarn*", "pnpm*"] {
+            let _ = Command::new("taskkill")
+                .args(["/F", "/FI", &format!("WINDOWTITLE eq {}", pattern)])
+                .creation_flags(CREATE_NO_WINDOW)
+                .output();
+        }
+        
+        // Mata o processo child diretamente
+        let _ = process.child.kill();
+    }
+    
+    /// Encerra um processo em segundo plano e todos os seus filhos.
+    /// 
+    /// No Unix envia SIGTERM ao grupo do processo e, se necessário, SIGKILL.
+    /// No Windows usa `taskkill /T` na árvore do processo.
+    fn kill_process_tree(child: &mut Child) {
+        let pid = child.id().to_string();
+        
+        #[cfg(windows)]
+        let _ = Command::new("taskkill")
+            .args(["/F", "/T", "/PID", &pid])
+            .creation_flags(CREATE_NO_WINDOW)
+            .output();
+        
+        #[cfg(unix)]
+        {
+            let group = format!("-{}", pid);
+            let _ = Command::new("kill").args(["-TERM", "--", &group]).output();
+            // Processos pausados só tratam o SIGTERM depois de retomados
+            let _ = Command::new("kill").args(["-CONT", "--", &group]).output();
+            for _ in 0..10 {
+                if let Ok(Some(_)) = child.try_wait() {
+                    return;
+                }
+                std::thread::sleep(Duration::from_millis(100));
             }
-            
-            // Mata o processo child diretamente
-            let _ = process.child.kill();
+            let _ = Command::new("kill").args(["-KILL", "--", &group]).output();
+        }
+        
+        let _ = child.kill();
+        let _ = child.wait();
+    }
+    
+    /// Envia um sinal ao grupo de processos de uma aplicação.
+    /// 
+    /// Disponível para aplicações em segundo plano e no terminal embutido.
+    /// No Windows apenas SIGINT é suportado, e somente no terminal embutido
+    /// (enviado como Ctrl+C).
+    pub fn send_signal(&self, app_id: &str, signal: AppSignal) -> Result<(), String> {
+        let pid = self.process_group_id(app_id)
+            .ok_or_else(|| "A aplicação não está em execução em segundo plano ou no terminal embutido".to_string())?;
+        
+        #[cfg(unix)]
+        Self::signal_group(pid, signal)?;
+        
+        #[cfg(not(unix))]
+        {
+            let _ = pid;
+            let sent = signal == AppSignal::Int
+                && self.with_terminal(app_id, |session| session.write(&[0x03])).is_some();
+            if !sent {
+                return Err(format!("Sinal {} não é suportado neste sistema", signal.name()));
+            }
+        }
+        
+        let mut paused = self.paused_apps.lock().unwrap();
+        match signal {
+            AppSignal::Stop => {
+                paused.insert(app_id.to_string());
+            }
+            AppSignal::Cont => {
+                paused.remove(app_id);
+            }
+            _ => {}
+        }
+        
+        Ok(())
+    }
+    
+    /// Envia um sinal ao grupo de processos usando o comando `kill`
+    #[cfg(unix)]
+    fn signal_group(pid: u32, signal: AppSignal) -> Result<(), String> {
+        let output = Command::new("kill")
+            .args(["-s", signal.name(), "--", &format!("-{}", pid)])
+            .output()
+            .map_err(|e| format!("Erro ao enviar sinal: {}", e))?;
+        
+        if output.status.success() {
+            Ok(())
+        } else {
+            Err(format!(
+                "Erro ao enviar SIG{}: {}",
+                signal.name(),
+                String::from_utf8_lossy(&output.stderr).trim()
+            ))
+        }
+    }
+    
+    /// Retorna o PID líder do grupo de processos de uma aplicação sem janela externa
+    fn process_group_id(&self, app_id: &str) -> Option<u32> {
+        let running = self.running_apps.lock().unwrap();
+        if let Some(process) = running.get(app_id) {
+            return (process.mode == RunMode::Headless).then(|| process.child.id());
+        }
+        drop(running);
+        
+        let terminals = self.terminals.lock().unwrap();
+        terminals
+            .get(app_id)
+            .filter(|session| session.is_running())
+            .and_then(|session| session.process_id())
+    }
+    
+    /// Envia uma linha de texto para a entrada padrão da aplicação.
+    /// 
+    /// Útil para comandos interativos como `rs` (nodemon) ou `r` (Flutter).
+    pub fn send_input(&self, app_id: &str, text: &str) -> Result<(), String> {
+        let mut running = self.running_apps.lock().unwrap();
+        if let Some(process) = running.get_mut(app_id) {
+            let stdin = process.child.stdin.as_mut()
+                .ok_or_else(|| "A aplicação não aceita entrada".to_string())?;
+            return stdin
+                .write_all(format!("{}\n", text).as_bytes())
+                .and_then(|_| stdin.flush())
+                .map_err(|e| format!("Erro ao enviar entrada: {}", e));
         }
+        drop(running);
+        
+        self.with_terminal(app_id, |session| {
+            session.write(format!("{}\r", text).as_bytes());
+        })
+        .ok_or_else(|| "A aplicação não está em execução".to_string())
+    }
+    
+    /// Verifica se uma aplicação está pausada (SIGSTOP)
+    pub fn is_paused(&self, app_id: &str) -> bool {
+        let paused = self.paused_apps.lock().unwrap();
+        paused.contains(app_id)
     }
     
     /// Reinicia uma aplicação.
     /// 
-    /// Para o processo atual e inicia novamente após um pequeno delay.
-    pub fn restart_app(&self, app: &AppConfig) {
+    /// Para o processo atual e inicia novamente assim que ele termina,
+    /// mantendo o perfil em uso.
+    pub fn restart_app(&self, app: &AppConfig, vars: &Interpolator) -> Result<(), String> {
+        let profile = self.active_profile(&app.id);
         self.stop_app(&app.id, Some(&app.name), Some(&app.commands));
-        std::thread::sleep(Duration::from_millis(200));
-        self.launch_app(app);
+        self.launch_profile(app, profile.as_deref(), vars)
     }
     
     /// Verifica se uma aplicação está em execução
     pub fn is_running(&self, app_id: &str) -> bool {
         let running = self.running_apps.lock().unwrap();
-        running.contains_key(app_id)
+        running.contains_key(app_id) || self.terminal_running(app_id)
+    }
+    
+    /// Verifica se a sessão de terminal embutido da aplicação está em execução
+    fn terminal_running(&self, app_id: &str) -> bool {
+        let terminals = self.terminals.lock().unwrap();
+        terminals.get(app_id).is_some_and(|session| session.is_running())
+    }
+    
+    /// Retorna o número de terminais embutidos em execução
+    fn running_terminal_count(&self) -> usize {
+        let terminals = self.terminals.lock().unwrap();
+        terminals.values().filter(|session| session.is_running()).count()
     }
     
     /// Verifica se uma aplicação está em processo de inicialização
@@ -376,7 +1033,7 @@ impl ProcessManager {
     /// Retorna o número de aplicações em execução
     pub fn running_count(&self) -> usize {
         let running = self.running_apps.lock().unwrap();
-        running.len()
+        running.len() + self.running_terminal_count()
     }
     
     /// Verifica se há alguma aplicação em loading
@@ -388,7 +1045,7 @@ impl ProcessManager {
     /// Verifica se há alguma aplicação em execução
     pub fn has_running(&self) -> bool {
         let running = self.running_apps.lock().unwrap();
-        !running.is_empty()
+        !running.is_empty() || self.running_terminal_count() > 0
     }
     
     /// Limpa processos que morreram do registro.
@@ -396,10 +1053,20 @@ impl ProcessManager {
     /// Verifica se os processos registrados ainda estão ativos
     /// e remove os que foram encerrados.
     pub fn cleanup_dead_processes(&self) {
+        self.stopping.lock().unwrap().retain(|_, handle| !handle.is_finished());
+        
         let mut running = self.running_apps.lock().unwrap();
         let mut to_remove = Vec::new();
         
-        for (app_id, process) in running.iter() {
+        for (app_id, process) in running.iter_mut() {
+            // Processos em segundo plano são filhos diretos
+            if process.mode == RunMode::Headless {
+                if !matches!(process.child.try_wait(), Ok(None)) {
+                    to_remove.push(app_id.clone());
+                }
+                continue;
+            }
+            
             if let Some(pid) = process.console_pid {
                 #[cfg(windows)]
                 let output = Command::new("tasklist")
@@ -427,6 +1094,16 @@ impl ProcessManager {
         
         for app_id in to_remove {
             running.remove(&app_id);
+            self.logs.mark_stopped(&app_id);
+            self.paused_apps.lock().unwrap().remove(&app_id);
+        }
+        
+        for (app_id, session) in self.terminals.lock().unwrap().iter_mut() {
+            let was_running = session.is_running();
+            session.poll();
+            if was_running && !session.is_running() {
+                self.logs.mark_stopped(app_id);
+            }
         }
     }
 }
@@ -436,3 +1113,213 @@ impl Default for ProcessManager {
         Self::new()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::{LogRule, LogRuleKind};
+    
+    fn headless_app(commands: &[&str]) -> AppConfig {
+        AppConfig {
+            run_mode: RunMode::Headless,
+            commands: commands.iter().map(|c| c.to_string()).collect(),
+            ..AppConfig::new("Teste".to_string())
+        }
+    }
+    
+    #[test]
+    fn test_shell_content_changes_dir() {
+        let mut app = headless_app(&["npm run dev"]);
+        app.working_dir = "/tmp/my app".to_string();
+        let script = ProcessManager::build_shell_content(&app);
+        assert!(script.contains("cd '/tmp/my app' || exit 1\n"));
+        assert!(script.ends_with("npm run dev\n"));
+    }
+    
+    #[test]
+    fn test_profile_overrides_commands_and_env() {
+        use crate::core::{EnvVar, LaunchProfile};
+        
+        let var = |key: &str, value: &str| EnvVar { key: key.to_string(), value: value.to_string() };
+        let mut app = headless_app(&["npm run dev"]);
+        app.env = vec![var("PORT", "3000"), var("NODE_ENV", "development")];
+        app.profiles = vec![
+            LaunchProfile { name: "dev".to_string(), is_default: true, ..Default::default() },
+            LaunchProfile {
+                name: "debug".to_string(),
+                env: vec![var("NODE_OPTIONS", "--inspect"), var("PORT", "9000")],
+                ..Default::default()
+            },
+        ];
+        
+        let (dev, name) = app.with_profile(None);
+        assert_eq!(name.as_deref(), Some("dev"));
+        assert_eq!(dev.commands, app.commands);
+        
+        let (debug, _) = app.with_profile(Some("debug"));
+        let script = ProcessManager::build_shell_content(&debug);
+        assert!(script.contains("export NODE_ENV='development'\n"));
+        assert!(script.contains("export PORT='9000'\n"));
+        assert!(!script.contains("PORT='3000'"));
+        assert!(script.contains("export NODE_OPTIONS='--inspect'\n"));
+    }
+    
+    #[cfg(unix)]
+    #[test]
+    fn test_headless_captures_output_and_rules() {
+        let mut app = headless_app(&["echo starting", "echo 'ready on http://localhost:3000'"]);
+        app.log_rules = vec![
+            LogRule { kind: LogRuleKind::Ready, pattern: "ready".to_string() },
+            LogRule { kind: LogRuleKind::ExtractUrl, pattern: r"http://\S+".to_string() },
+        ];
+        
+        let manager = ProcessManager::new();
+        manager.launch_app(&app);
+        
+        for _ in 0..50 {
+            if manager.logs().status(&app.id).ready {
+                break;
+            }
+            std::thread::sleep(Duration::from_millis(50));
+        }
+        
+        let status = manager.logs().status(&app.id);
+        assert!(status.ready);
+        assert_eq!(status.url.as_deref(), Some("http://localhost:3000"));
+        assert_eq!(manager.logs().recent_lines(&app.id, 10)[0], "starting");
+        manager.stop_app(&app.id, None, None);
+    }
+    
+    #[cfg(unix)]
+    #[test]
+    fn test_headless_receives_input_and_signals() {
+        let app = headless_app(&["read name", "echo \"ola $name\"", "sleep 30"]);
+        
+        let manager = ProcessManager::new();
+        manager.launch_app(&app);
+        for _ in 0..50 {
+            if manager.is_running(&app.id) {
+                break;
+            }
+            std::thread::sleep(Duration::from_millis(50));
+        }
+        
+        manager.send_input(&app.id, "iris").unwrap();
+        for _ in 0..50 {
+            if manager.logs().recent_lines(&app.id, 10).contains(&"ola iris".to_string()) {
+                break;
+            }
+            std::thread::sleep(Duration::from_millis(50));
+        }
+        assert!(manager.logs().recent_lines(&app.id, 10).contains(&"ola iris".to_string()));
+        
+        manager.send_signal(&app.id, AppSignal::Stop).unwrap();
+        assert!(manager.is_paused(&app.id));
+        manager.send_signal(&app.id, AppSignal::Cont).unwrap();
+        assert!(!manager.is_paused(&app.id));
+        manager.stop_app(&app.id, None, None);
+    }
+    
+    #[cfg(unix)]
+    #[test]
+    fn test_script_keeps_env_out_and_removes_itself() {
+        let mut app = headless_app(&["echo \"token=$API_TOKEN\"", "sleep 30"]);
+        app.env = vec![EnvVar { key: "API_TOKEN".to_string(), value: "s3cr3t".to_string() }];
+        
+        let script = ProcessManager::write_script(&app, &format!("iris_{}", app.id)).unwrap();
+        let content = std::fs::read_to_string(&script).unwrap();
+        assert!(!content.contains("s3cr3t"));
+        {
+            use std::os::unix::fs::PermissionsExt;
+            assert_eq!(std::fs::metadata(&script).unwrap().permissions().mode() & 0o777, 0o600);
+        }
+        
+        let manager = ProcessManager::new();
+        manager.launch_app(&app);
+        for _ in 0..50 {
+            if manager.logs().recent_lines(&app.id, 10).contains(&"token=s3cr3t".to_string()) {
+                break;
+            }
+            std::thread::sleep(Duration::from_millis(50));
+        }
+        assert!(manager.logs().recent_lines(&app.id, 10).contains(&"token=s3cr3t".to_string()));
+        assert!(!script.exists());
+        manager.stop_app(&app.id, None, None);
+    }
+    
+    #[cfg(unix)]
+    #[test]
+    fn test_invalid_log_rule_is_reported_in_the_log() {
+        let mut app = headless_app(&["echo ok"]);
+        app.log_rules = vec![LogRule { kind: LogRuleKind::Ready, pattern: "(".to_string() }];
+        
+        let manager = ProcessManager::new();
+        manager.launch_app(&app);
+        let first = manager.logs().recent_lines(&app.id, 10).first().cloned().unwrap_or_default();
+        assert!(first.starts_with("[IRIS] Regras de log ignoradas: Regra \"(\" inválida"), "{}", first);
+        manager.stop_app(&app.id, None, None);
+    }
+    
+    #[test]
+    fn test_stop_does_not_block_while_killing() {
+        // Ignora SIGTERM: o encerramento só acontece com SIGKILL, após ~1s
+        let app = headless_app(&["trap '' TERM", "echo pronto", "while true; do sleep 0.1; done"]);
+        
+        let manager = ProcessManager::new();
+        manager.launch_app(&app);
+        for _ in 0..50 {
+            if manager.logs().recent_lines(&app.id, 10).contains(&"pronto".to_string()) {
+                break;
+            }
+            std::thread::sleep(Duration::from_millis(50));
+        }
+        assert!(manager.is_running(&app.id));
+        
+        let started = std::time::Instant::now();
+        manager.stop_app(&app.id, None, None);
+        assert!(started.elapsed() < Duration::from_millis(500));
+        assert!(!manager.is_running(&app.id));
+        
+        let pending = manager.stopping.lock().unwrap().remove(&app.id);
+        pending.expect("encerramento em andamento").join().unwrap();
+        assert!(started.elapsed() >= Duration::from_millis(900));
+    }
+    
+    #[cfg(unix)]
+    #[test]
+    fn test_terminal_stop_and_relaunch_do_not_block() {
+        let app = AppConfig {
+            run_mode: RunMode::Terminal,
+            ..headless_app(&["trap '' HUP", "echo pronto", "while true; do sleep 0.1; done"])
+        };
+        
+        let manager = ProcessManager::new();
+        manager.launch_app(&app);
+        for _ in 0..50 {
+            if manager.logs().recent_lines(&app.id, 10).iter().any(|line| line.contains("pronto")) {
+                break;
+            }
+            std::thread::sleep(Duration::from_millis(50));
+        }
+        assert!(manager.is_running(&app.id));
+        
+        // Ignora SIGHUP: o encerramento só acontece com SIGKILL, após ~1s
+        let started = std::time::Instant::now();
+        manager.launch_app(&app);
+        assert!(started.elapsed() < Duration::from_millis(500));
+        assert!(manager.has_terminal(&app.id));
+        
+        for _ in 0..60 {
+            if !manager.is_loading(&app.id) {
+                break;
+            }
+            std::thread::sleep(Duration::from_millis(50));
+        }
+        assert!(started.elapsed() >= Duration::from_millis(900));
+        assert!(manager.is_running(&app.id));
+        manager.stop_app(&app.id, None, None);
+        let pending = manager.stopping.lock().unwrap().remove(&app.id);
+        pending.expect("encerramento em andamento").join().unwrap();
+        assert!(!manager.is_running(&app.id));
+    }
+}
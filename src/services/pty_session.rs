@@ -0,0 +1,295 @@
+//! # PTY Session Service
+//!
+//! Executa uma aplicação sob um pseudo-terminal, permitindo interação
+//! completa (prompts, REPLs, consoles) dentro do próprio Iris.
+//!
+//! ## Funcionalidades
+//! - Criação do pseudo-terminal via `portable-pty` (ConPTY no Windows)
+//! - Interpretação da saída em uma grade VT100 (`vt100`)
+//! - Envio de teclas e texto para o processo
+//! - Redimensionamento do terminal
+//! - Encaminhamento da saída para o monitor de logs
+
+use std::io::{Read, Write};
+use std::path::Path;
+use std::sync::{Arc, Mutex};
+
+use eframe::egui::{Key, Modifiers};
+use portable_pty::{native_pty_system, Child, ChildKiller, CommandBuilder, MasterPty, PtySize};
+
+use crate::core::EnvVar;
+use super::log_monitor::LogMonitor;
+
+/// Tamanho inicial do terminal (linhas, colunas)
+pub const DEFAULT_PTY_SIZE: (u16, u16) = (24, 100);
+
+/// Linhas mantidas no histórico de rolagem
+const SCROLLBACK_LINES: usize = 1000;
+
+/// Sessão de pseudo-terminal de uma aplicação.
+pub struct PtySession {
+    /// Grade VT100 alimentada pela saída do processo
+    parser: Arc<Mutex<vt100::Parser>>,
+    /// Lado mestre do pseudo-terminal (usado para redimensionar)
+    master: Box<dyn MasterPty + Send>,
+    /// Escrita para o stdin do processo
+    writer: Box<dyn Write + Send>,
+    /// Processo executado no terminal
+    child: Box<dyn Child + Send + Sync>,
+    /// Se o processo já terminou
+    exited: bool,
+}
+
+impl PtySession {
+    /// Inicia um script em um novo pseudo-terminal.
+    ///
+    /// # Argumentos
+    /// * `script_file` - Script gerado com os comandos da aplicação
+    /// * `env` - Variáveis de ambiente do processo
+    /// * `app_id` - ID da aplicação (para o monitor de logs)
+    /// * `logs` - Monitor que recebe as linhas de saída
+    pub fn spawn(script_file: &Path, env: &[EnvVar], app_id: &str, logs: LogMonitor) -> Result<Self, String> {
+        let (rows, cols) = DEFAULT_PTY_SIZE;
+        let pair = native_pty_system()
+            .openpty(PtySize {
+                rows,
+                cols,
+                pixel_width: 0,
+                pixel_height: 0,
+            })
+            .map_err(|e| format!("Erro ao criar pseudo-terminal: {}", e))?;
+
+        #[cfg(windows)]
+        let mut command = {
+            let mut command = CommandBuilder::new("cmd");
+            command.arg("/C");
+            command.arg(script_file);
+            command
+        };
+        #[cfg(not(windows))]
+        let mut command = {
+            let mut command = CommandBuilder::new("sh");
+            command.arg(script_file);
+            command.env("TERM", "xterm-256color");
+            command
+        };
+        for var in env {
+            command.env(&var.key, &var.value);
+        }
+
+        let child = pair
+            .slave
+            .spawn_command(command)
+            .map_err(|e| format!("Erro ao iniciar processo no terminal: {}", e))?;
+        drop(pair.slave);
+
+        let reader = pair
+            .master
+            .try_clone_reader()
+            .map_err(|e| format!("Erro ao ler do terminal: {}", e))?;
+        let writer = pair
+            .master
+            .take_writer()
+            .map_err(|e| format!("Erro ao escrever no terminal: {}", e))?;
+
+        let parser = Arc::new(Mutex::new(vt100::Parser::new(rows, cols, SCROLLBACK_LINES)));
+        Self::pump_output(reader, Arc::clone(&parser), app_id.to_string(), logs);
+
+        Ok(Self {
+            parser,
+            master: pair.master,
+            writer,
+            child,
+            exited: false,
+        })
+    }
+
+    /// Lê a saída do terminal em uma thread, alimentando a grade e os logs
+    fn pump_output(
+        mut reader: Box<dyn Read + Send>,
+        parser: Arc<Mutex<vt100::Parser>>,
+        app_id: String,
+        logs: LogMonitor,
+    ) {
+        std::thread::spawn(move || {
+            let mut buffer = [0u8; 4096];
+            let mut pending_line = Vec::new();
+            loop {
+                let read = match reader.read(&mut buffer) {
+                    Ok(0) | Err(_) => break,
+                    Ok(read) => read,
+                };
+                parser.lock().unwrap().process(&buffer[..read]);
+
+                for &byte in &buffer[..read] {
+                    if byte == b'\n' {
+                        logs.push_line(&app_id, &String::from_utf8_lossy(&pending_line));
+                        pending_line.clear();
+                    } else {
+                        pending_line.push(byte);
+                    }
+                }
+            }
+            if !pending_line.is_empty() {
+                logs.push_line(&app_id, &String::from_utf8_lossy(&pending_line));
+            }
+        });
+    }
+
+    /// Retorna uma cópia da tela atual do terminal
+    pub fn screen(&self) -> vt100::Screen {
+        self.parser.lock().unwrap().screen().clone()
+    }
+
+    /// Envia bytes (teclas ou texto) para o processo
+    pub fn write(&mut self, bytes: &[u8]) {
+        if self.writer.write_all(bytes).is_ok() {
+            let _ = self.writer.flush();
+        }
+    }
+
+    /// Redimensiona o terminal, se o tamanho mudou
+    pub fn resize(&mut self, rows: u16, cols: u16) {
+        let rows = rows.max(2);
+        let cols = cols.max(10);
+        let mut parser = self.parser.lock().unwrap();
+        if parser.screen().size() == (rows, cols) {
+            return;
+        }
+        parser.set_size(rows, cols);
+        let _ = self.master.resize(PtySize {
+            rows,
+            cols,
+            pixel_width: 0,
+            pixel_height: 0,
+        });
+    }
+
+    /// Retorna o PID do processo executado no terminal
+    pub fn process_id(&self) -> Option<u32> {
+        self.child.process_id()
+    }
+
+    /// Retorna um encerrador do processo, para pará-lo fora da thread da
+    /// interface (a saída é registrada no próximo `poll`)
+    pub fn killer(&self) -> Box<dyn ChildKiller + Send + Sync> {
+        self.child.clone_killer()
+    }
+
+    /// Verifica se o processo terminou, atualizando o estado da sessão
+    pub fn poll(&mut self) {
+        if !self.exited && !matches!(self.child.try_wait(), Ok(None)) {
+            self.exited = true;
+        }
+    }
+
+    /// Verifica se o processo ainda está em execução (segundo o último `poll`)
+    pub fn is_running(&self) -> bool {
+        !self.exited
+    }
+
+    /// Encerra o processo do terminal
+    pub fn kill(&mut self) {
+        if !self.exited {
+            let _ = self.child.kill();
+            let _ = self.child.wait();
+            self.exited = true;
+        }
+    }
+}
+
+/// Converte uma tecla pressionada na sequência de bytes enviada ao terminal.
+///
+/// Texto comum chega pelos eventos de texto do egui; aqui são tratadas
+/// apenas teclas especiais e combinações com Ctrl.
+///
+/// # Argumentos
+/// * `key` - Tecla pressionada
+/// * `modifiers` - Modificadores ativos
+/// * `application_cursor` - Se o terminal está no modo "application cursor keys"
+pub fn encode_key(key: Key, modifiers: Modifiers, application_cursor: bool) -> Option<Vec<u8>> {
+    let arrow = |code: u8| {
+        if application_cursor {
+            vec![0x1b, b'O', code]
+        } else {
+            vec![0x1b, b'[', code]
+        }
+    };
+
+    if modifiers.ctrl && !modifiers.alt {
+        let name = key.name();
+        if name.len() == 1 {
+            let letter = name.as_bytes()[0].to_ascii_uppercase();
+            // Ctrl+V é tratado como colar
+            if letter.is_ascii_uppercase() && letter != b'V' {
+                return Some(vec![letter - b'A' + 1]);
+            }
+        }
+    }
+
+    let bytes = match key {
+        Key::Enter => vec![b'\r'],
+        Key::Backspace => vec![0x7f],
+        Key::Tab if modifiers.shift => b"\x1b[Z".to_vec(),
+        Key::Tab => vec![b'\t'],
+        Key::Escape => vec![0x1b],
+        Key::ArrowUp => arrow(b'A'),
+        Key::ArrowDown => arrow(b'B'),
+        Key::ArrowRight => arrow(b'C'),
+        Key::ArrowLeft => arrow(b'D'),
+        Key::Home => b"\x1b[H".to_vec(),
+        Key::End => b"\x1b[F".to_vec(),
+        Key::PageUp => b"\x1b[5~".to_vec(),
+        Key::PageDown => b"\x1b[6~".to_vec(),
+        Key::Insert => b"\x1b[2~".to_vec(),
+        Key::Delete => b"\x1b[3~".to_vec(),
+        _ => return None,
+    };
+    Some(bytes)
+}
+
+impl Drop for PtySession {
+    fn drop(&mut self) {
+        self.kill();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    #[test]
+    fn test_encode_keys() {
+        assert_eq!(encode_key(Key::C, Modifiers::CTRL, false), Some(vec![3]));
+        assert_eq!(encode_key(Key::Enter, Modifiers::NONE, false), Some(vec![b'\r']));
+        assert_eq!(encode_key(Key::ArrowUp, Modifiers::NONE, true), Some(b"\x1bOA".to_vec()));
+        assert_eq!(encode_key(Key::V, Modifiers::CTRL, false), None);
+        assert_eq!(encode_key(Key::A, Modifiers::NONE, false), None);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_session_renders_output() {
+        let script = std::env::temp_dir().join(format!("iris_pty_test_{}.sh", std::process::id()));
+        std::fs::write(&script, "read name\necho \"$GREETING $name\"\n").unwrap();
+
+        let logs = LogMonitor::new();
+        let env = [EnvVar { key: "GREETING".to_string(), value: "ola".to_string() }];
+        let mut session = PtySession::spawn(&script, &env, "pty", logs.clone()).unwrap();
+        session.write(b"iris\r");
+
+        for _ in 0..50 {
+            if session.screen().contents().contains("ola iris") {
+                break;
+            }
+            std::thread::sleep(Duration::from_millis(50));
+        }
+
+        assert!(session.screen().contents().contains("ola iris"));
+        session.kill();
+        session.poll();
+        assert!(!session.is_running());
+        let _ = std::fs::remove_file(script);
+    }
+}
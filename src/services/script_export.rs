@@ -0,0 +1,313 @@
+//! # Script Export Service
+//!
+//! Gera arquivos para executar aplicações fora do Iris (CI, servidores,
+//! SSH): scripts `sh` ou `.bat` (os mesmos geradores usados ao iniciar as
+//! aplicações em segundo plano, que terminam junto com os comandos),
+//! Procfile, Makefile e units de usuário do systemd.
+//!
+//! As aplicações devem chegar com as variáveis já expandidas.
+
+use std::fs;
+use std::path::Path;
+use crate::core::AppConfig;
+use crate::utils::shell_quote;
+use super::process_manager::ProcessManager;
+
+/// Formato dos arquivos gerados
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ScriptTarget {
+    /// Um script POSIX por aplicação e um que inicia todas
+    #[default]
+    Shell,
+    /// Um `.bat` por aplicação e um que inicia todas
+    Batch,
+    /// Um Procfile com um processo por aplicação
+    Procfile,
+    /// Um Makefile com um alvo por aplicação
+    Makefile,
+    /// Uma unit `.service` por aplicação e um `.target` para o grupo
+    Systemd,
+}
+
+impl ScriptTarget {
+    /// Formatos disponíveis
+    pub const ALL: [ScriptTarget; 5] = [Self::Shell, Self::Batch, Self::Procfile, Self::Makefile, Self::Systemd];
+
+    /// Nome exibido no diálogo
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Shell => "Script sh (Linux/macOS)",
+            Self::Batch => "Script .bat (Windows)",
+            Self::Procfile => "Procfile",
+            Self::Makefile => "Makefile",
+            Self::Systemd => "Units do systemd (usuário)",
+        }
+    }
+}
+
+/// Arquivo gerado
+#[derive(Clone, Debug, PartialEq)]
+pub struct GeneratedFile {
+    /// Nome do arquivo (sem pasta)
+    pub name: String,
+    /// Conteúdo
+    pub content: String,
+    /// Se deve ser marcado como executável
+    pub executable: bool,
+}
+
+/// Nome seguro para arquivos, alvos e processos (`Minha API` -> `minha-api`)
+fn slug(name: &str) -> String {
+    let slug = name
+        .to_lowercase()
+        .split(|c: char| !c.is_ascii_alphanumeric())
+        .filter(|part| !part.is_empty())
+        .collect::<Vec<_>>()
+        .join("-");
+    if slug.is_empty() {
+        "app".to_string()
+    } else {
+        slug
+    }
+}
+
+/// Nomes únicos das aplicações, na mesma ordem
+fn unique_slugs(apps: &[AppConfig]) -> Vec<String> {
+    let mut slugs: Vec<String> = Vec::new();
+    for app in apps {
+        let base = slug(&app.name);
+        let mut candidate = base.clone();
+        let mut n = 2;
+        while slugs.contains(&candidate) {
+            candidate = format!("{}-{}", base, n);
+            n += 1;
+        }
+        slugs.push(candidate);
+    }
+    slugs
+}
+
+/// Linha de shell que entra na pasta, define as variáveis e executa os
+/// comandos em sequência
+fn one_liner(app: &AppConfig) -> String {
+    let mut parts = Vec::new();
+    if !app.working_dir.is_empty() {
+        parts.push(format!("cd {}", shell_quote(&app.working_dir)));
+    }
+    for var in &app.env {
+        parts.push(format!("export {}={}", var.key, shell_quote(&var.value)));
+    }
+    parts.extend(app.commands.iter().cloned());
+    parts.join(" && ")
+}
+
+/// Texto entre aspas para units do systemd, escapando `%` e, se
+/// `dollars`, também `$`
+fn systemd_quote(text: &str, dollars: bool) -> String {
+    let mut escaped = text.replace('\\', "\\\\").replace('"', "\\\"").replace('%', "%%");
+    if dollars {
+        escaped = escaped.replace('$', "$$");
+    }
+    format!("\"{}\"", escaped)
+}
+
+/// Gera os arquivos das aplicações no formato escolhido.
+///
+/// # Argumentos
+/// * `target` - Formato dos arquivos
+/// * `apps` - Aplicações, com as variáveis já expandidas
+/// * `name` - Nome do conjunto (script que inicia todas, `.target`)
+pub fn generate_scripts(target: ScriptTarget, apps: &[AppConfig], name: &str) -> Vec<GeneratedFile> {
+    let slugs = unique_slugs(apps);
+    let group = slug(name);
+    let mut files = Vec::new();
+
+    match target {
+        ScriptTarget::Shell => {
+            for (app, slug) in apps.iter().zip(&slugs) {
+                files.push(GeneratedFile {
+                    name: format!("{}.sh", slug),
+                    content: ProcessManager::build_shell_content(app),
+                    executable: true,
+                });
+            }
+            if apps.len() > 1 {
+                let mut script = String::from("#!/bin/sh\n");
+                script.push_str(&format!("# Inicia as aplicações de \"{}\" e aguarda; Ctrl+C encerra todas\n", name));
+                script.push_str("cd \"$(dirname \"$0\")\" || exit 1\n");
+                script.push_str("trap 'kill 0' INT TERM\n");
+                for slug in &slugs {
+                    script.push_str(&format!("sh ./{}.sh &\n", slug));
+                }
+                script.push_str("wait\n");
+                files.push(GeneratedFile {
+                    name: format!("{}.sh", group),
+                    content: script,
+                    executable: true,
+                });
+            }
+        }
+        ScriptTarget::Batch => {
+            for (app, slug) in apps.iter().zip(&slugs) {
+                files.push(GeneratedFile {
+                    name: format!("{}.bat", slug),
+                    content: ProcessManager::build_headless_batch_content(app),
+                    executable: false,
+                });
+            }
+            if apps.len() > 1 {
+                let mut batch = String::from("@echo off\n");
+                batch.push_str(&format!("rem Inicia as aplicações de \"{}\", cada uma em sua janela\n", name));
+                for (app, slug) in apps.iter().zip(&slugs) {
+                    batch.push_str(&format!("start \"{}\" \"%~dp0{}.bat\"\n", app.name, slug));
+                }
+                files.push(GeneratedFile {
+                    name: format!("{}.bat", group),
+                    content: batch,
+                    executable: false,
+                });
+            }
+        }
+        ScriptTarget::Procfile => {
+            let content = apps
+                .iter()
+                .zip(&slugs)
+                .map(|(app, slug)| format!("{}: {}\n", slug, one_liner(app)))
+                .collect();
+            files.push(GeneratedFile {
+                name: "Procfile".to_string(),
+                content,
+                executable: false,
+            });
+        }
+        ScriptTarget::Makefile => {
+            let mut make = format!("# Aplicações de \"{}\". Use `make -j all` para iniciar todas.\n", name);
+            make.push_str(&format!(".PHONY: all {}\n\n", slugs.join(" ")));
+            make.push_str(&format!("all: {}\n", slugs.join(" ")));
+            for (app, slug) in apps.iter().zip(&slugs) {
+                make.push_str(&format!("\n{}:\n\t{}\n", slug, one_liner(app).replace('$', "$$")));
+            }
+            files.push(GeneratedFile {
+                name: "Makefile".to_string(),
+                content: make,
+                executable: false,
+            });
+        }
+        ScriptTarget::Systemd => {
+            let grouped = apps.len() > 1;
+            for (app, slug) in apps.iter().zip(&slugs) {
+                let mut unit = format!("[Unit]\nDescription={} (Iris)\n", app.name.replace('%', "%%"));
+                if grouped {
+                    unit.push_str(&format!("PartOf=iris-{}.target\n", group));
+                }
+                unit.push_str("\n[Service]\nType=simple\n");
+                if !app.working_dir.is_empty() {
+                    unit.push_str(&format!("WorkingDirectory={}\n", app.working_dir.replace('%', "%%")));
+                }
+                for var in &app.env {
+                    unit.push_str(&format!("Environment={}\n", systemd_quote(&format!("{}={}", var.key, var.value), false)));
+                }
+                unit.push_str(&format!("ExecStart=/bin/sh -c {}\n", systemd_quote(&app.commands.join(" && "), true)));
+                unit.push_str("Restart=on-failure\n\n[Install]\n");
+                let wanted_by = if grouped { format!("iris-{}.target", group) } else { "default.target".to_string() };
+                unit.push_str(&format!("WantedBy={}\n", wanted_by));
+                files.push(GeneratedFile {
+                    name: format!("iris-{}.service", slug),
+                    content: unit,
+                    executable: false,
+                });
+            }
+            if grouped {
+                let services: Vec<String> = slugs.iter().map(|slug| format!("iris-{}.service", slug)).collect();
+                files.push(GeneratedFile {
+                    name: format!("iris-{}.target", group),
+                    content: format!(
+                        "[Unit]\nDescription={} (Iris)\nWants={}\n\n[Install]\nWantedBy=default.target\n",
+                        name.replace('%', "%%"),
+                        services.join(" ")
+                    ),
+                    executable: false,
+                });
+            }
+        }
+    }
+
+    files
+}
+
+/// Grava os arquivos na pasta, marcando os scripts como executáveis (no Unix)
+pub fn write_scripts(dir: &Path, files: &[GeneratedFile]) -> Result<(), String> {
+    for file in files {
+        let path = dir.join(&file.name);
+        fs::write(&path, &file.content).map_err(|e| format!("Erro ao gravar {}: {}", file.name, e))?;
+        #[cfg(unix)]
+        if file.executable {
+            use std::os::unix::fs::PermissionsExt;
+            let _ = fs::set_permissions(&path, fs::Permissions::from_mode(0o755));
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::EnvVar;
+
+    fn app(name: &str, dir: &str, commands: &[&str]) -> AppConfig {
+        AppConfig {
+            working_dir: dir.to_string(),
+            commands: commands.iter().map(|c| c.to_string()).collect(),
+            env: vec![EnvVar { key: "GREETING".to_string(), value: "olá $USER 100%".to_string() }],
+            ..AppConfig::new(name.to_string())
+        }
+    }
+
+    #[test]
+    fn test_makefile_procfile_and_systemd_escaping() {
+        let apps = vec![app("Minha API", "/srv/api", &["cargo run"]), app("Web", "/srv/web", &["npm start -- --port $PORT"])];
+
+        let make = &generate_scripts(ScriptTarget::Makefile, &apps, "loja")[0].content;
+        assert!(make.contains(".PHONY: all minha-api web\n"));
+        assert!(make.contains("\nweb:\n\tcd '/srv/web' && export GREETING='olá $$USER 100%' && npm start -- --port $$PORT\n"));
+
+        let procfile = &generate_scripts(ScriptTarget::Procfile, &apps, "loja")[0].content;
+        assert_eq!(procfile.lines().next(), Some("minha-api: cd '/srv/api' && export GREETING='olá $USER 100%' && cargo run"));
+
+        let units = generate_scripts(ScriptTarget::Systemd, &apps, "loja");
+        let names: Vec<&str> = units.iter().map(|f| f.name.as_str()).collect();
+        assert_eq!(names, vec!["iris-minha-api.service", "iris-web.service", "iris-loja.target"]);
+        let web = &units[1].content;
+        assert!(web.contains("WorkingDirectory=/srv/web\n"));
+        assert!(web.contains("Environment=\"GREETING=olá $USER 100%%\"\n"));
+        assert!(web.contains("ExecStart=/bin/sh -c \"npm start -- --port $$PORT\"\n"));
+        assert!(web.contains("PartOf=iris-loja.target\n"));
+        assert!(units[2].content.contains("Wants=iris-minha-api.service iris-web.service\n"));
+
+        let batch = &generate_scripts(ScriptTarget::Batch, &apps, "loja")[0].content;
+        assert!(batch.contains("cd /d \"/srv/api\"\n"));
+        assert!(!batch.contains("cmd /k") && !batch.contains("title "));
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_shell_launcher_runs_every_app() {
+        let dir = std::env::temp_dir().join(format!("iris_script_export_{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        std::fs::create_dir_all(&dir).unwrap();
+        let work = dir.display().to_string();
+        let apps = vec![
+            app("A", &work, &["echo \"$GREETING\" > a.txt"]),
+            app("A", &work, &["touch b.txt"]),
+        ];
+
+        let files = generate_scripts(ScriptTarget::Shell, &apps, "Tudo");
+        write_scripts(&dir, &files).unwrap();
+        assert_eq!(files.last().unwrap().name, "tudo.sh");
+        let status = std::process::Command::new(dir.join("tudo.sh")).status().unwrap();
+        assert!(status.success());
+        assert_eq!(std::fs::read_to_string(dir.join("a.txt")).unwrap().trim(), "olá $USER 100%");
+        assert!(dir.join("b.txt").is_file());
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+}
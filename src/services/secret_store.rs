@@ -0,0 +1,280 @@
+//! # Secret Store Service
+//!
+//! Cofre local de segredos (tokens, senhas) referenciados nas configurações
+//! como `${secret:NOME}`, para que os valores nunca fiquem no `config.json`.
+//!
+//! ## Funcionalidades
+//! - Arquivo `secrets.json` na pasta de configuração
+//! - Chave derivada da senha com Argon2id
+//! - Conteúdo cifrado com XChaCha20-Poly1305 (novo nonce a cada gravação)
+//! - Mascaramento e remoção dos valores em textos (logs, exportação)
+
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::PathBuf;
+
+use argon2::Argon2;
+use base64::{engine::general_purpose::STANDARD as BASE64, Engine};
+use chacha20poly1305::aead::{Aead, AeadCore, KeyInit, OsRng};
+use chacha20poly1305::aead::rand_core::RngCore;
+use chacha20poly1305::{Key, XChaCha20Poly1305, XNonce};
+use serde::{Deserialize, Serialize};
+
+use crate::core::write_atomic_private;
+
+/// Texto exibido no lugar de um segredo
+pub const SECRET_MASK: &str = "••••••";
+
+/// Tamanho mínimo de um valor para ser mascarado (evita mascarar textos triviais)
+const MIN_MASK_LEN: usize = 4;
+
+/// Formato do arquivo do cofre
+#[derive(Serialize, Deserialize)]
+struct SecretFile {
+    version: u32,
+    salt: String,
+    nonce: String,
+    ciphertext: String,
+}
+
+/// Cofre de segredos protegido por senha.
+///
+/// Enquanto bloqueado, nenhum valor está disponível em memória.
+pub struct SecretStore {
+    /// Caminho do arquivo do cofre
+    path: PathBuf,
+    /// Chave derivada da senha (presente apenas quando desbloqueado)
+    key: Option<Key>,
+    /// Sal usado na derivação da chave
+    salt: Vec<u8>,
+    /// Segredos decifrados (nome -> valor)
+    secrets: BTreeMap<String, String>,
+}
+
+impl SecretStore {
+    /// Cria o cofre no caminho padrão (`<config>/iris/secrets.json`)
+    pub fn new() -> Self {
+        let mut path = dirs::config_dir().unwrap_or_else(|| PathBuf::from("."));
+        path.push("iris");
+        path.push("secrets.json");
+        Self::with_path(path)
+    }
+
+    /// Cria o cofre em um caminho específico
+    pub fn with_path(path: PathBuf) -> Self {
+        Self {
+            path,
+            key: None,
+            salt: Vec::new(),
+            secrets: BTreeMap::new(),
+        }
+    }
+
+    /// Verifica se o arquivo do cofre já existe
+    pub fn exists(&self) -> bool {
+        self.path.exists()
+    }
+
+    /// Verifica se o cofre está desbloqueado
+    pub fn is_unlocked(&self) -> bool {
+        self.key.is_some()
+    }
+
+    /// Desbloqueia o cofre com a senha, criando-o se ainda não existir.
+    ///
+    /// # Retorno
+    /// Erro se a senha estiver incorreta ou o arquivo for inválido.
+    pub fn unlock(&mut self, passphrase: &str) -> Result<(), String> {
+        if passphrase.is_empty() {
+            return Err("Informe a senha do cofre".to_string());
+        }
+
+        if !self.exists() {
+            let mut salt = vec![0u8; 16];
+            OsRng.fill_bytes(&mut salt);
+            self.key = Some(derive_key(passphrase, &salt)?);
+            self.salt = salt;
+            self.secrets.clear();
+            return self.save();
+        }
+
+        let content = fs::read_to_string(&self.path)
+            .map_err(|e| format!("Erro ao ler o cofre: {}", e))?;
+        let file: SecretFile = serde_json::from_str(&content)
+            .map_err(|e| format!("Cofre inválido: {}", e))?;
+        let salt = decode(&file.salt)?;
+        let nonce = decode(&file.nonce)?;
+        let ciphertext = decode(&file.ciphertext)?;
+        if nonce.len() != 24 {
+            return Err("Cofre inválido: nonce corrompido".to_string());
+        }
+
+        let key = derive_key(passphrase, &salt)?;
+        let plaintext = XChaCha20Poly1305::new(&key)
+            .decrypt(XNonce::from_slice(&nonce), ciphertext.as_ref())
+            .map_err(|_| "Senha incorreta".to_string())?;
+        let secrets = serde_json::from_slice(&plaintext)
+            .map_err(|e| format!("Cofre inválido: {}", e))?;
+
+        self.key = Some(key);
+        self.salt = salt;
+        self.secrets = secrets;
+        Ok(())
+    }
+
+    /// Bloqueia o cofre, descartando a chave e os valores da memória
+    pub fn lock(&mut self) {
+        self.key = None;
+        self.secrets.clear();
+    }
+
+    /// Retorna os segredos decifrados (vazio se bloqueado)
+    pub fn secrets(&self) -> &BTreeMap<String, String> {
+        &self.secrets
+    }
+
+    /// Define (ou substitui) um segredo e grava o cofre
+    pub fn set(&mut self, name: &str, value: &str) -> Result<(), String> {
+        let name = name.trim();
+        if name.is_empty() || !name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-') {
+            return Err(format!("Nome de segredo inválido: \"{}\"", name));
+        }
+        if !self.is_unlocked() {
+            return Err("Cofre bloqueado".to_string());
+        }
+        self.secrets.insert(name.to_string(), value.to_string());
+        self.save()
+    }
+
+    /// Remove um segredo e grava o cofre
+    pub fn remove(&mut self, name: &str) -> Result<(), String> {
+        if !self.is_unlocked() {
+            return Err("Cofre bloqueado".to_string());
+        }
+        self.secrets.remove(name);
+        self.save()
+    }
+
+    /// Valores que devem ser mascarados nos textos exibidos
+    pub fn mask_values(&self) -> Vec<String> {
+        self.secrets
+            .values()
+            .filter(|value| value.len() >= MIN_MASK_LEN)
+            .cloned()
+            .collect()
+    }
+
+    /// Substitui os valores dos segredos em um texto por `${secret:NOME}`.
+    ///
+    /// Usado ao exportar configurações, para que tokens colados
+    /// diretamente nos comandos não saiam do computador.
+    pub fn redact(&self, text: &str) -> String {
+        let mut result = text.to_string();
+        for (name, value) in &self.secrets {
+            if value.len() >= MIN_MASK_LEN {
+                result = result.replace(value.as_str(), &format!("${{secret:{}}}", name));
+            }
+        }
+        result
+    }
+
+    /// Cifra e grava os segredos no arquivo do cofre
+    fn save(&self) -> Result<(), String> {
+        let key = self.key.as_ref().ok_or("Cofre bloqueado")?;
+        let plaintext = serde_json::to_vec(&self.secrets)
+            .map_err(|e| format!("Erro ao serializar segredos: {}", e))?;
+        let nonce = XChaCha20Poly1305::generate_nonce(&mut OsRng);
+        let ciphertext = XChaCha20Poly1305::new(key)
+            .encrypt(&nonce, plaintext.as_ref())
+            .map_err(|_| "Erro ao cifrar segredos".to_string())?;
+
+        let file = SecretFile {
+            version: 1,
+            salt: BASE64.encode(&self.salt),
+            nonce: BASE64.encode(nonce),
+            ciphertext: BASE64.encode(ciphertext),
+        };
+        let json = serde_json::to_string_pretty(&file)
+            .map_err(|e| format!("Erro ao serializar cofre: {}", e))?;
+        if let Some(dir) = self.path.parent() {
+            fs::create_dir_all(dir).ok();
+        }
+        write_atomic_private(&self.path, &json).map_err(|e| format!("Erro ao gravar o cofre: {}", e))
+    }
+}
+
+impl Default for SecretStore {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Substitui os valores informados por `SECRET_MASK` em uma linha
+pub fn mask_secrets(line: &str, values: &[String]) -> String {
+    let mut result = line.to_string();
+    for value in values {
+        if result.contains(value.as_str()) {
+            result = result.replace(value.as_str(), SECRET_MASK);
+        }
+    }
+    result
+}
+
+/// Deriva a chave de 256 bits da senha com Argon2id
+fn derive_key(passphrase: &str, salt: &[u8]) -> Result<Key, String> {
+    let mut key = Key::default();
+    Argon2::default()
+        .hash_password_into(passphrase.as_bytes(), salt, &mut key)
+        .map_err(|e| format!("Erro ao derivar chave: {}", e))?;
+    Ok(key)
+}
+
+/// Decodifica um campo base64 do arquivo do cofre
+fn decode(value: &str) -> Result<Vec<u8>, String> {
+    BASE64.decode(value).map_err(|e| format!("Cofre inválido: {}", e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn temp_store(name: &str) -> SecretStore {
+        let path = std::env::temp_dir().join(format!("iris_secrets_{}_{}.json", name, std::process::id()));
+        let _ = fs::remove_file(&path);
+        SecretStore::with_path(path)
+    }
+
+    #[test]
+    fn test_roundtrip_and_wrong_passphrase() {
+        let mut store = temp_store("roundtrip");
+        store.unlock("senha forte").unwrap();
+        store.set("API_TOKEN", "tok_123456").unwrap();
+        store.lock();
+        assert!(store.secrets().is_empty());
+
+        let content = fs::read_to_string(&store.path).unwrap();
+        assert!(!content.contains("tok_123456"));
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            let mode = fs::metadata(&store.path).unwrap().permissions().mode();
+            assert_eq!(mode & 0o777, 0o600);
+        }
+
+        assert_eq!(store.unlock("errada").unwrap_err(), "Senha incorreta");
+        store.unlock("senha forte").unwrap();
+        assert_eq!(store.secrets().get("API_TOKEN").map(String::as_str), Some("tok_123456"));
+        let _ = fs::remove_file(&store.path);
+    }
+
+    #[test]
+    fn test_mask_and_redact() {
+        let mut store = temp_store("mask");
+        store.unlock("x").unwrap();
+        store.set("TOKEN", "abc123xyz").unwrap();
+
+        assert_eq!(mask_secrets("Bearer abc123xyz", &store.mask_values()), "Bearer ••••••");
+        assert_eq!(store.redact("curl -H abc123xyz"), "curl -H ${secret:TOKEN}");
+        let _ = fs::remove_file(&store.path);
+    }
+}
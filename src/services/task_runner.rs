@@ -0,0 +1,391 @@
+//! # Task Runner Service
+//!
+//! Executa as ações personalizadas das aplicações (testes, lint, migrações)
+//! como tarefas avulsas, independentes do processo principal da aplicação.
+//!
+//! ## Funcionalidades
+//! - Execução em segundo plano com captura de stdout/stderr
+//! - Registro do código de saída e da duração de cada tarefa
+//! - Uma execução por ação de cada vez
+//! - Clonagem e configuração inicial das aplicações com repositório
+
+use std::collections::HashMap;
+use std::path::Path;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use crate::core::{AppConfig, CustomAction, Interpolator};
+use super::log_monitor::{LogMonitor, RuleSet};
+use super::process_manager::ProcessManager;
+
+/// Estado de uma tarefa
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TaskStatus {
+    /// Em execução
+    Running,
+    /// Terminou com código de saída zero
+    Succeeded,
+    /// Terminou com erro (código de saída, se disponível)
+    Failed(Option<i32>),
+}
+
+impl TaskStatus {
+    /// Ícone exibido no menu de ações
+    pub fn icon(&self) -> &'static str {
+        match self {
+            TaskStatus::Running => "⏳",
+            TaskStatus::Succeeded => "✔",
+            TaskStatus::Failed(_) => "✖",
+        }
+    }
+
+    /// Descrição do estado exibida na janela de saída
+    pub fn label(&self) -> String {
+        match self {
+            TaskStatus::Running => "Em execução...".to_string(),
+            TaskStatus::Succeeded => "Concluída com sucesso".to_string(),
+            TaskStatus::Failed(Some(code)) => format!("Falhou (código {})", code),
+            TaskStatus::Failed(None) => "Falhou".to_string(),
+        }
+    }
+}
+
+/// Informações da última execução de uma ação
+#[derive(Clone, Debug)]
+pub struct TaskInfo {
+    /// Estado atual
+    pub status: TaskStatus,
+    /// Momento de início
+    pub started: Instant,
+    /// Duração total (disponível ao terminar)
+    pub duration: Option<Duration>,
+}
+
+/// Gerenciador das tarefas avulsas.
+///
+/// Thread-safe: cada tarefa é executada em sua própria thread.
+#[derive(Clone, Default)]
+pub struct TaskRunner {
+    tasks: Arc<Mutex<HashMap<String, TaskInfo>>>,
+    output: LogMonitor,
+}
+
+/// Nome da tarefa que clona e configura uma aplicação
+pub const BOOTSTRAP_TASK: &str = "Clonar e configurar";
+
+/// Chave de uma ação: ID da aplicação e nome da ação
+pub fn task_key(app_id: &str, action_name: &str) -> String {
+    format!("{}::{}", app_id, action_name)
+}
+
+impl TaskRunner {
+    /// Cria um executor sem tarefas
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Saída capturada das tarefas (indexada por `task_key`)
+    pub fn output(&self) -> &LogMonitor {
+        &self.output
+    }
+
+    /// Inicia uma ação da aplicação.
+    ///
+    /// Erros na expansão de variáveis fazem a tarefa falhar com a
+    /// mensagem registrada na saída.
+    ///
+    /// # Retorno
+    /// Erro se a mesma ação já estiver em execução.
+    pub fn run(&self, app: &AppConfig, action: &CustomAction, vars: &Interpolator) -> Result<(), String> {
+        let key = self.begin(&app.id, &action.name)?;
+        let task_app = match vars.expand_app(&app.for_action(action)) {
+            Ok(task_app) => task_app,
+            Err(e) => {
+                self.output.push_line(&key, &format!("[IRIS] {}", e));
+                self.finish(&key, TaskStatus::Failed(None));
+                return Ok(());
+            }
+        };
+
+        let runner = self.clone();
+        std::thread::spawn(move || {
+            let status = runner.run_script(&task_app, &key);
+            runner.finish(&key, status);
+        });
+
+        Ok(())
+    }
+
+    /// Clona o repositório da aplicação na pasta de trabalho e executa os
+    /// passos de configuração, parando no primeiro que falhar.
+    ///
+    /// A saída fica na tarefa `BOOTSTRAP_TASK` da aplicação.
+    ///
+    /// # Retorno
+    /// Erro se a aplicação não tiver repositório ou já estiver sendo clonada.
+    pub fn bootstrap(&self, app: &AppConfig, vars: &Interpolator) -> Result<(), String> {
+        let Some(repository) = &app.repository else {
+            return Err(format!("\"{}\" não tem repositório configurado", app.name));
+        };
+        let key = self.begin(&app.id, BOOTSTRAP_TASK)?;
+        let expanded = vars.expand_app(app).and_then(|expanded| {
+            let url = vars.expand(&repository.url).map_err(|e| format!("Repositório: {}", e))?;
+            let setup = app
+                .setup
+                .iter()
+                .map(|step| vars.expand(step).map_err(|e| format!("Configuração: {}", e)))
+                .collect::<Result<Vec<_>, _>>()?;
+            Ok((expanded, url, setup))
+        });
+        let (task_app, url, setup) = match expanded {
+            Ok(expanded) => expanded,
+            Err(e) => {
+                self.output.push_line(&key, &format!("[IRIS] {}", e));
+                self.finish(&key, TaskStatus::Failed(None));
+                return Ok(());
+            }
+        };
+        let repository = crate::core::Repository {
+            url,
+            branch: repository.branch.clone(),
+        };
+        // Ao repetir após uma falha na configuração, o clone já existe
+        let clone_needed = app.needs_clone(&task_app.working_dir);
+
+        let runner = self.clone();
+        std::thread::spawn(move || {
+            let dir = task_app.working_dir.clone();
+            let parent = Path::new(&dir).parent().map(Path::to_path_buf).unwrap_or_default();
+            if let Err(e) = std::fs::create_dir_all(&parent) {
+                runner.output.push_line(&key, &format!("[IRIS] Erro ao criar \"{}\": {}", parent.display(), e));
+                runner.finish(&key, TaskStatus::Failed(None));
+                return;
+            }
+
+            let total = setup.len() + 1;
+            let mut status = if clone_needed {
+                let clone = AppConfig {
+                    working_dir: parent.display().to_string(),
+                    commands: vec![clone_command(&repository, &dir)],
+                    ..task_app.clone()
+                };
+                runner.output.push_line(&key, &format!("[IRIS] [1/{}] Clonando {}", total, repository.url));
+                runner.run_script(&clone, &key)
+            } else {
+                runner.output.push_line(&key, &format!("[IRIS] [1/{}] \"{}\" já existe; clone ignorado", total, dir));
+                TaskStatus::Succeeded
+            };
+
+            for (index, step) in setup.iter().enumerate() {
+                if status != TaskStatus::Succeeded {
+                    break;
+                }
+                runner.output.push_line(&key, &format!("[IRIS] [{}/{}] {}", index + 2, total, step));
+                let step_app = AppConfig {
+                    commands: vec![step.clone()],
+                    ..task_app.clone()
+                };
+                status = runner.run_script(&step_app, &key);
+            }
+            runner.finish(&key, status);
+        });
+
+        Ok(())
+    }
+
+    /// Registra o início de uma tarefa
+    ///
+    /// # Retorno
+    /// A chave da tarefa, ou erro se ela já estiver em execução.
+    fn begin(&self, app_id: &str, name: &str) -> Result<String, String> {
+        let key = task_key(app_id, name);
+        {
+            let mut tasks = self.tasks.lock().unwrap();
+            if tasks.get(&key).is_some_and(|task| task.status == TaskStatus::Running) {
+                return Err(format!("A ação \"{}\" já está em execução", name));
+            }
+            tasks.insert(
+                key.clone(),
+                TaskInfo {
+                    status: TaskStatus::Running,
+                    started: Instant::now(),
+                    duration: None,
+                },
+            );
+        }
+        self.output.start(&key, RuleSet::default());
+        Ok(key)
+    }
+
+    /// Registra o fim de uma tarefa
+    fn finish(&self, key: &str, status: TaskStatus) {
+        if let Some(task) = self.tasks.lock().unwrap().get_mut(key) {
+            task.duration = Some(task.started.elapsed());
+            task.status = status;
+        }
+    }
+
+    /// Executa os comandos de `task_app` em um script, enviando a saída
+    /// para a tarefa `key`, e aguarda o término
+    fn run_script(&self, task_app: &AppConfig, key: &str) -> TaskStatus {
+        let output = &self.output;
+        let file_stem = format!("iris_task_{}_{}", task_app.id, crate::utils::uuid_simple());
+        let script_file = ProcessManager::write_script(task_app, &file_stem);
+        let spawned = script_file
+            .as_ref()
+            .map_err(|e| e.clone())
+            .and_then(|script_file| ProcessManager::spawn_headless(script_file, &task_app.env));
+
+        let status = match spawned {
+            Ok(mut child) => {
+                // Sem entrada: comandos que leem do stdin recebem EOF
+                drop(child.stdin.take());
+                let readers: Vec<_> = [
+                    child.stdout.take().map(|r| ProcessManager::pipe_output(r, key.to_string(), output.clone())),
+                    child.stderr.take().map(|r| ProcessManager::pipe_output(r, key.to_string(), output.clone())),
+                ]
+                .into_iter()
+                .flatten()
+                .collect();
+
+                let exit = child.wait();
+                for reader in readers {
+                    let _ = reader.join();
+                }
+                match exit {
+                    Ok(exit) if exit.success() => TaskStatus::Succeeded,
+                    Ok(exit) => TaskStatus::Failed(exit.code()),
+                    Err(e) => {
+                        output.push_line(key, &format!("[IRIS] Erro ao aguardar tarefa: {}", e));
+                        TaskStatus::Failed(None)
+                    }
+                }
+            }
+            Err(e) => {
+                output.push_line(key, &format!("[IRIS] {}", e));
+                TaskStatus::Failed(None)
+            }
+        };
+
+        if let Ok(script_file) = script_file {
+            let _ = std::fs::remove_file(script_file);
+        }
+        status
+    }
+
+    /// Retorna a última execução da ação, se houver
+    pub fn info(&self, app_id: &str, action_name: &str) -> Option<TaskInfo> {
+        self.tasks.lock().unwrap().get(&task_key(app_id, action_name)).cloned()
+    }
+
+    /// Verifica se alguma tarefa está em execução
+    pub fn has_running(&self) -> bool {
+        self.tasks
+            .lock()
+            .unwrap()
+            .values()
+            .any(|task| task.status == TaskStatus::Running)
+    }
+}
+
+/// Linha de comando do `git clone` no shell do sistema
+fn clone_command(repository: &crate::core::Repository, dir: &str) -> String {
+    let args = repository.clone_args(dir).into_iter();
+    #[cfg(windows)]
+    let args: Vec<String> = args.map(|arg| format!("\"{}\"", arg)).collect();
+    #[cfg(not(windows))]
+    let args: Vec<String> = args.map(|arg| crate::utils::shell_quote(&arg)).collect();
+    format!("git {}", args.join(" "))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(unix)]
+    fn wait_finished(runner: &TaskRunner, app_id: &str, name: &str) -> TaskInfo {
+        for _ in 0..100 {
+            if let Some(info) = runner.info(app_id, name) {
+                if info.status != TaskStatus::Running {
+                    return info;
+                }
+            }
+            std::thread::sleep(Duration::from_millis(50));
+        }
+        panic!("tarefa não terminou");
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_task_captures_output_env_and_exit_code() {
+        let app = AppConfig::new("Teste".to_string());
+        let ok = CustomAction {
+            name: "Eco".to_string(),
+            commands: vec!["echo \"porta $PORT\"".to_string()],
+            env: vec![crate::core::EnvVar { key: "PORT".to_string(), value: "4000".to_string() }],
+            ..Default::default()
+        };
+        let failing = CustomAction {
+            name: "Falha".to_string(),
+            commands: vec!["exit 3".to_string()],
+            ..Default::default()
+        };
+
+        let runner = TaskRunner::new();
+        let vars = Interpolator::default();
+        runner.run(&app, &ok, &vars).unwrap();
+        runner.run(&app, &failing, &vars).unwrap();
+
+        assert_eq!(wait_finished(&runner, &app.id, "Eco").status, TaskStatus::Succeeded);
+        assert_eq!(
+            runner.output().recent_lines(&task_key(&app.id, "Eco"), 10),
+            vec!["porta 4000".to_string()]
+        );
+        assert_eq!(wait_finished(&runner, &app.id, "Falha").status, TaskStatus::Failed(Some(3)));
+        assert!(!runner.has_running());
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_bootstrap_clones_and_runs_setup_steps() {
+        let base = std::env::temp_dir().join(format!("iris_bootstrap_{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&base);
+        let source = base.join("origem");
+        std::fs::create_dir_all(&source).unwrap();
+        std::fs::write(source.join("README"), "iris").unwrap();
+        let git = |args: &[&str], dir: &Path| {
+            let status = std::process::Command::new("git")
+                .args(["-c", "user.name=Iris", "-c", "user.email=iris@example.com"])
+                .args(args)
+                .current_dir(dir)
+                .output()
+                .unwrap()
+                .status;
+            assert!(status.success(), "git {:?}", args);
+        };
+        git(&["init", "-q", "-b", "main"], &source);
+        git(&["add", "."], &source);
+        git(&["commit", "-q", "-m", "inicial"], &source);
+        git(&["clone", "-q", "--bare", "origem", "remoto.git"], &base);
+
+        let dir = base.join("clones").join("app");
+        let app = AppConfig {
+            working_dir: dir.display().to_string(),
+            repository: Some(crate::core::Repository {
+                url: base.join("remoto.git").display().to_string(),
+                branch: "main".to_string(),
+            }),
+            setup: vec!["touch configurado".to_string()],
+            ..AppConfig::new("Clonada".to_string())
+        };
+        assert!(app.needs_clone(&app.working_dir));
+
+        let runner = TaskRunner::new();
+        runner.bootstrap(&app, &Interpolator::default()).unwrap();
+        assert_eq!(wait_finished(&runner, &app.id, BOOTSTRAP_TASK).status, TaskStatus::Succeeded);
+        assert!(dir.join("README").is_file());
+        assert!(dir.join("configurado").is_file());
+        assert!(!app.needs_clone(&app.working_dir));
+        let _ = std::fs::remove_dir_all(&base);
+    }
+}
@@ -3,14 +3,28 @@
 //! Este módulo contém a estrutura principal da aplicação e a
 //! implementação do trait `eframe::App`.
 
-use std::sync::Arc;
+use std::collections::HashMap;
+use std::path::Path;
 use std::time::Duration;
 use eframe::egui;
 
-use crate::core::{AppConfig, AppState, ConfigManager, IconInfo};
-use crate::services::{IconCache, ProcessManager, load_available_icons};
-use crate::ui::components::{render_app_card, render_header, render_footer, render_empty_state, render_no_results};
-use crate::ui::dialogs::{AppModalState, AppModalResult, DeleteConfirmResult, render_app_modal, render_delete_confirm};
+use crate::core::importers::{compose, procfile, vscode};
+use crate::core::portable;
+use crate::core::project::{compute_overrides, find_project_file, is_from, load_project, sync_project};
+use crate::core::{apply_import, diff_states, merge_states, short_hash, AppConfig, AppState, ConfigError, ConfigFormat, ConfigManager, Trash, UndoStack, IconInfo, Interpolator, RunMode};
+use crate::services::{
+    AppSignal, ConfigWatcher, FileWatcher, IconCache, ProcessManager, SecretStore, TaskRunner, load_available_icons, task_key, generate_scripts, write_scripts, TaskStatus, BOOTSTRAP_TASK, MAX_LOG_LINES,
+};
+use crate::ui::components::{
+    render_app_card, render_header, render_footer, render_empty_state, render_no_results, render_toast, CardStatus, Toast,
+    ToastButton,
+};
+use crate::ui::dialogs::{
+    AppModalState, AppModalResult, MergeConflictsResult, MergeConflictsState, HistoryDialogResult, HistoryDialogState, ImportPreviewResult, ImportPreviewState, ImportReviewResult, ImportReviewState, ConfigRecoveryResult, ConfigRecoveryState, DeleteConfirmResult, ExportDialogResult, ExportDialogState, LaunchPromptResult, LaunchPromptState, LogViewerResult, ProcessControls, ScanWizardResult, ScanWizardState, ScriptExportDialogState, ScriptExportResult, SecretsDialogResult,
+    SecretsDialogState, TaskOutputResult,
+    TerminalWindowResult, TrashDialogResult, WorkspaceSettingsResult, WorkspaceSettingsState, render_app_modal, render_config_recovery, render_delete_confirm, render_export_dialog, render_history_dialog, render_import_preview, render_import_review,
+    render_launch_prompt, render_log_viewer, render_merge_conflicts, render_scan_wizard, render_script_export_dialog, render_secrets_dialog, render_task_output, render_terminal_window, render_trash_dialog, render_workspace_settings,
+};
 use crate::ui::theme;
 use crate::utils::uuid_simple;
 
@@ -21,9 +35,29 @@ pub struct AppHub {
     // Dados
     state: AppState,
     config_manager: ConfigManager,
+    /// Observa alterações externas no arquivo de configuração
+    config_watcher: ConfigWatcher,
+    /// Último estado lido ou gravado no arquivo (base da mesclagem)
+    synced: AppState,
+    /// Hash do conteúdo do arquivo correspondente a `synced`
+    synced_hash: Option<String>,
+    /// Alterações da sessão que podem ser desfeitas
+    undo: UndoStack,
+    /// Aplicações excluídas
+    trash: Trash,
+    /// Observadores dos arquivos de projeto, por pasta raiz
+    project_watchers: HashMap<String, ConfigWatcher>,
+    /// Contexto do egui, para criar observadores
+    egui_ctx: egui::Context,
+    /// Se a pasta da aplicação ainda precisa ser clonada, por ID, com o
+    /// estado do bootstrap na última verificação (refeita quando ele muda)
+    clone_status: HashMap<String, (Option<TaskStatus>, bool)>,
     
     // Serviços
     process_manager: ProcessManager,
+    file_watcher: FileWatcher,
+    task_runner: TaskRunner,
+    secret_store: SecretStore,
     icon_cache: IconCache,
     available_icons: Vec<IconInfo>,
     
@@ -33,25 +67,72 @@ pub struct AppHub {
     show_edit_modal: bool,
     modal_state: AppModalState,
     show_delete_confirm: Option<usize>,
+    /// ID da aplicação com a janela de logs aberta
+    show_logs: Option<String>,
+    /// Texto sendo digitado para a entrada padrão da aplicação
+    log_input: String,
+    /// ID da aplicação com o terminal embutido aberto
+    show_terminal: Option<String>,
+    /// ID da aplicação e nome da ação com a janela de saída aberta
+    show_task: Option<(String, String)>,
+    /// Diálogo de variáveis globais (None = fechado)
+    workspace_dialog: Option<WorkspaceSettingsState>,
+    /// Diálogo de parâmetros antes da execução (None = fechado)
+    launch_prompt: Option<LaunchPromptState>,
+    /// Diálogo do cofre de segredos (None = fechado)
+    secrets_dialog: Option<SecretsDialogState>,
+    /// Recuperação de um `config.json` inválido (None = arquivo carregado)
+    recovery: Option<ConfigRecoveryState>,
+    /// Aviso temporário (ex: "Desfazer" após excluir)
+    toast: Option<Toast>,
+    /// Se a lixeira está aberta
+    show_trash: bool,
+    /// Diálogo de histórico de versões (None = fechado)
+    history_dialog: Option<HistoryDialogState>,
+    /// Conflitos entre a interface e o arquivo alterado externamente
+    merge_dialog: Option<MergeConflictsState>,
+    /// Escolha do que exportar (None = fechado)
+    export_dialog: Option<ExportDialogState>,
+    /// Exportação como scripts, Procfile, Makefile ou systemd (None = fechado)
+    script_export: Option<ScriptExportDialogState>,
+    /// Pré-visualização da importação de configurações (None = fechado)
+    import_preview: Option<ImportPreviewState>,
+    /// Revisão das aplicações propostas por um importador (None = fechado)
+    import_review: Option<ImportReviewState>,
+    /// Assistente de descoberta de projetos (None = fechado)
+    scan_wizard: Option<ScanWizardState>,
 }
 
 impl AppHub {
     /// Cria uma nova instância da aplicação
-    pub fn new(_cc: &eframe::CreationContext<'_>) -> Self {
+    pub fn new(cc: &eframe::CreationContext<'_>) -> Self {
         let config_manager = ConfigManager::new();
-        let mut state = config_manager.load();
-        
-        // Garantir que todos os apps tenham ID
-        for app in &mut state.apps {
-            if app.id.is_empty() {
-                app.id = uuid_simple();
+        let (state, recovery) = match config_manager.try_load() {
+            Ok(state) => (state, None),
+            Err(error) => {
+                let backups = config_manager.list_backups();
+                (AppState::default(), Some(ConfigRecoveryState::new(error, backups)))
             }
-        }
+        };
+
+        let trash = Trash::load(config_manager.path().with_file_name("trash.json"));
+        let config_watcher = ConfigWatcher::new(config_manager.path(), cc.egui_ctx.clone());
 
-        Self {
-            state,
+        let mut hub = Self {
+            state: AppState::default(),
             config_manager,
+            config_watcher,
+            synced: AppState::default(),
+            synced_hash: None,
+            undo: UndoStack::default(),
+            trash,
+            project_watchers: HashMap::new(),
+            egui_ctx: cc.egui_ctx.clone(),
+            clone_status: HashMap::new(),
             process_manager: ProcessManager::new(),
+            file_watcher: FileWatcher::new(cc.egui_ctx.clone()),
+            task_runner: TaskRunner::new(),
+            secret_store: SecretStore::new(),
             icon_cache: IconCache::new(),
             available_icons: load_available_icons(),
             search_filter: String::new(),
@@ -59,13 +140,670 @@ impl AppHub {
             show_edit_modal: false,
             modal_state: AppModalState::default(),
             show_delete_confirm: None,
+            show_logs: None,
+            log_input: String::new(),
+            show_terminal: None,
+            show_task: None,
+            workspace_dialog: None,
+            launch_prompt: None,
+            secrets_dialog: None,
+            recovery,
+            toast: None,
+            show_trash: false,
+            history_dialog: None,
+            merge_dialog: None,
+            export_dialog: None,
+            script_export: None,
+            import_preview: None,
+            import_review: None,
+            scan_wizard: None,
+        };
+        hub.set_state(state);
+
+        // Arquivos de projeto podem ter mudado com o Iris fechado
+        let roots: Vec<String> = hub.state.projects.iter().map(|link| link.root.clone()).collect();
+        for root in roots {
+            hub.refresh_project(&root);
+        }
+        hub
+    }
+
+    /// Substitui o estado carregado e sincroniza os serviços
+    fn set_state(&mut self, mut state: AppState) {
+        // Garantir que todos os apps tenham ID
+        for app in &mut state.apps {
+            if app.id.is_empty() {
+                app.id = uuid_simple();
+            }
+        }
+        self.state = state;
+        self.mark_synced();
+        self.sync_watchers();
+        self.clone_status.clear();
+    }
+
+    /// Registra o estado atual como igual ao conteúdo do arquivo
+    fn mark_synced(&mut self) {
+        self.synced = self.state.clone();
+        self.synced_hash = self.config_manager.file_hash();
+    }
+
+    /// Salva o estado atual em disco.
+    /// 
+    /// Durante a recuperação de um arquivo inválido nada é gravado,
+    /// para não sobrescrever a configuração do usuário.
+    fn save_state(&mut self) {
+        if self.recovery.is_some() {
+            return;
+        }
+        match self.config_manager.save(&self.state) {
+            Ok(()) => self.mark_synced(),
+            Err(e) => eprintln!("Erro ao salvar: {}", e),
+        }
+        self.sync_watchers();
+        self.clone_status.clear();
+    }
+
+    /// Registra o estado atual (aplicações, workspace e projetos) antes de
+    /// uma alteração
+    fn record_change(&mut self, label: &str) {
+        self.undo.record(label, &self.state);
+    }
+
+    /// Substitui a lista de aplicações e salva (ex: reverter pelo histórico).
+    /// 
+    /// Aplicações que saem da lista são paradas e vão para a lixeira;
+    /// as que voltam são retiradas dela.
+    fn replace_apps(&mut self, apps: Vec<AppConfig>) {
+        self.swap_apps(apps);
+        self.save_state();
+    }
+
+    /// Restaura o estado completo ao desfazer ou refazer.
+    /// 
+    /// Aplicações que saem da lista são paradas, mas não vão para a
+    /// lixeira (a alteração desfeita não as excluiu); as que voltam são
+    /// retiradas dela.
+    fn restore_state(&mut self, state: AppState) {
+        self.leave_apps(&state.apps, false);
+        self.state = state;
+        self.save_state();
+    }
+
+    /// Troca a lista de aplicações sem salvar, parando as que saem da
+    /// lista e movendo-as para a lixeira
+    fn swap_apps(&mut self, apps: Vec<AppConfig>) {
+        self.leave_apps(&apps, true);
+        self.state.apps = apps;
+    }
+
+    /// Para as aplicações que não estão em `apps` e atualiza a lixeira:
+    /// as que continuam saem dela e, se `trash_removed`, as que saem entram
+    fn leave_apps(&mut self, apps: &[AppConfig], trash_removed: bool) {
+        let removed: Vec<AppConfig> = self.state.apps
+            .iter()
+            .filter(|app| !apps.iter().any(|a| a.id == app.id))
+            .cloned()
+            .collect();
+        for app in &removed {
+            if self.process_manager.is_running(&app.id) {
+                self.process_manager.stop_app(&app.id, Some(&app.name), Some(&app.commands));
+            }
+        }
+
+        let kept_ids: Vec<&str> = apps.iter().map(|a| a.id.as_str()).collect();
+        let mut trash_result = self.trash.forget(&kept_ids);
+        if trash_removed {
+            trash_result = trash_result.and_then(|_| self.trash.push(removed));
+        }
+        if let Err(e) = trash_result {
+            eprintln!("{}", e);
         }
     }
 
-    /// Salva o estado atual em disco
-    fn save_state(&self) {
-        if let Err(e) = self.config_manager.save(&self.state) {
-            eprintln!("Erro ao salvar: {}", e);
+    /// Recarrega o arquivo de configuração alterado fora do Iris,
+    /// mesclando-o com as alterações feitas na interface.
+    fn reload_external(&mut self) {
+        if self.recovery.is_some() {
+            // O arquivo inválido pode ter sido corrigido no editor
+            if let Ok(state) = self.config_manager.try_load() {
+                self.recovery = None;
+                self.set_state(state);
+            }
+            return;
+        }
+        let hash = self.config_manager.file_hash();
+        if hash.is_none() || hash == self.synced_hash || self.merge_dialog.is_some() {
+            return;
+        }
+
+        let remote = match self.config_manager.try_load() {
+            Ok(remote) => remote,
+            Err(e) => {
+                let location = e.location.map(|(line, col)| format!(" (linha {}, coluna {})", line, col));
+                self.toast = Some(Toast::new(
+                    format!("config.json alterado externamente é inválido{}", location.unwrap_or_default()),
+                    None,
+                ));
+                return;
+            }
+        };
+
+        let merge = merge_states(&self.synced, &self.state, &remote);
+        if merge.conflicts.is_empty() && !merge.workspace_conflict {
+            self.apply_external(merge.state, remote);
+        } else {
+            self.merge_dialog = Some(MergeConflictsState::new(merge, remote));
+        }
+    }
+
+    /// Aplica o resultado da mesclagem com o arquivo externo.
+    /// 
+    /// As aplicações em execução continuam associadas pelo ID; o arquivo
+    /// só é regravado se a interface tinha alterações próprias. Os
+    /// observadores de arquivos e de projetos acompanham o novo estado.
+    fn apply_external(&mut self, merged: AppState, remote: AppState) {
+        self.record_change("Recarregar config.json");
+        self.swap_apps(merged.apps);
+        self.state.workspace = merged.workspace;
+        self.state.projects = merged.projects;
+
+        self.synced = remote;
+        self.synced_hash = self.config_manager.file_hash();
+        if self.state == self.synced {
+            self.sync_watchers();
+            self.clone_status.clear();
+        } else {
+            self.save_state();
+        }
+        self.toast = Some(Toast::new("config.json recarregado", Some(ToastButton::Undo)));
+    }
+
+    /// Renderiza o diálogo de conflitos da recarga externa
+    fn render_merge_conflicts(&mut self, ctx: &egui::Context) {
+        let Some(dialog) = &mut self.merge_dialog else {
+            return;
+        };
+        if let MergeConflictsResult::Apply = render_merge_conflicts(ctx, dialog) {
+            let resolved = dialog.resolved();
+            let remote = dialog.remote.clone();
+            self.merge_dialog = None;
+            self.apply_external(resolved, remote);
+        }
+    }
+
+    /// Desfaz a última alteração
+    fn undo(&mut self) {
+        if let Some((label, state)) = self.undo.undo(&self.state) {
+            self.restore_state(state);
+            self.toast = Some(Toast::new(format!("Desfeito: {}", label), Some(ToastButton::Redo)));
+        }
+    }
+
+    /// Refaz a última alteração desfeita
+    fn redo(&mut self) {
+        if let Some((label, state)) = self.undo.redo(&self.state) {
+            self.restore_state(state);
+            self.toast = Some(Toast::new(format!("Refeito: {}", label), Some(ToastButton::Undo)));
+        }
+    }
+
+    /// Trata Ctrl+Z / Ctrl+Shift+Z quando nenhum campo de texto está em foco
+    fn handle_undo_shortcuts(&mut self, ctx: &egui::Context) {
+        let editing = self.show_add_modal
+            || self.show_edit_modal
+            || ctx.memory(|m| m.focused().is_some());
+        if editing || self.recovery.is_some() {
+            return;
+        }
+        // Ctrl+Shift+Z primeiro: Ctrl+Z também corresponderia com Shift pressionado
+        let redo = egui::KeyboardShortcut::new(egui::Modifiers::COMMAND | egui::Modifiers::SHIFT, egui::Key::Z);
+        let undo = egui::KeyboardShortcut::new(egui::Modifiers::COMMAND, egui::Key::Z);
+        if ctx.input_mut(|i| i.consume_shortcut(&redo)) {
+            self.redo();
+        } else if ctx.input_mut(|i| i.consume_shortcut(&undo)) {
+            self.undo();
+        }
+    }
+
+    /// Renderiza o aviso temporário e trata o botão clicado
+    fn render_toast(&mut self, ctx: &egui::Context) {
+        let Some(toast) = &self.toast else {
+            return;
+        };
+        if toast.is_expired() {
+            self.toast = None;
+            return;
+        }
+        match render_toast(ctx, toast) {
+            Some(ToastButton::Undo) => self.undo(),
+            Some(ToastButton::Redo) => self.redo(),
+            None => {}
+        }
+    }
+
+    /// Renderiza a lixeira e aplica a ação escolhida
+    fn render_trash(&mut self, ctx: &egui::Context) {
+        if !self.show_trash {
+            return;
+        }
+        let outcome = match render_trash_dialog(ctx, self.trash.items()) {
+            TrashDialogResult::None => return,
+            TrashDialogResult::Close => {
+                self.show_trash = false;
+                return;
+            }
+            TrashDialogResult::Restore(index) => match self.trash.take(index) {
+                Ok(mut app) => {
+                    self.record_change(&format!("Restaurar {}", app.name));
+                    // Sem o projeto, a aplicação restaurada passa a ser local
+                    let linked = app.project.as_ref()
+                        .is_some_and(|p| self.state.projects.iter().any(|link| link.root == p.root));
+                    if !linked {
+                        app.project = None;
+                    }
+                    self.state.add_app(app);
+                    self.save_state();
+                    Ok(())
+                }
+                Err(e) => Err(e),
+            },
+            TrashDialogResult::Purge(index) => self.trash.purge(index),
+            TrashDialogResult::Empty => self.trash.empty(),
+        };
+        if let Err(e) = outcome {
+            eprintln!("{}", e);
+        }
+    }
+
+    /// Abre o histórico comparando a versão atual com a anterior
+    fn open_history(&mut self) {
+        let mut dialog = HistoryDialogState::new(self.config_manager.history().entries());
+        dialog.diff = self.compare_versions(&dialog);
+        self.history_dialog = Some(dialog);
+    }
+
+    /// Calcula as diferenças entre as versões selecionadas no histórico
+    fn compare_versions(&self, dialog: &HistoryDialogState) -> Result<Vec<crate::core::AppDiff>, String> {
+        let (Some(from), Some(to)) = (dialog.entries.get(dialog.from), dialog.entries.get(dialog.to)) else {
+            return Ok(Vec::new());
+        };
+        let history = self.config_manager.history();
+        let old = history.load(&from.hash).map_err(|e| e.to_string())?;
+        let new = history.load(&to.hash).map_err(|e| e.to_string())?;
+        Ok(diff_states(&old, &new))
+    }
+
+    /// Renderiza o histórico e aplica a ação escolhida
+    fn render_history(&mut self, ctx: &egui::Context) {
+        let Some(mut dialog) = self.history_dialog.take() else {
+            return;
+        };
+        let from = dialog.entries.get(dialog.from).cloned();
+
+        match render_history_dialog(ctx, &mut dialog) {
+            HistoryDialogResult::None => {}
+            HistoryDialogResult::Close => return,
+            HistoryDialogResult::Compare => dialog.diff = self.compare_versions(&dialog),
+            HistoryDialogResult::RevertApp(app_id) => {
+                if let Some(from) = from {
+                    self.revert_app(&from.hash, &app_id);
+                    self.open_history();
+                    return;
+                }
+            }
+            HistoryDialogResult::RevertAll => {
+                if let Some(from) = from {
+                    self.revert_all(&from.hash);
+                    self.open_history();
+                    return;
+                }
+            }
+        }
+        self.history_dialog = Some(dialog);
+    }
+
+    /// Volta uma aplicação ao estado de uma versão do histórico
+    /// (removendo-a, se ela não existia naquela versão)
+    fn revert_app(&mut self, hash: &str, app_id: &str) {
+        let old = match self.config_manager.history().load(hash) {
+            Ok(old) => old,
+            Err(e) => {
+                eprintln!("{}", e);
+                return;
+            }
+        };
+        let mut apps = self.state.apps.clone();
+        let previous = old.apps.into_iter().find(|a| a.id == app_id);
+        let name = previous
+            .as_ref()
+            .or_else(|| apps.iter().find(|a| a.id == app_id))
+            .map(|a| a.name.clone())
+            .unwrap_or_default();
+        match (previous, apps.iter().position(|a| a.id == app_id)) {
+            (Some(app), Some(index)) => apps[index] = app,
+            (Some(app), None) => apps.push(app),
+            (None, Some(index)) => {
+                apps.remove(index);
+            }
+            (None, None) => return,
+        }
+        self.record_change(&format!("Reverter {}", name));
+        self.replace_apps(apps);
+    }
+
+    /// Volta a configuração inteira a uma versão do histórico
+    fn revert_all(&mut self, hash: &str) {
+        match self.config_manager.history().load(hash) {
+            Ok(old) => {
+                self.record_change(&format!("Reverter para a versão {}", short_hash(hash)));
+                self.state.workspace = old.workspace;
+                // Os vínculos voltam junto, para que os projetos não tragam
+                // de volta as aplicações da versão atual
+                self.state.projects = old.projects;
+                self.replace_apps(old.apps);
+            }
+            Err(e) => eprintln!("{}", e),
+        }
+    }
+
+    /// Adiciona uma pasta com arquivo de projeto (`.iris.toml`)
+    fn add_project_folder(&mut self) {
+        let Some(folder) = rfd::FileDialog::new()
+            .set_title("Adicionar pasta de projeto")
+            .pick_folder()
+        else {
+            return;
+        };
+        let root = folder.display().to_string();
+        if self.state.projects.iter().any(|link| link.root == root) {
+            self.refresh_project(&root);
+            return;
+        }
+
+        let mut state = self.state.clone();
+        match sync_project(&mut state, &root) {
+            Ok(_) => {
+                let count = state.apps.iter().filter(|app| is_from(app, &root)).count();
+                self.record_change(&format!("Adicionar projeto {}", project_name(&root)));
+                self.state.projects = state.projects;
+                self.swap_apps(state.apps);
+                self.save_state();
+                self.toast = Some(Toast::new(
+                    format!("{} aplicações do projeto adicionadas", count),
+                    Some(ToastButton::Undo),
+                ));
+            }
+            Err(e) => self.toast = Some(Toast::new(e, None)),
+        }
+    }
+
+    /// Relê o arquivo de um projeto e atualiza suas aplicações
+    fn refresh_project(&mut self, root: &str) {
+        let mut state = self.state.clone();
+        match sync_project(&mut state, root) {
+            Ok(false) => {}
+            Ok(true) => {
+                self.record_change(&format!("Atualizar projeto {}", project_name(root)));
+                self.swap_apps(state.apps);
+                self.save_state();
+                self.toast = Some(Toast::new(
+                    format!("Projeto {} atualizado", project_name(root)),
+                    Some(ToastButton::Undo),
+                ));
+            }
+            Err(e) => self.toast = Some(Toast::new(e, None)),
+        }
+    }
+
+    /// Remove um projeto e todas as suas aplicações
+    fn remove_project(&mut self, root: &str) {
+        self.record_change(&format!("Remover projeto {}", project_name(root)));
+        self.state.projects.retain(|link| link.root != root);
+        let apps = self.state.apps.iter().filter(|app| !is_from(app, root)).cloned().collect();
+        self.swap_apps(apps);
+        self.save_state();
+        self.toast = Some(Toast::new(
+            format!("Projeto {} removido", project_name(root)),
+            Some(ToastButton::Undo),
+        ));
+    }
+
+    /// Guarda a edição de uma aplicação de projeto como ajustes locais,
+    /// sem alterar o arquivo do repositório
+    fn store_project_overrides(&mut self, app: &AppConfig) {
+        let Some(source) = &app.project else {
+            return;
+        };
+        let pristine = load_project(Path::new(&source.root))
+            .ok()
+            .and_then(|apps| apps.into_iter().find(|a| a.id == app.id));
+        let Some(pristine) = pristine else {
+            return;
+        };
+        let overrides = compute_overrides(&pristine, app);
+        if let Some(link) = self.state.projects.iter_mut().find(|link| link.root == source.root) {
+            if overrides.is_empty() {
+                link.overrides.remove(&source.key);
+            } else {
+                link.overrides.insert(source.key.clone(), overrides);
+            }
+        }
+    }
+
+    /// Move uma aplicação uma posição para trás ou para frente
+    fn move_app(&mut self, index: usize, offset: isize) {
+        let Some(target) = index.checked_add_signed(offset) else {
+            return;
+        };
+        if target >= self.state.apps.len() {
+            return;
+        }
+        self.record_change(&format!("Mover {}", self.state.apps[index].name));
+        self.state.apps.swap(index, target);
+        self.save_state();
+    }
+
+    /// Sincroniza os observadores de arquivos com as aplicações configuradas
+    fn sync_watchers(&mut self) {
+        for (app_id, error) in self.file_watcher.sync(&self.state.apps) {
+            eprintln!("Erro ao observar arquivos da aplicação {}: {}", app_id, error);
+        }
+
+        let projects = &self.state.projects;
+        self.project_watchers.retain(|root, _| projects.iter().any(|link| &link.root == root));
+        for link in projects {
+            if self.project_watchers.contains_key(&link.root) {
+                continue;
+            }
+            if let Some(file) = find_project_file(Path::new(&link.root)) {
+                let watcher = ConfigWatcher::new(&file, self.egui_ctx.clone());
+                self.project_watchers.insert(link.root.clone(), watcher);
+            }
+        }
+    }
+
+    /// Atualiza os projetos cujos arquivos foram alterados
+    fn refresh_changed_projects(&mut self) {
+        let changed: Vec<String> = self.project_watchers
+            .iter()
+            .filter(|(_, watcher)| watcher.take_changed())
+            .map(|(root, _)| root.clone())
+            .collect();
+        for root in changed {
+            self.refresh_project(&root);
+        }
+    }
+
+    /// Verifica quais pastas de repositório ainda não foram clonadas.
+    ///
+    /// Evita ler o disco a cada quadro: só verifica aplicações novas no
+    /// cache ou cujo bootstrap mudou de estado (ex: clone concluído).
+    fn refresh_clone_status(&mut self) {
+        let vars = self.variables();
+        for app in self.state.apps.iter().filter(|app| app.repository.is_some()) {
+            let bootstrap = self.task_runner.info(&app.id, BOOTSTRAP_TASK).map(|info| info.status);
+            if self.clone_status.get(&app.id).is_some_and(|(checked, _)| *checked == bootstrap) {
+                continue;
+            }
+            let missing = vars.expand(&app.working_dir).is_ok_and(|dir| app.needs_clone(&dir));
+            self.clone_status.insert(app.id.clone(), (bootstrap, missing));
+        }
+    }
+
+    /// Variáveis disponíveis para interpolação nas execuções
+    fn variables(&self) -> Interpolator {
+        let vars = Interpolator::new(&self.state.workspace);
+        // Bloqueado, o cofre não entra: `${secret:...}` pede o desbloqueio
+        if self.secret_store.is_unlocked() {
+            vars.with_secrets(self.secret_store.secrets())
+        } else {
+            vars
+        }
+    }
+
+    /// Variáveis para validar o formulário (segredos aceitos mesmo com o cofre bloqueado)
+    fn modal_variables(&self) -> Interpolator {
+        if self.secret_store.is_unlocked() {
+            self.variables()
+        } else {
+            Interpolator::new(&self.state.workspace).with_secret_placeholders()
+        }
+    }
+
+    /// Atualiza os valores mascarados nos logs e nas saídas das tarefas
+    /// (as execuções em andamento continuam mascarando os do início)
+    fn sync_secret_masks(&self) {
+        let values = self.secret_store.mask_values();
+        self.process_manager.logs().set_masked_values(values.clone());
+        self.task_runner.output().set_masked_values(values);
+    }
+
+    /// Renderiza o diálogo de recuperação e aplica a ação escolhida
+    fn render_recovery(&mut self, ctx: &egui::Context) {
+        let Some(recovery) = &mut self.recovery else {
+            return;
+        };
+        let loaded = match render_config_recovery(ctx, self.config_manager.path(), recovery) {
+            ConfigRecoveryResult::None => return,
+            ConfigRecoveryResult::Restore(index) => match recovery.backups.get(index) {
+                Some(backup) => self.config_manager.restore_backup(&backup.path),
+                None => return,
+            },
+            ConfigRecoveryResult::Retry => self.config_manager.try_load(),
+            ConfigRecoveryResult::StartFresh => match self.config_manager.set_aside_current() {
+                Ok(()) => Ok(AppState::default()),
+                Err(e) => Err(ConfigError::new(e)),
+            },
+        };
+
+        match loaded {
+            Ok(state) => {
+                self.recovery = None;
+                self.set_state(state);
+            }
+            Err(error) => {
+                recovery.error = error;
+                recovery.backups = self.config_manager.list_backups();
+            }
+        }
+    }
+
+    /// Renderiza o diálogo de segredos e aplica a ação escolhida
+    fn render_secrets_dialog(&mut self, ctx: &egui::Context) {
+        let Some(dialog) = &mut self.secrets_dialog else {
+            return;
+        };
+        let outcome = match render_secrets_dialog(ctx, &self.secret_store, dialog) {
+            SecretsDialogResult::None => return,
+            SecretsDialogResult::Close => {
+                self.secrets_dialog = None;
+                return;
+            }
+            SecretsDialogResult::Unlock => {
+                let outcome = self.secret_store.unlock(&dialog.passphrase);
+                dialog.passphrase.clear();
+                outcome
+            }
+            SecretsDialogResult::Set => {
+                let outcome = self.secret_store.set(&dialog.new_name, &dialog.new_value);
+                if outcome.is_ok() {
+                    dialog.new_name.clear();
+                    dialog.new_value.clear();
+                }
+                outcome
+            }
+            SecretsDialogResult::Remove(name) => self.secret_store.remove(&name),
+            SecretsDialogResult::Lock => {
+                self.secret_store.lock();
+                Ok(())
+            }
+        };
+        dialog.error = outcome.err();
+        self.sync_secret_masks();
+    }
+
+    /// Solicita a execução: pergunta os parâmetros, se houver, antes de iniciar
+    fn request_launch(&mut self, index: usize, profile: Option<String>) {
+        let app = &self.state.apps[index];
+        if app.params.is_empty() {
+            self.launch_app(index, profile.as_deref());
+        } else {
+            self.launch_prompt = Some(LaunchPromptState::new(app, profile));
+        }
+    }
+
+    /// Renderiza o diálogo de parâmetros e inicia a aplicação ao confirmar
+    fn render_launch_prompt(&mut self, ctx: &egui::Context) {
+        let Some(prompt) = &mut self.launch_prompt else {
+            return;
+        };
+        let Some(index) = self.state.apps.iter().position(|a| a.id == prompt.app_id) else {
+            self.launch_prompt = None;
+            return;
+        };
+        
+        let app = &self.state.apps[index];
+        match render_launch_prompt(ctx, &app.name, &app.params, prompt) {
+            LaunchPromptResult::Launch => {
+                let Some(prompt) = self.launch_prompt.take() else {
+                    return;
+                };
+                self.state.apps[index].param_values = prompt.values;
+                self.save_state();
+                self.launch_app(index, prompt.profile.as_deref());
+            }
+            LaunchPromptResult::Cancelled => self.launch_prompt = None,
+            LaunchPromptResult::None => {}
+        }
+    }
+
+    /// Inicia uma aplicação com um perfil, exibindo os logs se a interpolação falhar
+    fn launch_app(&mut self, index: usize, profile: Option<&str>) {
+        let app = self.state.apps[index].clone();
+        if self.process_manager.launch_profile(&app, profile, &self.variables()).is_err() {
+            self.show_logs = Some(app.id);
+        } else if app.run_mode == RunMode::Terminal {
+            self.show_terminal = Some(app.id);
+        }
+    }
+
+    /// Reinicia uma aplicação, exibindo os logs se a interpolação falhar
+    fn restart_app(&mut self, app_id: &str) {
+        let Some(app) = self.state.find_by_id(app_id).cloned() else {
+            return;
+        };
+        if self.process_manager.restart_app(&app, &self.variables()).is_err() {
+            self.show_logs = Some(app.id);
+        }
+    }
+
+    /// Reinicia as aplicações em execução cujos arquivos observados foram alterados
+    fn restart_changed_apps(&mut self) {
+        for app_id in self.file_watcher.take_due() {
+            if self.process_manager.is_running(&app_id) {
+                self.restart_app(&app_id);
+            }
         }
     }
 
@@ -94,10 +832,16 @@ impl AppHub {
     fn handle_modal_result(&mut self, result: AppModalResult) {
         match result {
             AppModalResult::Save(app, edit_index) => {
+                let label = match edit_index {
+                    Some(_) => format!("Editar {}", app.name),
+                    None => format!("Adicionar {}", app.name),
+                };
+                self.record_change(&label);
+                self.store_project_overrides(&app);
                 if let Some(index) = edit_index {
-                    self.state.apps[index] = app;
+                    self.state.apps[index] = *app;
                 } else {
-                    self.state.add_app(app);
+                    self.state.add_app(*app);
                 }
                 self.save_state();
                 self.close_modal();
@@ -113,8 +857,23 @@ impl AppHub {
     fn handle_delete_result(&mut self, result: DeleteConfirmResult) {
         match result {
             DeleteConfirmResult::Confirmed(index) => {
-                self.state.remove_app(index);
-                self.save_state();
+                let project_root = self.state.apps.get(index)
+                    .and_then(|app| app.project.as_ref())
+                    .map(|project| project.root.clone());
+                if let Some(root) = project_root {
+                    // Aplicações de projeto só saem junto com o projeto
+                    self.remove_project(&root);
+                } else if let Some(app) = self.state.apps.get(index).cloned() {
+                    // Como nas demais remoções: para a aplicação e a move para a lixeira
+                    self.record_change(&format!("Excluir {}", app.name));
+                    let mut apps = self.state.apps.clone();
+                    apps.remove(index);
+                    self.replace_apps(apps);
+                    self.toast = Some(Toast::new(
+                        format!("\"{}\" movida para a lixeira", app.name),
+                        Some(ToastButton::Undo),
+                    ));
+                }
                 self.show_delete_confirm = None;
             }
             DeleteConfirmResult::Cancelled => {
@@ -125,35 +884,342 @@ impl AppHub {
     }
 
     /// Exporta as configurações para um arquivo
-    fn export_config(&self) {
-        if let Some(path) = rfd::FileDialog::new()
-            .set_title("Exportar configurações do Iris")
-            .set_file_name("iris-config.json")
-            .add_filter("JSON", &["json"])
-            .save_file()
-        {
-            if let Err(e) = self.config_manager.export(&self.state, &path) {
-                eprintln!("{}", e);
+    fn export_config(&mut self) {
+        self.export_dialog = Some(ExportDialogState::new(&self.state));
+    }
+
+    /// Renderiza a escolha do que exportar e grava o arquivo
+    fn render_export_dialog(&mut self, ctx: &egui::Context) {
+        let Some(export) = &mut self.export_dialog else {
+            return;
+        };
+        match render_export_dialog(ctx, export) {
+            ExportDialogResult::None => {}
+            ExportDialogResult::Cancelled => self.export_dialog = None,
+            ExportDialogResult::Export => {
+                let dialog = ConfigFormat::ALL.iter().fold(
+                    rfd::FileDialog::new()
+                        .set_title("Exportar configurações do Iris")
+                        .set_file_name("iris-config.json"),
+                    |dialog, format| dialog.add_filter(format.label(), format.extensions()),
+                );
+                let Some(path) = dialog.save_file() else {
+                    return;
+                };
+                let exported = portable::export_state(&self.state, &export.selected_ids(), export.portable_base());
+                self.export_dialog = None;
+                let store = &self.secret_store;
+                self.toast = Some(match self.config_manager.export(&exported, &path, |text| store.redact(text)) {
+                    Ok(()) => Toast::new(format!("{} aplicações exportadas", exported.apps.len()), None),
+                    Err(e) => Toast::new(e, None),
+                });
+            }
+        }
+    }
+
+    /// Renderiza a exportação como script e grava os arquivos gerados
+    fn render_script_export(&mut self, ctx: &egui::Context) {
+        let Some(export) = &mut self.script_export else {
+            return;
+        };
+        match render_script_export_dialog(ctx, export) {
+            ScriptExportResult::None => {}
+            ScriptExportResult::Cancelled => self.script_export = None,
+            ScriptExportResult::Export => {
+                let ids = export.selected_ids();
+                let variables = Interpolator::new(&self.state.workspace);
+                let mut apps = Vec::new();
+                for app in self.state.apps.iter().filter(|app| ids.contains(&app.id)) {
+                    if serde_json::to_string(app).is_ok_and(|json| json.contains("${secret:")) {
+                        self.toast = Some(Toast::new(
+                            format!("{} usa segredos do cofre, que não são exportados em scripts", app.name),
+                            None,
+                        ));
+                        return;
+                    }
+                    match variables.expand_app(app) {
+                        Ok(expanded) => apps.push(expanded),
+                        Err(e) => {
+                            self.toast = Some(Toast::new(format!("{}: {}", app.name, e), None));
+                            return;
+                        }
+                    }
+                }
+                let Some(dir) = rfd::FileDialog::new().set_title("Pasta dos scripts").pick_folder() else {
+                    return;
+                };
+                let files = generate_scripts(export.target, &apps, &export.name);
+                self.script_export = None;
+                self.toast = Some(match write_scripts(&dir, &files) {
+                    Ok(()) => Toast::new(format!("{} arquivos gerados em {}", files.len(), dir.display()), None),
+                    Err(e) => Toast::new(e, None),
+                });
             }
         }
     }
 
     /// Importa configurações de um arquivo
     fn import_config(&mut self) {
-        if let Some(path) = rfd::FileDialog::new()
-            .set_title("Importar configurações do Iris")
-            .add_filter("JSON", &["json"])
-            .pick_file()
-        {
+        let all_extensions: Vec<&str> = ConfigFormat::ALL.iter().flat_map(|f| f.extensions().iter().copied()).collect();
+        let dialog = ConfigFormat::ALL.iter().fold(
+            rfd::FileDialog::new()
+                .set_title("Importar configurações do Iris")
+                .add_filter("Configurações (JSON, TOML, YAML)", &all_extensions),
+            |dialog, format| dialog.add_filter(format.label(), format.extensions()),
+        );
+        if let Some(path) = dialog.pick_file() {
             match self.config_manager.import(&path) {
                 Ok(imported_state) => {
-                    self.state.apps.extend(imported_state.apps);
-                    self.save_state();
+                    self.import_preview = Some(ImportPreviewState::new(
+                        path.display().to_string(),
+                        &self.state.apps,
+                        imported_state.apps,
+                        &self.state.workspace.root,
+                    ));
+                }
+                Err(e) => self.toast = Some(Toast::new(e, None)),
+            }
+        }
+    }
+
+    /// Renderiza a pré-visualização da importação e aplica o plano
+    fn render_import_preview(&mut self, ctx: &egui::Context) {
+        let Some(preview) = &mut self.import_preview else {
+            return;
+        };
+        match render_import_preview(ctx, preview) {
+            ImportPreviewResult::None => {}
+            ImportPreviewResult::Cancelled => self.import_preview = None,
+            ImportPreviewResult::Replan => preview.replan(&self.state.apps),
+            ImportPreviewResult::Apply => {
+                let plan = std::mem::take(&mut preview.items);
+                self.import_preview = None;
+                self.record_change(&format!("Importar {} aplicações", plan.len()));
+                let summary = apply_import(&mut self.state.apps, &plan);
+                self.save_state();
+                let mut parts = vec![format!("{} adicionadas", summary.added)];
+                if summary.overwritten > 0 {
+                    parts.push(format!("{} sobrescritas", summary.overwritten));
+                }
+                if summary.merged > 0 {
+                    parts.push(format!("{} mescladas", summary.merged));
+                }
+                if summary.skipped > 0 {
+                    parts.push(format!("{} ignoradas", summary.skipped));
                 }
-                Err(e) => {
-                    eprintln!("{}", e);
+                self.toast = Some(Toast::new(
+                    format!("Importação: {}", parts.join(", ")),
+                    Some(ToastButton::Undo),
+                ));
+            }
+        }
+    }
+
+    /// Propõe aplicações a partir de um docker-compose para revisão
+    fn import_compose(&mut self) {
+        let Some(path) = rfd::FileDialog::new()
+            .set_title("Importar docker-compose")
+            .add_filter("Docker Compose", &["yml", "yaml"])
+            .pick_file()
+        else {
+            return;
+        };
+        match compose::propose(&path) {
+            Ok(proposal) => self.import_review = Some(ImportReviewState::new("🐳 Importar docker-compose", proposal)),
+            Err(e) => self.toast = Some(Toast::new(e, None)),
+        }
+    }
+
+    /// Propõe aplicações a partir de um Procfile para revisão
+    fn import_procfile(&mut self) {
+        let Some(path) = rfd::FileDialog::new()
+            .set_title("Importar Procfile")
+            .pick_file()
+        else {
+            return;
+        };
+        match procfile::propose(&path) {
+            Ok(proposal) => self.import_review = Some(ImportReviewState::new("📜 Importar Procfile", proposal)),
+            Err(e) => self.toast = Some(Toast::new(e, None)),
+        }
+    }
+
+    /// Propõe aplicações a partir de um tasks.json do VS Code para revisão
+    fn import_vscode(&mut self) {
+        let Some(path) = rfd::FileDialog::new()
+            .set_title("Importar tarefas do VS Code")
+            .add_filter("tasks.json", &["json"])
+            .pick_file()
+        else {
+            return;
+        };
+        match vscode::propose(&path) {
+            Ok(proposal) => self.import_review = Some(ImportReviewState::new("🆚 Importar tarefas do VS Code", proposal)),
+            Err(e) => self.toast = Some(Toast::new(e, None)),
+        }
+    }
+
+    /// Renderiza a revisão da importação e adiciona as aplicações escolhidas
+    fn render_import_review(&mut self, ctx: &egui::Context) {
+        let Some(review) = &mut self.import_review else {
+            return;
+        };
+        match render_import_review(ctx, review) {
+            ImportReviewResult::None => {}
+            ImportReviewResult::Cancelled => self.import_review = None,
+            ImportReviewResult::Add => {
+                let apps = review.chosen();
+                self.import_review = None;
+                let count = apps.len();
+                self.record_change(&format!("Importar {} aplicações", count));
+                self.state.apps.extend(apps);
+                self.save_state();
+                self.toast = Some(Toast::new(
+                    format!("{} aplicações importadas", count),
+                    Some(ToastButton::Undo),
+                ));
+            }
+        }
+    }
+
+    /// Renderiza o assistente de descoberta e cria as aplicações escolhidas
+    fn render_scan_wizard(&mut self, ctx: &egui::Context) {
+        let Some(wizard) = &mut self.scan_wizard else {
+            return;
+        };
+        match render_scan_wizard(ctx, wizard) {
+            ScanWizardResult::None => {}
+            ScanWizardResult::Close => self.scan_wizard = None,
+            ScanWizardResult::PickRoot => {
+                if let Some(path) = rfd::FileDialog::new().set_title("Escanear pasta").pick_folder() {
+                    wizard.root = path.display().to_string();
+                    wizard.group_name = project_name(&wizard.root).to_string();
+                    wizard.start_scan();
+                }
+            }
+            ScanWizardResult::Scan => wizard.start_scan(),
+            ScanWizardResult::CancelScan => wizard.scanning = None,
+            ScanWizardResult::Create => {
+                let apps = wizard.apps();
+                self.scan_wizard = None;
+                let count = apps.len();
+                self.record_change(&format!("Criar {} aplicações", count));
+                self.state.apps.extend(apps);
+                self.save_state();
+                self.toast = Some(Toast::new(
+                    format!("{} aplicações criadas", count),
+                    Some(ToastButton::Undo),
+                ));
+            }
+        }
+    }
+
+    /// Renderiza a janela de logs da aplicação selecionada
+    fn render_logs_window(&mut self, ctx: &egui::Context) {
+        let Some(app_id) = self.show_logs.clone() else {
+            return;
+        };
+        let Some(app_name) = self.state.find_by_id(&app_id).map(|a| a.name.clone()) else {
+            self.show_logs = None;
+            return;
+        };
+        
+        let logs = self.process_manager.logs();
+        let lines = logs.recent_lines(&app_id, MAX_LOG_LINES);
+        let status = logs.status(&app_id);
+        let controls = self.process_manager.is_running(&app_id).then(|| ProcessControls {
+            is_paused: self.process_manager.is_paused(&app_id),
+        });
+        
+        let result = render_log_viewer(ctx, &app_name, &lines, &status, &mut self.log_input, controls);
+        let logs = self.process_manager.logs();
+        match result {
+            LogViewerResult::Clear => logs.clear(&app_id),
+            LogViewerResult::ResetErrors => logs.reset_errors(&app_id),
+            LogViewerResult::SendInput(text) => {
+                if let Err(e) = self.process_manager.send_input(&app_id, &text) {
+                    logs.push_line(&app_id, &format!("[IRIS] {}", e));
+                }
+            }
+            LogViewerResult::Signal(signal) => self.send_signal(&app_id, signal),
+            LogViewerResult::Close => self.show_logs = None,
+            LogViewerResult::None => {}
+        }
+    }
+    
+    /// Envia um sinal à aplicação, registrando falhas no log da aplicação
+    fn send_signal(&self, app_id: &str, signal: AppSignal) {
+        if let Err(e) = self.process_manager.send_signal(app_id, signal) {
+            self.process_manager.logs().push_line(app_id, &format!("[IRIS] {}", e));
+        }
+    }
+
+    /// Renderiza a janela do terminal embutido da aplicação selecionada
+    fn render_terminal(&mut self, ctx: &egui::Context) {
+        let Some(app_id) = self.show_terminal.clone() else {
+            return;
+        };
+        let app_name = self.state.find_by_id(&app_id)
+            .map(|a| a.name.clone())
+            .unwrap_or_default();
+        
+        let result = self.process_manager.with_terminal(&app_id, |session| {
+            render_terminal_window(ctx, &app_name, session)
+        });
+        
+        match result {
+            Some(TerminalWindowResult::None) => {}
+            Some(TerminalWindowResult::Close) | None => self.show_terminal = None,
+        }
+    }
+
+    /// Executa uma ação personalizada e abre a janela com sua saída
+    fn run_action(&mut self, index: usize, action_index: usize) {
+        let app = &self.state.apps[index];
+        let Some(action) = app.actions.get(action_index) else {
+            return;
+        };
+        if let Err(e) = self.task_runner.run(app, action, &self.variables()) {
+            eprintln!("{}", e);
+        }
+        self.show_task = Some((app.id.clone(), action.name.clone()));
+    }
+
+    /// Clona o repositório da aplicação, executa a configuração inicial e
+    /// abre a janela com o progresso
+    fn bootstrap_app(&mut self, index: usize) {
+        let app = &self.state.apps[index];
+        if let Err(e) = self.task_runner.bootstrap(app, &self.variables()) {
+            self.toast = Some(Toast::new(e, None));
+            return;
+        }
+        self.show_task = Some((app.id.clone(), BOOTSTRAP_TASK.to_string()));
+    }
+
+    /// Renderiza a janela de saída da ação personalizada selecionada
+    fn render_task_window(&mut self, ctx: &egui::Context) {
+        let Some((app_id, action_name)) = self.show_task.clone() else {
+            return;
+        };
+        let Some(index) = self.state.apps.iter().position(|a| a.id == app_id) else {
+            self.show_task = None;
+            return;
+        };
+        
+        let title = format!("{} - {}", self.state.apps[index].name, action_name);
+        let lines = self.task_runner.output().recent_lines(&task_key(&app_id, &action_name), MAX_LOG_LINES);
+        let info = self.task_runner.info(&app_id, &action_name);
+        
+        match render_task_output(ctx, &title, &lines, info.as_ref()) {
+            TaskOutputResult::Rerun if action_name == BOOTSTRAP_TASK => self.bootstrap_app(index),
+            TaskOutputResult::Rerun => {
+                let action_index = self.state.apps[index].actions.iter().position(|a| a.name == action_name);
+                if let Some(action_index) = action_index {
+                    self.run_action(index, action_index);
                 }
             }
+            TaskOutputResult::Close => self.show_task = None,
+            TaskOutputResult::None => {}
         }
     }
 
@@ -181,6 +1247,7 @@ impl AppHub {
                     self.search_filter.is_empty()
                         || app.name.to_lowercase().contains(&self.search_filter.to_lowercase())
                         || app.working_dir.to_lowercase().contains(&self.search_filter.to_lowercase())
+                        || app.group.as_ref().is_some_and(|g| g.to_lowercase().contains(&self.search_filter.to_lowercase()))
                 })
                 .map(|(i, _)| i)
                 .collect();
@@ -203,62 +1270,142 @@ impl AppHub {
         let cards_per_row = ((available_width + spacing) / (card_width + spacing)).floor() as usize;
         let cards_per_row = cards_per_row.max(1);
 
-        let mut app_to_launch: Option<usize> = None;
+        let mut app_to_launch: Option<(usize, Option<String>)> = None;
         let mut app_to_stop: Option<usize> = None;
         let mut app_to_restart: Option<usize> = None;
         let mut app_to_edit: Option<usize> = None;
         let mut app_to_delete: Option<usize> = None;
+        let mut app_to_toggle_watch: Option<usize> = None;
+        let mut app_to_show_logs: Option<usize> = None;
+        let mut app_to_show_terminal: Option<usize> = None;
+        let mut app_to_signal: Option<(usize, AppSignal)> = None;
+        let mut action_to_run: Option<(usize, usize)> = None;
+        let mut action_to_view: Option<(usize, usize)> = None;
+        let mut app_to_move: Option<(usize, isize)> = None;
+        let mut app_to_clone: Option<usize> = None;
+        let mut app_to_view_clone: Option<usize> = None;
+        self.refresh_clone_status();
 
-        egui::Grid::new("apps_grid")
-            .spacing([spacing, spacing])
-            .show(ui, |ui| {
-                for (col, &index) in filtered_indices.iter().enumerate() {
-                    let app = &self.state.apps[index];
-                    let is_running = self.process_manager.is_running(&app.id);
-                    let is_loading = self.process_manager.is_loading(&app.id);
-                    
-                    let actions = render_app_card(
-                        ui,
-                        app,
-                        is_running,
-                        is_loading,
-                        &mut self.icon_cache,
-                    );
-                    
-                    if actions.start_clicked {
-                        app_to_launch = Some(index);
-                    }
-                    if actions.stop_clicked {
-                        app_to_stop = Some(index);
-                    }
-                    if actions.restart_clicked {
-                        app_to_restart = Some(index);
-                    }
-                    if actions.edit_clicked {
-                        app_to_edit = Some(index);
-                    }
-                    if actions.delete_clicked {
-                        app_to_delete = Some(index);
-                    }
+        // Aplicações sem grupo primeiro; grupos na ordem em que aparecem
+        let mut sections: Vec<(Option<&str>, Vec<usize>)> = Vec::new();
+        for &index in filtered_indices {
+            let group = self.state.apps[index].group.as_deref();
+            match sections.iter_mut().find(|(g, _)| *g == group) {
+                Some((_, indices)) => indices.push(index),
+                None => sections.push((group, vec![index])),
+            }
+        }
+        sections.sort_by_key(|(group, _)| group.is_some());
+        let sections: Vec<(Option<String>, Vec<usize>)> = sections
+            .into_iter()
+            .map(|(group, indices)| (group.map(str::to_string), indices))
+            .collect();
+
+        for (section, (group, indices)) in sections.iter().enumerate() {
+            if let Some(group) = group {
+                ui.add_space(if section == 0 { 0.0 } else { 12.0 });
+                ui.label(
+                    egui::RichText::new(format!("📂 {} ({})", group, indices.len()))
+                        .size(15.0)
+                        .strong()
+                        .color(theme::ThemeColors::TEXT_SECONDARY),
+                );
+                ui.add_space(8.0);
+            }
+            egui::Grid::new(("apps_grid", section))
+                .spacing([spacing, spacing])
+                .show(ui, |ui| {
+                    for (col, &index) in indices.iter().enumerate() {
+                        let app = &self.state.apps[index];
+                        let status = CardStatus {
+                            is_running: self.process_manager.is_running(&app.id),
+                            is_loading: self.process_manager.is_loading(&app.id),
+                            watch_status: self.file_watcher.status(&app.id),
+                            log: self.process_manager.logs().status(&app.id),
+                            has_terminal: self.process_manager.has_terminal(&app.id),
+                            is_paused: self.process_manager.is_paused(&app.id),
+                            tasks: app.actions
+                                .iter()
+                                .map(|action| self.task_runner.info(&app.id, &action.name).map(|info| info.status))
+                                .collect(),
+                            active_profile: self.process_manager.active_profile(&app.id),
+                            not_cloned: self.clone_status.get(&app.id).is_some_and(|(_, missing)| *missing),
+                            bootstrap: app.repository.as_ref().and_then(|_| {
+                                self.task_runner.info(&app.id, BOOTSTRAP_TASK).map(|info| info.status)
+                            }),
+                        };
+                        
+                        let actions = render_app_card(
+                            ui,
+                            app,
+                            &status,
+                            &mut self.icon_cache,
+                        );
+                        
+                        if actions.start_clicked {
+                            app_to_launch = Some((index, None));
+                        }
+                        if let Some(profile) = actions.start_profile {
+                            app_to_launch = Some((index, Some(profile)));
+                        }
+                        if actions.stop_clicked {
+                            app_to_stop = Some(index);
+                        }
+                        if actions.restart_clicked {
+                            app_to_restart = Some(index);
+                        }
+                        if actions.edit_clicked {
+                            app_to_edit = Some(index);
+                        }
+                        if actions.delete_clicked {
+                            app_to_delete = Some(index);
+                        }
+                        if actions.toggle_watch_clicked {
+                            app_to_toggle_watch = Some(index);
+                        }
+                        if actions.logs_clicked {
+                            app_to_show_logs = Some(index);
+                        }
+                        if actions.terminal_clicked {
+                            app_to_show_terminal = Some(index);
+                        }
+                        if let Some(signal) = actions.signal {
+                            app_to_signal = Some((index, signal));
+                        }
+                        if let Some(action_index) = actions.run_action {
+                            action_to_run = Some((index, action_index));
+                        }
+                        if let Some(action_index) = actions.view_action {
+                            action_to_view = Some((index, action_index));
+                        }
+                        if let Some(offset) = actions.move_by {
+                            app_to_move = Some((index, offset));
+                        }
+                        if actions.clone_clicked {
+                            app_to_clone = Some(index);
+                        }
+                        if actions.view_clone_clicked {
+                            app_to_view_clone = Some(index);
+                        }
 
-                    if (col + 1) % cards_per_row == 0 {
-                        ui.end_row();
+                        if (col + 1) % cards_per_row == 0 {
+                            ui.end_row();
+                        }
                     }
-                }
-            });
+                });
+        }
 
         // Executar ações
-        if let Some(index) = app_to_launch {
-            let app = self.state.apps[index].clone();
-            self.process_manager.launch_app(&app);
+        if let Some((index, profile)) = app_to_launch {
+            self.request_launch(index, profile);
         }
         if let Some(index) = app_to_stop {
             let app = &self.state.apps[index];
             self.process_manager.stop_app(&app.id, Some(&app.name), Some(&app.commands));
         }
         if let Some(index) = app_to_restart {
-            let app = self.state.apps[index].clone();
-            self.process_manager.restart_app(&app);
+            let app_id = self.state.apps[index].id.clone();
+            self.restart_app(&app_id);
         }
         if let Some(index) = app_to_edit {
             self.start_edit_app(index);
@@ -266,6 +1413,36 @@ impl AppHub {
         if let Some(index) = app_to_delete {
             self.show_delete_confirm = Some(index);
         }
+        if let Some(index) = app_to_toggle_watch {
+            self.file_watcher.toggle_pause(&self.state.apps[index].id);
+        }
+        if let Some(index) = app_to_show_logs {
+            self.show_logs = Some(self.state.apps[index].id.clone());
+        }
+        if let Some(index) = app_to_show_terminal {
+            self.show_terminal = Some(self.state.apps[index].id.clone());
+        }
+        if let Some((index, signal)) = app_to_signal {
+            self.send_signal(&self.state.apps[index].id, signal);
+        }
+        if let Some((index, action_index)) = action_to_run {
+            self.run_action(index, action_index);
+        }
+        if let Some((index, offset)) = app_to_move {
+            self.move_app(index, offset);
+        }
+        if let Some((index, action_index)) = action_to_view {
+            let app = &self.state.apps[index];
+            if let Some(action) = app.actions.get(action_index) {
+                self.show_task = Some((app.id.clone(), action.name.clone()));
+            }
+        }
+        if let Some(index) = app_to_clone {
+            self.bootstrap_app(index);
+        }
+        if let Some(index) = app_to_view_clone {
+            self.show_task = Some((self.state.apps[index].id.clone(), BOOTSTRAP_TASK.to_string()));
+        }
     }
 }
 
@@ -277,8 +1454,25 @@ impl eframe::App for AppHub {
         // Limpar processos mortos
         self.process_manager.cleanup_dead_processes();
         
+        // Reiniciar aplicações com arquivos alterados
+        self.restart_changed_apps();
+        
+        // Recarregar alterações externas no config.json
+        if self.config_watcher.take_changed() {
+            self.reload_external();
+        }
+        
+        // Atualizar aplicações de arquivos de projeto alterados
+        self.refresh_changed_projects();
+        
+        // Desfazer/refazer pelo teclado
+        self.handle_undo_shortcuts(ctx);
+        
         // Configurar repaint
-        let needs_fast_repaint = self.process_manager.has_loading() || self.process_manager.has_running();
+        let needs_fast_repaint = self.process_manager.has_loading()
+            || self.process_manager.has_running()
+            || self.task_runner.has_running()
+            || self.file_watcher.has_pending();
         if needs_fast_repaint {
             ctx.request_repaint_after(Duration::from_millis(250));
         } else {
@@ -300,9 +1494,39 @@ impl eframe::App for AppHub {
                 if header_actions.export_clicked {
                     self.export_config();
                 }
+                if header_actions.export_scripts_clicked {
+                    self.script_export = Some(ScriptExportDialogState::new(&self.state));
+                }
                 if header_actions.import_clicked {
                     self.import_config();
                 }
+                if header_actions.variables_clicked {
+                    self.workspace_dialog = Some(WorkspaceSettingsState::from_settings(&self.state.workspace));
+                }
+                if header_actions.secrets_clicked {
+                    self.secrets_dialog = Some(SecretsDialogState::default());
+                }
+                if header_actions.trash_clicked {
+                    self.show_trash = true;
+                }
+                if header_actions.history_clicked {
+                    self.open_history();
+                }
+                if header_actions.import_compose_clicked {
+                    self.import_compose();
+                }
+                if header_actions.import_procfile_clicked {
+                    self.import_procfile();
+                }
+                if header_actions.import_vscode_clicked {
+                    self.import_vscode();
+                }
+                if header_actions.add_project_clicked {
+                    self.add_project_folder();
+                }
+                if header_actions.scan_clicked {
+                    self.scan_wizard = Some(ScanWizardState::default());
+                }
             });
 
         // Footer
@@ -327,23 +1551,97 @@ impl eframe::App for AppHub {
 
         // Modais
         if self.show_add_modal || self.show_edit_modal {
+            let recent_logs = self.process_manager.logs().recent_lines(&self.modal_state.app.id, 200);
+            let vars = self.modal_variables();
             let result = render_app_modal(
                 ctx,
                 &mut self.modal_state,
                 self.show_edit_modal,
                 &self.available_icons,
                 &mut self.icon_cache,
+                &recent_logs,
+                &vars,
             );
             self.handle_modal_result(result);
         }
 
+        // Janela de logs
+        self.render_logs_window(ctx);
+
+        // Terminal embutido
+        self.render_terminal(ctx);
+
+        // Saída das ações personalizadas
+        self.render_task_window(ctx);
+
+        // Parâmetros de execução
+        self.render_launch_prompt(ctx);
+
+        // Variáveis globais
+        if let Some(dialog) = &mut self.workspace_dialog {
+            match render_workspace_settings(ctx, dialog) {
+                WorkspaceSettingsResult::Save(settings) => {
+                    self.record_change("Alterar variáveis globais");
+                    self.state.workspace = settings;
+                    self.save_state();
+                    self.workspace_dialog = None;
+                }
+                WorkspaceSettingsResult::Cancelled => self.workspace_dialog = None,
+                WorkspaceSettingsResult::None => {}
+            }
+        }
+
+        // Cofre de segredos
+        self.render_secrets_dialog(ctx);
+
+        // Lixeira
+        self.render_trash(ctx);
+
+        // Histórico de versões
+        self.render_history(ctx);
+
+        // Exportação e importação de configurações
+        self.render_export_dialog(ctx);
+        self.render_script_export(ctx);
+        self.render_import_preview(ctx);
+
+        // Revisão de importação
+        self.render_import_review(ctx);
+
+        // Descoberta de projetos
+        self.render_scan_wizard(ctx);
+
+        // Conflitos com alterações externas
+        self.render_merge_conflicts(ctx);
+
+        // Aviso temporário
+        self.render_toast(ctx);
+
+        // Recuperação de configuração inválida
+        self.render_recovery(ctx);
+
         // Diálogo de confirmação de exclusão
         if let Some(index) = self.show_delete_confirm {
             let app_name = self.state.apps.get(index)
-                .map(|a| a.name.clone())
+                .map(|a| match &a.project {
+                    Some(project) => format!(
+                        "projeto {} ({} aplicações)",
+                        project_name(&project.root),
+                        self.state.apps.iter().filter(|app| is_from(app, &project.root)).count()
+                    ),
+                    None => a.name.clone(),
+                })
                 .unwrap_or_default();
             let result = render_delete_confirm(ctx, &app_name, index);
             self.handle_delete_result(result);
         }
     }
 }
+
+/// Nome de exibição de um projeto (última parte da pasta)
+fn project_name(root: &str) -> &str {
+    Path::new(root)
+        .file_name()
+        .and_then(|name| name.to_str())
+        .unwrap_or(root)
+}
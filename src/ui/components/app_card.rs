@@ -5,28 +5,57 @@
 
 use eframe::egui::{self, RichText};
 use crate::core::AppConfig;
-use crate::services::IconCache;
+use crate::services::{AppSignal, IconCache, LogStatus, TaskStatus, WatchStatus, BOOTSTRAP_TASK};
 use crate::ui::theme::{self, ThemeColors, ThemeSpacing};
 
 /// Resultado das interações com o card
+#[derive(Default)]
 pub struct CardActions {
     pub start_clicked: bool,
     pub stop_clicked: bool,
     pub restart_clicked: bool,
     pub edit_clicked: bool,
     pub delete_clicked: bool,
+    pub toggle_watch_clicked: bool,
+    pub logs_clicked: bool,
+    pub terminal_clicked: bool,
+    pub signal: Option<AppSignal>,
+    /// Índice da ação personalizada a executar
+    pub run_action: Option<usize>,
+    /// Índice da ação personalizada cuja saída deve ser exibida
+    pub view_action: Option<usize>,
+    /// Perfil escolhido no menu "Executar"
+    pub start_profile: Option<String>,
+    /// Deslocamento na lista (-1 = para trás, 1 = para frente)
+    pub move_by: Option<isize>,
+    /// Clonar o repositório e executar a configuração inicial
+    pub clone_clicked: bool,
+    /// Ver a saída da clonagem
+    pub view_clone_clicked: bool,
 }
 
-impl Default for CardActions {
-    fn default() -> Self {
-        Self {
-            start_clicked: false,
-            stop_clicked: false,
-            restart_clicked: false,
-            edit_clicked: false,
-            delete_clicked: false,
-        }
-    }
+/// Estado de execução exibido no card
+pub struct CardStatus {
+    /// Se a aplicação está executando
+    pub is_running: bool,
+    /// Se a aplicação está iniciando
+    pub is_loading: bool,
+    /// Estado da observação de arquivos
+    pub watch_status: WatchStatus,
+    /// Estado derivado das regras de log
+    pub log: LogStatus,
+    /// Se há uma sessão de terminal embutido disponível
+    pub has_terminal: bool,
+    /// Se a aplicação foi pausada com SIGSTOP
+    pub is_paused: bool,
+    /// Estado da última execução de cada ação personalizada (mesma ordem de `app.actions`)
+    pub tasks: Vec<Option<TaskStatus>>,
+    /// Perfil da execução atual
+    pub active_profile: Option<String>,
+    /// Se o repositório da aplicação ainda não foi clonado
+    pub not_cloned: bool,
+    /// Estado da última clonagem e configuração
+    pub bootstrap: Option<TaskStatus>,
 }
 
 /// Renderiza um card de aplicação.
@@ -34,8 +63,7 @@ impl Default for CardActions {
 /// # Argumentos
 /// * `ui` - Contexto de UI do egui
 /// * `app` - Configuração da aplicação
-/// * `is_running` - Se a aplicação está executando
-/// * `is_loading` - Se a aplicação está iniciando
+/// * `status` - Estado de execução da aplicação
 /// * `icon_cache` - Cache de ícones para renderização
 /// 
 /// # Retorno
@@ -43,13 +71,14 @@ impl Default for CardActions {
 pub fn render_app_card(
     ui: &mut egui::Ui,
     app: &AppConfig,
-    is_running: bool,
-    is_loading: bool,
+    status: &CardStatus,
     icon_cache: &mut IconCache,
 ) -> CardActions {
     let mut actions = CardActions::default();
+    let is_running = status.is_running;
+    let is_loading = status.is_loading;
 
-    let (bg_color, border_color, glow_color) = theme::get_card_colors(is_running, is_loading);
+    let (bg_color, border_color, glow_color) = theme::get_card_colors(is_running, is_loading, status.is_paused);
     let card_width = ThemeSpacing::CARD_WIDTH;
     let card_height = ThemeSpacing::CARD_HEIGHT;
     
@@ -62,33 +91,39 @@ pub fn render_app_card(
                 ui.set_min_height(card_height);
                 
                 // Header do card
-                render_card_header(ui, app, is_running, is_loading, icon_cache, &mut actions);
+                render_card_header(ui, app, status, icon_cache, &mut actions);
 
                 ui.add_space(12.0);
 
-                // Nome da aplicação
-                ui.label(
-                    RichText::new(&app.name)
-                        .size(17.0)
-                        .strong()
-                        .color(ThemeColors::TEXT_PRIMARY),
-                );
+                // Nome da aplicação (e marcação das definidas em repositório)
+                ui.horizontal(|ui| {
+                    ui.label(
+                        RichText::new(&app.name)
+                            .size(17.0)
+                            .strong()
+                            .color(ThemeColors::TEXT_PRIMARY),
+                    );
+                    if let Some(project) = &app.project {
+                        ui.label(RichText::new("📁 repo").size(10.0).color(ThemeColors::TEXT_MUTED))
+                            .on_hover_text(format!("Definida em {}\nAjustes ficam apenas no seu config.json", project.root));
+                    }
+                });
 
                 ui.add_space(4.0);
 
                 // Status badge
-                render_status_badge(ui, card_width, is_running, is_loading);
+                render_status_badge(ui, card_width, status, &mut actions);
 
                 ui.add_space(8.0);
 
                 // Info do projeto
-                render_project_info(ui, app, card_width);
+                render_project_info(ui, app, card_width, status.watch_status, &mut actions);
 
                 // Preencher espaço restante
                 ui.add_space(ui.available_height() - 46.0);
 
                 // Botões de ação
-                render_action_buttons(ui, card_width, is_running, is_loading, &mut actions);
+                render_action_buttons(ui, app, card_width, status, &mut actions);
             });
         });
 
@@ -96,17 +131,31 @@ pub fn render_app_card(
         ui.ctx().request_repaint();
     }
 
+    // Menu de contexto para reordenar
+    response.response.interact(egui::Sense::click()).context_menu(|ui| {
+        if ui.button("◀ Mover para trás").clicked() {
+            actions.move_by = Some(-1);
+            ui.close_menu();
+        }
+        if ui.button("Mover para frente ▶").clicked() {
+            actions.move_by = Some(1);
+            ui.close_menu();
+        }
+    });
+
     actions
 }
 
 fn render_card_header(
     ui: &mut egui::Ui,
     app: &AppConfig,
-    is_running: bool,
-    is_loading: bool,
+    status: &CardStatus,
     icon_cache: &mut IconCache,
     actions: &mut CardActions,
 ) {
+    let is_running = status.is_running;
+    let is_loading = status.is_loading;
+
     ui.horizontal(|ui| {
         // Ícone da aplicação
         egui::Frame::none()
@@ -156,29 +205,160 @@ fn render_card_header(
                         actions.edit_clicked = true;
                     }
                 }
+                if is_running && app.run_mode.captures_output() {
+                    render_signal_menu(ui, status, actions);
+                }
+                if !app.actions.is_empty() {
+                    render_actions_menu(ui, app, status, actions);
+                }
+                if status.has_terminal {
+                    let terminal_btn = egui::Button::new(
+                        RichText::new("🖥").size(12.0).color(ThemeColors::TEXT_SECONDARY)
+                    )
+                    .fill(egui::Color32::from_rgb(50, 50, 58))
+                    .rounding(6.0)
+                    .min_size(egui::vec2(24.0, 24.0));
+                    if ui.add(terminal_btn).on_hover_text("Abrir terminal").clicked() {
+                        actions.terminal_clicked = true;
+                    }
+                }
+                if app.run_mode.captures_output() || status.log.has_output {
+                    let logs_btn = egui::Button::new(
+                        RichText::new("📜").size(12.0).color(ThemeColors::TEXT_SECONDARY)
+                    )
+                    .fill(egui::Color32::from_rgb(50, 50, 58))
+                    .rounding(6.0)
+                    .min_size(egui::vec2(24.0, 24.0));
+                    if ui.add(logs_btn).on_hover_text("Ver logs").clicked() {
+                        actions.logs_clicked = true;
+                    }
+                }
             });
         });
     });
 }
 
-fn render_status_badge(ui: &mut egui::Ui, card_width: f32, is_running: bool, is_loading: bool) {
+fn render_actions_menu(
+    ui: &mut egui::Ui,
+    app: &AppConfig,
+    status: &CardStatus,
+    actions: &mut CardActions,
+) {
+    let any_running = status.tasks.contains(&Some(TaskStatus::Running));
+    let icon = if any_running { "⏳" } else { "🛠" };
+    
+    ui.menu_button(
+        RichText::new(icon).size(12.0).color(ThemeColors::TEXT_SECONDARY),
+        |ui| {
+            ui.set_min_width(200.0);
+            for (index, action) in app.actions.iter().enumerate() {
+                let task = status.tasks.get(index).copied().flatten();
+                ui.horizontal(|ui| {
+                    let running = task == Some(TaskStatus::Running);
+                    let run_btn = egui::Button::new(format!("▶ {}", action.name));
+                    if ui.add_enabled(!running, run_btn).clicked() {
+                        actions.run_action = Some(index);
+                        ui.close_menu();
+                    }
+                    if let Some(task) = task {
+                        if ui.small_button(task.icon()).on_hover_text("Ver saída").clicked() {
+                            actions.view_action = Some(index);
+                            ui.close_menu();
+                        }
+                    }
+                });
+            }
+        },
+    )
+    .response
+    .on_hover_text("Ações personalizadas");
+}
+
+fn render_signal_menu(ui: &mut egui::Ui, status: &CardStatus, actions: &mut CardActions) {
+    ui.menu_button(
+        RichText::new("⚡").size(12.0).color(ThemeColors::TEXT_SECONDARY),
+        |ui| {
+            ui.set_min_width(180.0);
+            for signal in AppSignal::MENU {
+                if ui.button(signal.label()).clicked() {
+                    actions.signal = Some(signal);
+                    ui.close_menu();
+                }
+            }
+            
+            ui.separator();
+            
+            let pause_signal = if status.is_paused { AppSignal::Cont } else { AppSignal::Stop };
+            if ui.button(pause_signal.label()).clicked() {
+                actions.signal = Some(pause_signal);
+                ui.close_menu();
+            }
+            
+            ui.separator();
+            
+            if ui.button("⌨ Enviar entrada...").clicked() {
+                actions.logs_clicked = true;
+                ui.close_menu();
+            }
+        },
+    )
+    .response
+    .on_hover_text("Sinais e entrada");
+}
+
+fn render_status_badge(
+    ui: &mut egui::Ui,
+    card_width: f32,
+    status: &CardStatus,
+    actions: &mut CardActions,
+) {
     ui.allocate_ui_with_layout(
         egui::vec2(card_width, 18.0),
         egui::Layout::left_to_right(egui::Align::Min),
         |ui| {
-            if is_running {
+            ui.style_mut().spacing.item_spacing = egui::vec2(4.0, 0.0);
+            
+            if status.is_running && status.is_paused {
+                egui::Frame::none()
+                    .fill(ThemeColors::PAUSED_BADGE_BG)
+                    .rounding(4.0)
+                    .inner_margin(egui::Margin::symmetric(8.0, 2.0))
+                    .show(ui, |ui| {
+                        ui.label(
+                            RichText::new("⏸ Pausado")
+                                .size(10.0)
+                                .color(ThemeColors::PAUSED_BORDER),
+                        );
+                    });
+            } else if status.is_running && status.log.ready {
+                egui::Frame::none()
+                    .fill(ThemeColors::READY_BADGE_BG)
+                    .rounding(4.0)
+                    .inner_margin(egui::Margin::symmetric(8.0, 2.0))
+                    .show(ui, |ui| {
+                        ui.label(
+                            RichText::new("✔ Pronto")
+                                .size(10.0)
+                                .color(ThemeColors::READY_TEXT),
+                        );
+                    });
+            } else if status.is_running {
                 egui::Frame::none()
                     .fill(ThemeColors::RUNNING_BADGE_BG)
                     .rounding(4.0)
                     .inner_margin(egui::Margin::symmetric(8.0, 2.0))
                     .show(ui, |ui| {
+                        let text = match &status.active_profile {
+                            Some(profile) => format!("▶ Executando · {}", profile),
+                            None => "▶ Executando".to_string(),
+                        };
                         ui.label(
-                            RichText::new("▶ Executando")
+                            RichText::new(text)
                                 .size(10.0)
                                 .color(ThemeColors::RUNNING_BORDER),
                         );
                     });
-            } else if is_loading {
+            } else if status.is_loading {
                 egui::Frame::none()
                     .fill(egui::Color32::from_rgb(40, 40, 60))
                     .rounding(4.0)
@@ -190,12 +370,61 @@ fn render_status_badge(ui: &mut egui::Ui, card_width: f32, is_running: bool, is_
                                 .color(ThemeColors::LOADING_BORDER),
                         );
                     });
+            } else if status.not_cloned {
+                egui::Frame::none()
+                    .fill(egui::Color32::from_rgb(40, 40, 60))
+                    .rounding(4.0)
+                    .inner_margin(egui::Margin::symmetric(8.0, 2.0))
+                    .show(ui, |ui| {
+                        ui.label(
+                            RichText::new("⬇ Não clonado")
+                                .size(10.0)
+                                .color(ThemeColors::TEXT_SECONDARY),
+                        );
+                    });
+            }
+            
+            // Progresso da clonagem e configuração
+            if let Some(task) = status.bootstrap {
+                if ui
+                    .small_button(RichText::new(task.icon()).size(10.0))
+                    .on_hover_text(format!("{}: {}", BOOTSTRAP_TASK, task.label()))
+                    .clicked()
+                {
+                    actions.view_clone_clicked = true;
+                }
+            }
+            
+            // Badge de erros detectados pelas regras de log
+            if status.log.error_count > 0 {
+                let errors_btn = egui::Button::new(
+                    RichText::new(format!("⚠ {}", status.log.error_count))
+                        .size(10.0)
+                        .color(ThemeColors::BTN_DANGER),
+                )
+                .fill(ThemeColors::BTN_DELETE_BG)
+                .rounding(4.0);
+                if ui.add(errors_btn).on_hover_text("Erros detectados nos logs").clicked() {
+                    actions.logs_clicked = true;
+                }
+            }
+            
+            // Link extraído dos logs
+            if let Some(url) = &status.log.url {
+                ui.hyperlink_to(RichText::new("🔗 Abrir").size(10.0), url)
+                    .on_hover_text(url);
             }
         },
     );
 }
 
-fn render_project_info(ui: &mut egui::Ui, app: &AppConfig, card_width: f32) {
+fn render_project_info(
+    ui: &mut egui::Ui,
+    app: &AppConfig,
+    card_width: f32,
+    watch_status: WatchStatus,
+    actions: &mut CardActions,
+) {
     ui.allocate_ui_with_layout(
         egui::vec2(card_width, 16.0),
         egui::Layout::left_to_right(egui::Align::Min),
@@ -210,23 +439,50 @@ fn render_project_info(ui: &mut egui::Ui, app: &AppConfig, card_width: f32) {
         },
     );
 
-    ui.label(
-        RichText::new(format!("⚡ {} comando(s)", app.commands.len()))
-            .size(11.0)
-            .color(ThemeColors::TEXT_MUTED),
-    );
+    ui.horizontal(|ui| {
+        ui.label(
+            RichText::new(format!("⚡ {} comando(s)", app.commands.len()))
+                .size(11.0)
+                .color(ThemeColors::TEXT_MUTED),
+        );
+
+        render_watch_indicator(ui, watch_status, actions);
+    });
+}
+
+fn render_watch_indicator(ui: &mut egui::Ui, watch_status: WatchStatus, actions: &mut CardActions) {
+    let (text, color, hover) = match watch_status {
+        WatchStatus::Off => return,
+        WatchStatus::Active => (
+            "👁 Observando",
+            ThemeColors::WATCH_ACTIVE,
+            "Reinicia ao alterar arquivos. Clique para pausar",
+        ),
+        WatchStatus::Paused => (
+            "⏸ Pausado",
+            ThemeColors::TEXT_MUTED,
+            "Observação pausada. Clique para retomar",
+        ),
+    };
+
+    let btn = egui::Button::new(RichText::new(text).size(10.0).color(color))
+        .fill(ThemeColors::BG_ICON)
+        .rounding(4.0);
+    if ui.add(btn).on_hover_text(hover).clicked() {
+        actions.toggle_watch_clicked = true;
+    }
 }
 
 fn render_action_buttons(
     ui: &mut egui::Ui,
+    app: &AppConfig,
     card_width: f32,
-    is_running: bool,
-    is_loading: bool,
+    status: &CardStatus,
     actions: &mut CardActions,
 ) {
     let button_width = card_width - 36.0;
     
-    if is_loading {
+    if status.is_loading {
         let button = egui::Button::new(
             RichText::new("⏳ Iniciando...")
                 .size(13.0)
@@ -236,7 +492,7 @@ fn render_action_buttons(
         .rounding(ThemeSpacing::BUTTON_ROUNDING)
         .min_size(egui::vec2(button_width, ThemeSpacing::BUTTON_HEIGHT));
         ui.add_enabled(false, button);
-    } else if is_running {
+    } else if status.is_running {
         ui.horizontal(|ui| {
             let btn_width = (button_width - 8.0) / 2.0;
             
@@ -247,6 +503,16 @@ fn render_action_buttons(
                 actions.stop_clicked = true;
             }
 
+            if status.is_paused {
+                let resume_button = theme::action_button("▶ Retomar", ThemeColors::PAUSED_BORDER)
+                    .min_size(egui::vec2(btn_width, ThemeSpacing::BUTTON_HEIGHT));
+
+                if ui.add(resume_button).clicked() {
+                    actions.signal = Some(AppSignal::Cont);
+                }
+                return;
+            }
+
             let restart_button = theme::action_button("↻ Restart", ThemeColors::BTN_WARNING)
                 .min_size(egui::vec2(btn_width, ThemeSpacing::BUTTON_HEIGHT));
 
@@ -254,6 +520,28 @@ fn render_action_buttons(
                 actions.restart_clicked = true;
             }
         });
+    } else if status.not_cloned || status.bootstrap == Some(TaskStatus::Running) {
+        let cloning = status.bootstrap == Some(TaskStatus::Running);
+        let text = if cloning { "⏳ Clonando..." } else { "⬇  Clonar e configurar" };
+        let button = egui::Button::new(
+            RichText::new(text)
+                .size(13.0)
+                .color(ThemeColors::TEXT_PRIMARY),
+        )
+        .fill(ThemeColors::BTN_PRIMARY)
+        .rounding(ThemeSpacing::BUTTON_ROUNDING)
+        .min_size(egui::vec2(button_width, ThemeSpacing::BUTTON_HEIGHT));
+
+        let hover = app
+            .repository
+            .as_ref()
+            .map(|repo| format!("git clone {}", repo.url))
+            .unwrap_or_default();
+        if ui.add_enabled(!cloning, button).on_hover_text(hover).clicked() {
+            actions.clone_clicked = true;
+        }
+    } else if app.profiles.len() > 1 {
+        render_profile_split_button(ui, app, button_width, actions);
     } else {
         let button = egui::Button::new(
             RichText::new("▶  Executar")
@@ -269,3 +557,53 @@ fn render_action_buttons(
         }
     }
 }
+
+/// Botão "Executar" com menu para escolher o perfil de execução
+fn render_profile_split_button(
+    ui: &mut egui::Ui,
+    app: &AppConfig,
+    button_width: f32,
+    actions: &mut CardActions,
+) {
+    let default_name = app.default_profile().map(|p| p.name.as_str()).unwrap_or_default();
+    
+    ui.horizontal(|ui| {
+        ui.style_mut().spacing.item_spacing = egui::vec2(2.0, 0.0);
+        let menu_width = 30.0;
+        
+        let button = egui::Button::new(
+            RichText::new(format!("▶  Executar · {}", default_name))
+                .size(13.0)
+                .color(ThemeColors::TEXT_PRIMARY),
+        )
+        .fill(ThemeColors::BTN_PRIMARY)
+        .rounding(ThemeSpacing::BUTTON_ROUNDING)
+        .min_size(egui::vec2(button_width - menu_width - 2.0, ThemeSpacing::BUTTON_HEIGHT));
+        
+        if ui.add(button).clicked() {
+            actions.start_clicked = true;
+        }
+        
+        ui.scope(|ui| {
+            ui.style_mut().visuals.widgets.inactive.weak_bg_fill = ThemeColors::BTN_PRIMARY;
+            ui.style_mut().visuals.widgets.hovered.weak_bg_fill = ThemeColors::BTN_PRIMARY;
+            ui.style_mut().spacing.button_padding = egui::vec2(8.0, 8.0);
+            ui.menu_button(RichText::new("⏷").size(13.0).color(ThemeColors::TEXT_PRIMARY), |ui| {
+                ui.set_min_width(160.0);
+                for profile in &app.profiles {
+                    let label = if profile.name == default_name {
+                        format!("▶ {} (padrão)", profile.name)
+                    } else {
+                        format!("▶ {}", profile.name)
+                    };
+                    if ui.button(label).clicked() {
+                        actions.start_profile = Some(profile.name.clone());
+                        ui.close_menu();
+                    }
+                }
+            })
+            .response
+            .on_hover_text("Escolher perfil");
+        });
+    });
+}
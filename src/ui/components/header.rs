@@ -7,20 +7,21 @@ use eframe::egui::{self, RichText};
 use crate::ui::theme::ThemeColors;
 
 /// Resultado das interações com o header
+#[derive(Default)]
 pub struct HeaderActions {
     pub add_app_clicked: bool,
     pub export_clicked: bool,
+    pub export_scripts_clicked: bool,
     pub import_clicked: bool,
-}
-
-impl Default for HeaderActions {
-    fn default() -> Self {
-        Self {
-            add_app_clicked: false,
-            export_clicked: false,
-            import_clicked: false,
-        }
-    }
+    pub variables_clicked: bool,
+    pub secrets_clicked: bool,
+    pub trash_clicked: bool,
+    pub history_clicked: bool,
+    pub add_project_clicked: bool,
+    pub scan_clicked: bool,
+    pub import_compose_clicked: bool,
+    pub import_procfile_clicked: bool,
+    pub import_vscode_clicked: bool,
 }
 
 /// Renderiza o cabeçalho da aplicação.
@@ -83,11 +84,61 @@ pub fn render_header(ui: &mut egui::Ui, search_filter: &mut String) -> HeaderAct
                         ui.close_menu();
                     }
                     
+                    if ui.button("🧾  Exportar como script").clicked() {
+                        actions.export_scripts_clicked = true;
+                        ui.close_menu();
+                    }
+                    
                     if ui.button("📥  Importar configurações").clicked() {
                         actions.import_clicked = true;
                         ui.close_menu();
                     }
                     
+                    ui.menu_button("🧩  Importar de outras ferramentas", |ui| {
+                        if ui.button("🐳  docker-compose").clicked() {
+                            actions.import_compose_clicked = true;
+                            ui.close_menu();
+                        }
+                        if ui.button("📜  Procfile").clicked() {
+                            actions.import_procfile_clicked = true;
+                            ui.close_menu();
+                        }
+                        if ui.button("🆚  VS Code (tasks.json)").clicked() {
+                            actions.import_vscode_clicked = true;
+                            ui.close_menu();
+                        }
+                    });
+                    
+                    if ui.button("📁  Adicionar pasta de projeto").clicked() {
+                        actions.add_project_clicked = true;
+                        ui.close_menu();
+                    }
+                    
+                    if ui.button("🔍  Escanear pasta").clicked() {
+                        actions.scan_clicked = true;
+                        ui.close_menu();
+                    }
+                    
+                    if ui.button("🔣  Variáveis globais").clicked() {
+                        actions.variables_clicked = true;
+                        ui.close_menu();
+                    }
+                    
+                    if ui.button("🔐  Segredos").clicked() {
+                        actions.secrets_clicked = true;
+                        ui.close_menu();
+                    }
+                    
+                    if ui.button("🗑  Lixeira").clicked() {
+                        actions.trash_clicked = true;
+                        ui.close_menu();
+                    }
+                    
+                    if ui.button("🕘  Histórico").clicked() {
+                        actions.history_clicked = true;
+                        ui.close_menu();
+                    }
+                    
                     ui.separator();
                     
                     ui.label(
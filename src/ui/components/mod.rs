@@ -6,8 +6,10 @@ pub mod app_card;
 pub mod header;
 pub mod footer;
 pub mod empty_state;
+pub mod toast;
 
 pub use app_card::*;
 pub use header::*;
 pub use footer::*;
 pub use empty_state::*;
+pub use toast::*;
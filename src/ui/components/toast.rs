@@ -0,0 +1,85 @@
+//! # Toast Component
+//!
+//! Aviso temporário exibido no canto inferior da janela, com um botão
+//! opcional (ex: "Desfazer" após excluir uma aplicação).
+
+use std::time::{Duration, Instant};
+
+use eframe::egui::{self, RichText};
+use crate::ui::theme::ThemeColors;
+
+/// Tempo que o aviso permanece visível
+pub const TOAST_DURATION: Duration = Duration::from_secs(6);
+
+/// Botão oferecido pelo aviso
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ToastButton {
+    /// "↶ Desfazer"
+    Undo,
+    /// "↷ Refazer"
+    Redo,
+}
+
+/// Aviso temporário
+pub struct Toast {
+    /// Mensagem exibida
+    pub message: String,
+    /// Botão oferecido (se houver)
+    pub button: Option<ToastButton>,
+    /// Momento em que o aviso foi criado
+    pub created: Instant,
+}
+
+impl Toast {
+    /// Cria um aviso com um botão opcional
+    pub fn new(message: impl Into<String>, button: Option<ToastButton>) -> Self {
+        Self {
+            message: message.into(),
+            button,
+            created: Instant::now(),
+        }
+    }
+
+    /// Verifica se o aviso já deve desaparecer
+    pub fn is_expired(&self) -> bool {
+        self.created.elapsed() >= TOAST_DURATION
+    }
+}
+
+/// Renderiza o aviso.
+///
+/// # Retorno
+/// O botão clicado, se houver
+pub fn render_toast(ctx: &egui::Context, toast: &Toast) -> Option<ToastButton> {
+    let mut clicked = None;
+
+    egui::Area::new(egui::Id::new("toast"))
+        .anchor(egui::Align2::CENTER_BOTTOM, [0.0, -64.0])
+        .order(egui::Order::Foreground)
+        .show(ctx, |ui| {
+            egui::Frame::none()
+                .fill(egui::Color32::from_rgb(38, 38, 45))
+                .stroke(egui::Stroke::new(1.0, egui::Color32::from_rgb(60, 60, 70)))
+                .rounding(8.0)
+                .inner_margin(egui::Margin::symmetric(14.0, 8.0))
+                .show(ui, |ui| {
+                    ui.horizontal(|ui| {
+                        ui.label(RichText::new(&toast.message).color(ThemeColors::TEXT_PRIMARY));
+                        if let Some(button) = toast.button {
+                            ui.add_space(8.0);
+                            let label = match button {
+                                ToastButton::Undo => "↶ Desfazer",
+                                ToastButton::Redo => "↷ Refazer",
+                            };
+                            if ui.button(RichText::new(label).strong()).clicked() {
+                                clicked = Some(button);
+                            }
+                        }
+                    });
+                });
+        });
+
+    // Repinta para que o aviso desapareça no tempo certo
+    ctx.request_repaint_after(Duration::from_millis(250));
+    clicked
+}
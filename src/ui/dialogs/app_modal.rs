@@ -2,14 +2,22 @@
 //! 
 //! Modal para adicionar/editar aplicações.
 
+use std::path::Path;
+
 use eframe::egui::{self, RichText};
-use crate::core::{AppConfig, IconInfo};
-use crate::services::IconCache;
+use crate::core::{
+    detect_project, AppConfig, CustomAction, EnvVar, IconInfo, Interpolator, LaunchParam, LaunchProfile, LogRule, LogRuleKind,
+    ParamKind, ProjectDetection, Repository, RunMode, WatchConfig,
+};
+use crate::services::{compile_rule, IconCache, LineMatch, RuleSet, WatchMatcher};
 
 /// Estado do modal de aplicação
+#[derive(Default)]
 pub struct AppModalState {
     /// Aplicação sendo editada
     pub app: AppConfig,
+    /// Índice do perfil padrão em `profile_drafts`
+    pub default_profile: usize,
     /// Novo comando sendo digitado
     pub new_command: String,
     /// Se o picker de ícones está aberto
@@ -18,18 +26,173 @@ pub struct AppModalState {
     pub icon_search_filter: String,
     /// Índice da app sendo editada (None = nova app)
     pub edit_index: Option<usize>,
+    /// Globs observados, um por linha
+    pub watch_patterns_text: String,
+    /// Globs ignorados, um por linha
+    pub watch_ignore_text: String,
+    /// Linha digitada para testar as regras de log
+    pub rule_test_input: String,
+    /// Ações personalizadas em edição
+    pub action_drafts: Vec<ActionDraft>,
+    /// Variáveis de ambiente da aplicação, `CHAVE=valor` por linha
+    pub env_text: String,
+    /// Perfis de execução em edição
+    pub profile_drafts: Vec<ProfileDraft>,
+    /// Porta da aplicação (vazio = sem porta)
+    pub port_text: String,
+    /// Parâmetros de execução em edição
+    pub param_drafts: Vec<ParamDraft>,
+    /// Pasta inspecionada por último e o que foi detectado nela
+    pub detection: Option<(String, Option<ProjectDetection>)>,
+    /// URL do repositório git (vazio = sem repositório)
+    pub repo_url: String,
+    /// Branch do repositório
+    pub repo_branch: String,
+    /// Passos de configuração após o clone, um por linha
+    pub setup_text: String,
+}
+
+/// Parâmetro de execução em edição, com as opções como texto
+#[derive(Default, Clone)]
+pub struct ParamDraft {
+    /// Nome usado em `${param.NOME}`
+    pub name: String,
+    /// Texto exibido no diálogo
+    pub label: String,
+    /// Tipo do parâmetro
+    pub kind: ParamKind,
+    /// Valor padrão
+    pub default: String,
+    /// Opções separadas por vírgula (para listas)
+    pub choices_text: String,
+}
+
+impl ParamDraft {
+    /// Cria o rascunho a partir de um parâmetro salvo
+    fn from_param(param: &LaunchParam) -> Self {
+        Self {
+            name: param.name.clone(),
+            label: param.label.clone(),
+            kind: param.kind,
+            default: param.default.clone(),
+            choices_text: param.choices.join(", "),
+        }
+    }
+    
+    /// Converte o rascunho em um parâmetro, validando nome e opções
+    fn to_param(&self) -> Result<LaunchParam, String> {
+        let name = self.name.trim();
+        if name.is_empty() || !name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-') {
+            return Err(format!("Nome de parâmetro inválido: \"{}\" (use letras, números, _ e -)", name));
+        }
+        let choices: Vec<String> = self.choices_text
+            .split(',')
+            .map(|choice| choice.trim().to_string())
+            .filter(|choice| !choice.is_empty())
+            .collect();
+        let default = self.default.trim().to_string();
+        
+        match self.kind {
+            ParamKind::Choice if choices.is_empty() => {
+                return Err(format!("Parâmetro \"{}\": informe as opções da lista", name));
+            }
+            ParamKind::Choice if !default.is_empty() && !choices.contains(&default) => {
+                return Err(format!("Parâmetro \"{}\": o padrão deve ser uma das opções", name));
+            }
+            _ => {}
+        }
+        
+        Ok(LaunchParam {
+            name: name.to_string(),
+            label: self.label.trim().to_string(),
+            kind: self.kind,
+            default: match self.kind {
+                ParamKind::Boolean if default != "true" => "false".to_string(),
+                ParamKind::Choice if default.is_empty() => choices[0].clone(),
+                _ => default,
+            },
+            choices: if self.kind == ParamKind::Choice { choices } else { Vec::new() },
+        })
+    }
+}
+
+/// Perfil de execução em edição, com comandos e variáveis como texto
+#[derive(Default, Clone)]
+pub struct ProfileDraft {
+    /// Nome do perfil
+    pub name: String,
+    /// Comandos, um por linha (vazio = comandos da aplicação)
+    pub commands_text: String,
+    /// Variáveis que sobrescrevem as da aplicação
+    pub env_text: String,
 }
 
-impl Default for AppModalState {
-    fn default() -> Self {
+impl ProfileDraft {
+    /// Cria o rascunho a partir de um perfil salvo
+    fn from_profile(profile: &LaunchProfile) -> Self {
         Self {
-            app: AppConfig::default(),
-            new_command: String::new(),
-            show_icon_picker: false,
-            icon_search_filter: String::new(),
-            edit_index: None,
+            name: profile.name.clone(),
+            commands_text: profile.commands.join("\n"),
+            env_text: EnvVar::format_lines(&profile.env),
         }
     }
+    
+    /// Converte o rascunho em um perfil, validando as variáveis de ambiente
+    fn to_profile(&self, is_default: bool) -> Result<LaunchProfile, String> {
+        let name = self.name.trim();
+        if name.is_empty() {
+            return Err("Todo perfil precisa de um nome".to_string());
+        }
+        let env = EnvVar::parse_lines(&self.env_text)
+            .map_err(|e| format!("Perfil \"{}\": {}", name, e))?;
+        Ok(LaunchProfile {
+            name: name.to_string(),
+            commands: split_lines(&self.commands_text),
+            env,
+            is_default,
+        })
+    }
+}
+
+/// Ação personalizada em edição, com comandos e variáveis como texto
+#[derive(Default, Clone)]
+pub struct ActionDraft {
+    /// Nome da ação
+    pub name: String,
+    /// Pasta de execução (vazio = pasta da aplicação)
+    pub working_dir: String,
+    /// Comandos, um por linha
+    pub commands_text: String,
+    /// Variáveis de ambiente, `CHAVE=valor` por linha
+    pub env_text: String,
+}
+
+impl ActionDraft {
+    /// Cria o rascunho a partir de uma ação salva
+    fn from_action(action: &CustomAction) -> Self {
+        Self {
+            name: action.name.clone(),
+            working_dir: action.working_dir.clone(),
+            commands_text: action.commands.join("\n"),
+            env_text: EnvVar::format_lines(&action.env),
+        }
+    }
+    
+    /// Converte o rascunho em uma ação, validando as variáveis de ambiente
+    fn to_action(&self) -> Result<CustomAction, String> {
+        let name = self.name.trim();
+        if name.is_empty() {
+            return Err("Toda ação precisa de um nome".to_string());
+        }
+        let env = EnvVar::parse_lines(&self.env_text)
+            .map_err(|e| format!("Ação \"{}\": {}", name, e))?;
+        Ok(CustomAction {
+            name: name.to_string(),
+            commands: split_lines(&self.commands_text),
+            working_dir: self.working_dir.trim().to_string(),
+            env,
+        })
+    }
 }
 
 impl AppModalState {
@@ -47,16 +210,152 @@ impl AppModalState {
     /// Cria um estado para editar uma aplicação existente
     pub fn edit_app(app: AppConfig, index: usize) -> Self {
         Self {
+            watch_patterns_text: app.watch.patterns.join("\n"),
+            watch_ignore_text: app.watch.ignore.join("\n"),
+            action_drafts: app.actions.iter().map(ActionDraft::from_action).collect(),
+            env_text: EnvVar::format_lines(&app.env),
+            port_text: app.port.map(|port| port.to_string()).unwrap_or_default(),
+            param_drafts: app.params.iter().map(ParamDraft::from_param).collect(),
+            profile_drafts: app.profiles.iter().map(ProfileDraft::from_profile).collect(),
+            default_profile: app.profiles.iter().position(|p| p.is_default).unwrap_or(0),
+            repo_url: app.repository.as_ref().map(|repo| repo.url.clone()).unwrap_or_default(),
+            repo_branch: app.repository.as_ref().map(|repo| repo.branch.clone()).unwrap_or_default(),
+            setup_text: app.setup.join("\n"),
             app,
             edit_index: Some(index),
             ..Default::default()
         }
     }
     
+    /// Retorna a configuração de observação montada a partir dos campos de texto
+    fn watch_config(&self) -> WatchConfig {
+        WatchConfig {
+            patterns: split_lines(&self.watch_patterns_text),
+            ignore: split_lines(&self.watch_ignore_text),
+            ..self.app.watch.clone()
+        }
+    }
+    
+    /// Converte as ações em edição, falhando na primeira inválida ou com nome repetido
+    fn custom_actions(&self) -> Result<Vec<CustomAction>, String> {
+        let mut actions: Vec<CustomAction> = Vec::new();
+        for draft in &self.action_drafts {
+            let action = draft.to_action()?;
+            if actions.iter().any(|a| a.name == action.name) {
+                return Err(format!("Ação \"{}\" repetida", action.name));
+            }
+            actions.push(action);
+        }
+        Ok(actions)
+    }
+    
+    /// Converte os perfis em edição, falhando no primeiro inválido ou com nome repetido
+    fn launch_profiles(&self) -> Result<Vec<LaunchProfile>, String> {
+        let mut profiles: Vec<LaunchProfile> = Vec::new();
+        for (i, draft) in self.profile_drafts.iter().enumerate() {
+            let profile = draft.to_profile(i == self.default_profile)?;
+            if profiles.iter().any(|p| p.name == profile.name) {
+                return Err(format!("Perfil \"{}\" repetido", profile.name));
+            }
+            profiles.push(profile);
+        }
+        Ok(profiles)
+    }
+    
+    /// Converte os parâmetros em edição, falhando no primeiro inválido ou com nome repetido
+    fn launch_params(&self) -> Result<Vec<LaunchParam>, String> {
+        let mut params: Vec<LaunchParam> = Vec::new();
+        for draft in &self.param_drafts {
+            let param = draft.to_param()?;
+            if params.iter().any(|p| p.name == param.name) {
+                return Err(format!("Parâmetro \"{}\" repetido", param.name));
+            }
+            params.push(param);
+        }
+        Ok(params)
+    }
+    
+    /// Monta a aplicação a partir dos campos do modal.
+    /// 
+    /// # Retorno
+    /// A aplicação pronta para salvar, ou o primeiro erro de validação.
+    fn build_app(&self) -> Result<AppConfig, String> {
+        if self.app.name.trim().is_empty() {
+            return Err("Nome é obrigatório".to_string());
+        }
+        
+        let watch = self.watch_config();
+        if watch.enabled {
+            WatchMatcher::new(&watch.patterns, &watch.ignore)?;
+        }
+        RuleSet::compile(&self.app.log_rules)?;
+        
+        let port = match self.port_text.trim() {
+            "" => None,
+            text => Some(
+                text.parse::<u16>()
+                    .map_err(|_| format!("Porta inválida: \"{}\"", text))?,
+            ),
+        };
+        
+        let mut app = self.app.clone();
+        app.name = app.name.trim().to_string();
+        app.working_dir = app.working_dir.trim().to_string();
+        app.watch = watch;
+        app.log_rules.retain(|rule| !rule.pattern.trim().is_empty());
+        app.actions = self.custom_actions()?;
+        app.env = EnvVar::parse_lines(&self.env_text)?;
+        app.profiles = self.launch_profiles()?;
+        app.port = port;
+        app.params = self.launch_params()?;
+        app.repository = (!self.repo_url.trim().is_empty()).then(|| Repository {
+            url: self.repo_url.trim().to_string(),
+            branch: self.repo_branch.trim().to_string(),
+        });
+        app.setup = split_lines(&self.setup_text);
+        if app.repository.is_some() && app.working_dir.is_empty() {
+            return Err("Informe a pasta onde o repositório será clonado".to_string());
+        }
+        // Descarta valores lembrados de parâmetros removidos ou que não são mais válidos
+        let params = app.params.clone();
+        app.param_values.retain(|name, value| {
+            params.iter().any(|p| {
+                &p.name == name && (p.kind != ParamKind::Choice || p.choices.contains(value))
+            })
+        });
+        Ok(app)
+    }
+    
     /// Reseta o estado
     pub fn reset(&mut self) {
         *self = Self::default();
     }
+
+    /// Inspeciona a pasta inicial, se ela mudou desde a última inspeção
+    pub fn refresh_detection(&mut self) -> Option<&ProjectDetection> {
+        let dir = self.app.working_dir.trim();
+        if self.detection.as_ref().is_none_or(|(inspected, _)| inspected != dir) {
+            self.detection = Some((dir.to_string(), detect_project(Path::new(dir))));
+        }
+        self.detection.as_ref().and_then(|(_, detection)| detection.as_ref())
+    }
+
+    /// Preenche nome, ícone e comandos com o que foi detectado na pasta,
+    /// sem substituir o que o usuário já informou
+    pub fn apply_detection(&mut self) {
+        let Some(detection) = self.refresh_detection().cloned() else {
+            return;
+        };
+        if self.app.name.trim().is_empty() {
+            self.app.name = detection.name;
+        }
+        if self.app.icon_emoji.is_empty() {
+            self.app.icon_emoji = detection.icon;
+        }
+        if self.app.commands.is_empty() {
+            self.app.commands = detection.commands;
+        }
+    }
 }
 
 /// Resultado das ações do modal
@@ -64,7 +363,7 @@ pub enum AppModalResult {
     /// Modal fechado, nenhuma ação
     None,
     /// Salvar aplicação
-    Save(AppConfig, Option<usize>),
+    Save(Box<AppConfig>, Option<usize>),
     /// Cancelado
     Cancelled,
 }
@@ -77,6 +376,8 @@ pub enum AppModalResult {
 /// * `is_editing` - Se está editando ou adicionando
 /// * `available_icons` - Lista de ícones disponíveis
 /// * `icon_cache` - Cache de ícones
+/// * `recent_logs` - Últimas linhas capturadas da aplicação (para testar regras)
+/// * `vars` - Variáveis disponíveis, para validar os marcadores `${...}`
 /// 
 /// # Retorno
 /// `AppModalResult` indicando a ação tomada
@@ -86,6 +387,8 @@ pub fn render_app_modal(
     is_editing: bool,
     available_icons: &[IconInfo],
     icon_cache: &mut IconCache,
+    recent_logs: &[String],
+    vars: &Interpolator,
 ) -> AppModalResult {
     let mut result = AppModalResult::None;
     
@@ -103,6 +406,18 @@ pub fn render_app_modal(
         .show(ctx, |ui| {
             ui.add_space(10.0);
 
+            if let Some(project) = &state.app.project {
+                ui.label(
+                    RichText::new(format!(
+                        "📁 Definida no arquivo de projeto de {}. As alterações ficam apenas no seu config.json.",
+                        project.root
+                    ))
+                    .size(11.0)
+                    .color(egui::Color32::GRAY),
+                );
+                ui.add_space(8.0);
+            }
+
             // Nome e Ícone
             render_name_and_icon(ui, state, icon_cache);
             
@@ -126,12 +441,42 @@ pub fn render_app_modal(
             // Comandos sugeridos
             render_suggested_commands(ui, state);
 
+            ui.add_space(8.0);
+
+            // Perfis de execução e variáveis de ambiente
+            render_profiles(ui, state);
+
+            ui.add_space(8.0);
+
+            // Parâmetros perguntados ao executar
+            render_launch_params(ui, state);
+
+            ui.add_space(8.0);
+
+            // Reinício automático por alteração de arquivos
+            render_watch_config(ui, state);
+
+            ui.add_space(8.0);
+
+            // Modo de execução e regras de log
+            render_log_rules(ui, state, recent_logs);
+
+            ui.add_space(8.0);
+
+            // Ações personalizadas
+            render_custom_actions(ui, state);
+
+            ui.add_space(8.0);
+
+            // Repositório e configuração inicial
+            render_repository(ui, state);
+
             ui.add_space(20.0);
             ui.separator();
             ui.add_space(10.0);
 
             // Botões de ação
-            result = render_modal_actions(ui, state, is_editing);
+            result = render_modal_actions(ui, state, is_editing, vars);
         });
 
     result
@@ -189,6 +534,17 @@ fn render_name_and_icon(ui: &mut egui::Ui, state: &mut AppModalState, icon_cache
                     .desired_width(300.0)
                     .hint_text("Minha Aplicação"),
             );
+            ui.add_space(4.0);
+            ui.label("Grupo (opcional):");
+            let mut group = state.app.group.clone().unwrap_or_default();
+            let response = ui.add(
+                egui::TextEdit::singleline(&mut group)
+                    .desired_width(300.0)
+                    .hint_text("Ex: backend"),
+            );
+            if response.changed() {
+                state.app.group = (!group.trim().is_empty()).then_some(group);
+            }
         });
     });
 }
@@ -276,9 +632,39 @@ fn render_working_dir(ui: &mut egui::Ui, state: &mut AppModalState) {
         if ui.button("📁 Selecionar").clicked() {
             if let Some(path) = rfd::FileDialog::new().pick_folder() {
                 state.app.working_dir = path.display().to_string();
+                state.apply_detection();
             }
         }
     });
+    if let Some((_, Some(detection))) = &state.detection {
+        ui.label(
+            RichText::new(format!("🔎 Detectado: {}", detection.stacks.join(", ")))
+                .size(11.0)
+                .color(egui::Color32::GRAY),
+        );
+    }
+    ui.horizontal(|ui| {
+        ui.label("Porta:");
+        ui.add(
+            egui::TextEdit::singleline(&mut state.port_text)
+                .desired_width(70.0)
+                .hint_text("3000"),
+        );
+        ui.label(
+            RichText::new("Use ${workspace.root}, ${app.port}, ${HOME}, ${env:VAR} e variáveis globais")
+                .size(11.0)
+                .color(egui::Color32::GRAY),
+        );
+    });
+    ui.horizontal(|ui| {
+        ui.label("Health-check:");
+        ui.add(
+            egui::TextEdit::singleline(&mut state.app.health_check_url)
+                .desired_width(300.0)
+                .hint_text("http://localhost:${app.port}/health"),
+        )
+        .on_hover_text("Consultada após iniciar: a aplicação fica pronta quando a URL responde com sucesso");
+    });
 }
 
 fn render_commands_list(ui: &mut egui::Ui, state: &mut AppModalState) {
@@ -354,33 +740,508 @@ fn render_commands_list(ui: &mut egui::Ui, state: &mut AppModalState) {
 }
 
 fn render_suggested_commands(ui: &mut egui::Ui, state: &mut AppModalState) {
-    ui.collapsing("💡 Comandos Comuns", |ui| {
+    let suggestions = state
+        .refresh_detection()
+        .map(|detection| detection.suggestions.clone())
+        .unwrap_or_default();
+    ui.collapsing("💡 Comandos Sugeridos", |ui| {
+        if suggestions.is_empty() {
+            ui.label(
+                RichText::new("Escolha a pasta inicial para ver os comandos do projeto (scripts, alvos do Makefile...)")
+                    .size(11.0)
+                    .color(egui::Color32::GRAY),
+            );
+            return;
+        }
         ui.horizontal_wrapped(|ui| {
-            let suggestions = [
-                "npm install",
-                "npm run dev",
-                "npm start",
-                "yarn dev",
-                "pnpm dev",
-                "cargo run",
-                "python main.py",
-                "dotnet run",
-                "go run .",
-                "docker-compose up",
-            ];
             for suggestion in suggestions {
-                if ui.small_button(suggestion).clicked() {
-                    state.app.commands.push(suggestion.to_string());
+                let added = state.app.commands.contains(&suggestion);
+                if ui.add_enabled(!added, egui::Button::new(&suggestion).small()).clicked() {
+                    state.app.commands.push(suggestion);
+                }
+            }
+        });
+    });
+}
+
+fn render_watch_config(ui: &mut egui::Ui, state: &mut AppModalState) {
+    ui.collapsing("🔄 Reinício Automático", |ui| {
+        let was_enabled = state.app.watch.enabled;
+        ui.checkbox(
+            &mut state.app.watch.enabled,
+            "Reiniciar quando arquivos forem alterados",
+        );
+        
+        // Sugere ignorar pastas comuns ao habilitar pela primeira vez
+        if state.app.watch.enabled && !was_enabled && state.watch_ignore_text.trim().is_empty() {
+            state.watch_ignore_text = WatchConfig::DEFAULT_IGNORE.join("\n");
+        }
+        
+        ui.add_enabled_ui(state.app.watch.enabled, |ui| {
+            ui.label(
+                RichText::new("Globs relativos à pasta inicial, um por linha")
+                    .size(11.0)
+                    .color(egui::Color32::GRAY),
+            );
+            
+            ui.horizontal(|ui| {
+                ui.vertical(|ui| {
+                    ui.label("Observar:");
+                    ui.add(
+                        egui::TextEdit::multiline(&mut state.watch_patterns_text)
+                            .desired_width(220.0)
+                            .desired_rows(3)
+                            .hint_text("**/*.py")
+                            .font(egui::TextStyle::Monospace),
+                    );
+                });
+                ui.vertical(|ui| {
+                    ui.label("Ignorar:");
+                    ui.add(
+                        egui::TextEdit::multiline(&mut state.watch_ignore_text)
+                            .desired_width(220.0)
+                            .desired_rows(3)
+                            .hint_text("**/node_modules/**")
+                            .font(egui::TextStyle::Monospace),
+                    );
+                });
+            });
+            
+            ui.horizontal(|ui| {
+                ui.label("Aguardar após alteração:");
+                ui.add(
+                    egui::DragValue::new(&mut state.app.watch.debounce_ms)
+                        .range(50..=10_000)
+                        .suffix(" ms"),
+                );
+            });
+        });
+    });
+}
+
+fn render_repository(ui: &mut egui::Ui, state: &mut AppModalState) {
+    ui.collapsing("⬇ Repositório e Configuração Inicial", |ui| {
+        ui.label(
+            RichText::new("Se a pasta inicial não existir, o card oferece clonar o repositório nela e executar os passos abaixo")
+                .size(11.0)
+                .color(egui::Color32::GRAY),
+        );
+        ui.horizontal(|ui| {
+            ui.label("URL:");
+            ui.add(
+                egui::TextEdit::singleline(&mut state.repo_url)
+                    .desired_width(260.0)
+                    .hint_text("git@github.com:empresa/gateway.git"),
+            );
+            ui.label("Branch:");
+            ui.add(
+                egui::TextEdit::singleline(&mut state.repo_branch)
+                    .desired_width(80.0)
+                    .hint_text("padrão"),
+            );
+        });
+        ui.label("Passos de configuração (um por linha, na pasta clonada):");
+        ui.add(
+            egui::TextEdit::multiline(&mut state.setup_text)
+                .desired_width(440.0)
+                .desired_rows(3)
+                .hint_text("npm ci\ncp .env.example .env")
+                .font(egui::TextStyle::Monospace),
+        );
+    });
+}
+
+fn render_log_rules(ui: &mut egui::Ui, state: &mut AppModalState, recent_logs: &[String]) {
+    let ignored_rules = !state.app.log_rules.is_empty() && !state.app.run_mode.captures_output();
+    egui::CollapsingHeader::new("📜 Execução e Regras de Log")
+        .default_open(ignored_rules)
+        .show(ui, |ui| {
+            ui.horizontal(|ui| {
+                ui.label("Modo:");
+                for mode in [RunMode::External, RunMode::Headless, RunMode::Terminal] {
+                    ui.radio_value(&mut state.app.run_mode, mode, mode.label());
+                }
+            });
+        
+            if !state.app.run_mode.captures_output() {
+                ui.horizontal_wrapped(|ui| {
+                    let text = if state.app.log_rules.is_empty() {
+                        "As regras só são avaliadas nos modos com captura de saída".to_string()
+                    } else {
+                        format!(
+                            "⚠ {} regra(s) não serão avaliadas no modo {}",
+                            state.app.log_rules.len(),
+                            state.app.run_mode.label()
+                        )
+                    };
+                    ui.label(
+                        RichText::new(text)
+                            .size(11.0)
+                            .color(egui::Color32::from_rgb(255, 200, 100)),
+                    );
+                    if !state.app.log_rules.is_empty() && ui.small_button("Usar segundo plano").clicked() {
+                        state.app.run_mode = RunMode::Headless;
+                    }
+                });
+            }
+        
+            ui.add_space(5.0);
+        
+            let mut to_remove: Option<usize> = None;
+            for (i, rule) in state.app.log_rules.iter_mut().enumerate() {
+                ui.horizontal(|ui| {
+                    egui::ComboBox::from_id_source(("log_rule_kind", i))
+                        .width(90.0)
+                        .selected_text(rule.kind.label())
+                        .show_ui(ui, |ui| {
+                            for kind in LogRuleKind::ALL {
+                                ui.selectable_value(&mut rule.kind, kind, kind.label());
+                            }
+                        });
+                
+                    ui.add(
+                        egui::TextEdit::singleline(&mut rule.pattern)
+                            .desired_width(260.0)
+                            .hint_text(r"Local:\s+http://")
+                            .font(egui::TextStyle::Monospace),
+                    );
+                
+                    match compile_rule(rule) {
+                        Ok(_) => ui.label(RichText::new("✔").color(egui::Color32::from_rgb(80, 220, 120))),
+                        Err(e) => ui.label(RichText::new("✖").color(egui::Color32::from_rgb(255, 100, 100)))
+                            .on_hover_text(e),
+                    };
+                
+                    if ui.button("❌").on_hover_text("Remover regra").clicked() {
+                        to_remove = Some(i);
+                    }
+                });
+            }
+        
+            if let Some(i) = to_remove {
+                state.app.log_rules.remove(i);
+            }
+        
+            if ui.button("➕ Adicionar regra").clicked() {
+                state.app.log_rules.push(LogRule {
+                    kind: LogRuleKind::Ready,
+                    pattern: String::new(),
+                });
+            }
+        
+            // Teste ao vivo das regras
+            let Ok(rules) = RuleSet::compile(&state.app.log_rules) else {
+                return;
+            };
+            if state.app.log_rules.is_empty() {
+                return;
+            }
+        
+            ui.add_space(8.0);
+            ui.label("Testar regras:");
+            ui.add(
+                egui::TextEdit::singleline(&mut state.rule_test_input)
+                    .desired_width(440.0)
+                    .hint_text("Cole uma linha de log aqui")
+                    .font(egui::TextStyle::Monospace),
+            );
+            if !state.rule_test_input.is_empty() {
+                ui.label(
+                    RichText::new(describe_match(&rules.evaluate(&state.rule_test_input)))
+                        .size(11.0)
+                        .color(egui::Color32::GRAY),
+                );
+            }
+        
+            let matches: Vec<(&String, String)> = recent_logs
+                .iter()
+                .filter_map(|line| {
+                    let matched = rules.evaluate(line);
+                    matched.any().then(|| (line, describe_match(&matched)))
+                })
+                .collect();
+        
+            ui.label(
+                RichText::new(format!(
+                    "{} de {} linha(s) recentes casam com as regras",
+                    matches.len(),
+                    recent_logs.len()
+                ))
+                .size(11.0)
+                .color(egui::Color32::GRAY),
+            );
+        
+            egui::ScrollArea::vertical()
+                .id_source("log_rules_matches")
+                .max_height(100.0)
+                .show(ui, |ui| {
+                    for (line, description) in matches.iter().rev().take(20) {
+                        ui.label(RichText::new(*line).monospace().size(11.0))
+                            .on_hover_text(description);
+                    }
+                });
+        });
+}
+
+fn render_profiles(ui: &mut egui::Ui, state: &mut AppModalState) {
+    ui.collapsing("🎛 Perfis e Variáveis de Ambiente", |ui| {
+        ui.label("Variáveis de todas as execuções (CHAVE=valor):");
+        ui.add(
+            egui::TextEdit::multiline(&mut state.env_text)
+                .desired_width(460.0)
+                .desired_rows(2)
+                .hint_text("PORT=3000")
+                .font(egui::TextStyle::Monospace),
+        );
+        
+        ui.add_space(6.0);
+        ui.label(
+            RichText::new("Perfis sem comandos usam os comandos acima, alterando só as variáveis")
+                .size(11.0)
+                .color(egui::Color32::GRAY),
+        );
+        
+        let mut to_remove: Option<usize> = None;
+        for (i, draft) in state.profile_drafts.iter_mut().enumerate() {
+            ui.add_space(6.0);
+            egui::Frame::group(ui.style()).show(ui, |ui| {
+                ui.horizontal(|ui| {
+                    ui.label("Nome:");
+                    ui.add(
+                        egui::TextEdit::singleline(&mut draft.name)
+                            .desired_width(160.0)
+                            .hint_text("dev"),
+                    );
+                    ui.radio_value(&mut state.default_profile, i, "Padrão");
+                    if ui.button("❌").on_hover_text("Remover perfil").clicked() {
+                        to_remove = Some(i);
+                    }
+                });
+                ui.horizontal(|ui| {
+                    ui.vertical(|ui| {
+                        ui.label("Comandos (um por linha):");
+                        ui.add(
+                            egui::TextEdit::multiline(&mut draft.commands_text)
+                                .desired_width(220.0)
+                                .desired_rows(3)
+                                .hint_text("npm run build\nnpm start")
+                                .font(egui::TextStyle::Monospace),
+                        );
+                    });
+                    ui.vertical(|ui| {
+                        ui.label("Variáveis (CHAVE=valor):");
+                        ui.add(
+                            egui::TextEdit::multiline(&mut draft.env_text)
+                                .desired_width(220.0)
+                                .desired_rows(3)
+                                .hint_text("NODE_OPTIONS=--inspect")
+                                .font(egui::TextStyle::Monospace),
+                        );
+                    });
+                });
+            });
+        }
+        if let Some(i) = to_remove {
+            state.profile_drafts.remove(i);
+            if state.default_profile >= i && state.default_profile > 0 {
+                state.default_profile -= 1;
+            }
+        }
+        
+        ui.add_space(6.0);
+        ui.horizontal_wrapped(|ui| {
+            if ui.button("➕ Novo perfil").clicked() {
+                state.profile_drafts.push(ProfileDraft::default());
+            }
+            for name in ["dev", "prod", "debug"] {
+                if ui.small_button(name).clicked() {
+                    state.profile_drafts.push(ProfileDraft {
+                        name: name.to_string(),
+                        ..Default::default()
+                    });
+                }
+            }
+        });
+    });
+}
+
+fn render_launch_params(ui: &mut egui::Ui, state: &mut AppModalState) {
+    ui.collapsing("❓ Parâmetros de Execução", |ui| {
+        ui.label(
+            RichText::new("Perguntados antes de cada execução e usados como ${param.NOME}")
+                .size(11.0)
+                .color(egui::Color32::GRAY),
+        );
+        
+        let mut to_remove: Option<usize> = None;
+        for (i, draft) in state.param_drafts.iter_mut().enumerate() {
+            ui.add_space(6.0);
+            egui::Frame::group(ui.style()).show(ui, |ui| {
+                ui.horizontal(|ui| {
+                    ui.label("Nome:");
+                    ui.add(
+                        egui::TextEdit::singleline(&mut draft.name)
+                            .desired_width(110.0)
+                            .hint_text("tenant")
+                            .font(egui::TextStyle::Monospace),
+                    );
+                    ui.label("Rótulo:");
+                    ui.add(
+                        egui::TextEdit::singleline(&mut draft.label)
+                            .desired_width(130.0)
+                            .hint_text("Tenant a popular"),
+                    );
+                    if ui.button("❌").on_hover_text("Remover parâmetro").clicked() {
+                        to_remove = Some(i);
+                    }
+                });
+                ui.horizontal(|ui| {
+                    egui::ComboBox::from_id_source(("param_kind", i))
+                        .selected_text(draft.kind.label())
+                        .width(90.0)
+                        .show_ui(ui, |ui| {
+                            for kind in ParamKind::ALL {
+                                ui.selectable_value(&mut draft.kind, kind, kind.label());
+                            }
+                        });
+                    ui.label("Padrão:");
+                    ui.add(
+                        egui::TextEdit::singleline(&mut draft.default)
+                            .desired_width(90.0)
+                            .hint_text(if draft.kind == ParamKind::Boolean { "false" } else { "" }),
+                    );
+                    if draft.kind == ParamKind::Choice {
+                        ui.label("Opções:");
+                        ui.add(
+                            egui::TextEdit::singleline(&mut draft.choices_text)
+                                .desired_width(140.0)
+                                .hint_text("acme, globex"),
+                        );
+                    }
+                });
+            });
+        }
+        if let Some(i) = to_remove {
+            state.param_drafts.remove(i);
+        }
+        
+        ui.add_space(6.0);
+        if ui.button("➕ Novo parâmetro").clicked() {
+            state.param_drafts.push(ParamDraft::default());
+        }
+    });
+}
+
+fn render_custom_actions(ui: &mut egui::Ui, state: &mut AppModalState) {
+    ui.collapsing("🛠 Ações Personalizadas", |ui| {
+        ui.label(
+            RichText::new("Comandos avulsos (testes, lint, migrações) executados pelo menu do card")
+                .size(11.0)
+                .color(egui::Color32::GRAY),
+        );
+        
+        let mut to_remove: Option<usize> = None;
+        for (i, draft) in state.action_drafts.iter_mut().enumerate() {
+            ui.add_space(6.0);
+            egui::Frame::group(ui.style()).show(ui, |ui| {
+                ui.horizontal(|ui| {
+                    ui.label("Nome:");
+                    ui.add(
+                        egui::TextEdit::singleline(&mut draft.name)
+                            .desired_width(200.0)
+                            .hint_text("Testes"),
+                    );
+                    if ui.button("❌").on_hover_text("Remover ação").clicked() {
+                        to_remove = Some(i);
+                    }
+                });
+                ui.horizontal(|ui| {
+                    ui.label("Pasta:");
+                    ui.add(
+                        egui::TextEdit::singleline(&mut draft.working_dir)
+                            .desired_width(340.0)
+                            .hint_text("Pasta da aplicação"),
+                    );
+                });
+                ui.horizontal(|ui| {
+                    ui.vertical(|ui| {
+                        ui.label("Comandos (um por linha):");
+                        ui.add(
+                            egui::TextEdit::multiline(&mut draft.commands_text)
+                                .desired_width(220.0)
+                                .desired_rows(3)
+                                .hint_text("npm test")
+                                .font(egui::TextStyle::Monospace),
+                        );
+                    });
+                    ui.vertical(|ui| {
+                        ui.label("Variáveis (CHAVE=valor):");
+                        ui.add(
+                            egui::TextEdit::multiline(&mut draft.env_text)
+                                .desired_width(220.0)
+                                .desired_rows(3)
+                                .hint_text("NODE_ENV=test")
+                                .font(egui::TextStyle::Monospace),
+                        );
+                    });
+                });
+            });
+        }
+        if let Some(i) = to_remove {
+            state.action_drafts.remove(i);
+        }
+        
+        ui.add_space(6.0);
+        ui.horizontal_wrapped(|ui| {
+            if ui.button("➕ Nova ação").clicked() {
+                state.action_drafts.push(ActionDraft::default());
+            }
+            for (name, command) in [("Testes", "npm test"), ("Lint", "npm run lint"), ("Limpar", "git clean -fdX")] {
+                if ui.small_button(name).on_hover_text(command).clicked() {
+                    state.action_drafts.push(ActionDraft {
+                        name: name.to_string(),
+                        commands_text: command.to_string(),
+                        ..Default::default()
+                    });
                 }
             }
         });
     });
 }
 
+/// Descreve quais regras casaram com uma linha
+fn describe_match(matched: &LineMatch) -> String {
+    let mut parts = Vec::new();
+    if matched.ready {
+        parts.push("✔ pronto".to_string());
+    }
+    if matched.error {
+        parts.push("⚠ erro".to_string());
+    }
+    if let Some(url) = &matched.url {
+        parts.push(format!("🔗 {}", url));
+    }
+    if parts.is_empty() {
+        "Nenhuma regra casou".to_string()
+    } else {
+        parts.join("  ")
+    }
+}
+
+/// Divide um texto em linhas não vazias, sem espaços nas bordas
+fn split_lines(text: &str) -> Vec<String> {
+    text.lines()
+        .map(|line| line.trim())
+        .filter(|line| !line.is_empty())
+        .map(|line| line.to_string())
+        .collect()
+}
+
 fn render_modal_actions(
     ui: &mut egui::Ui,
     state: &mut AppModalState,
     is_editing: bool,
+    vars: &Interpolator,
 ) -> AppModalResult {
     let mut result = AppModalResult::None;
     
@@ -390,25 +1251,27 @@ fn render_modal_actions(
         }
 
         ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
-            let save_enabled = !state.app.name.trim().is_empty();
+            let app = state.build_app().and_then(|app| {
+                vars.check_app(&app)?;
+                Ok(app)
+            });
             
-            ui.add_enabled_ui(save_enabled, |ui| {
+            ui.add_enabled_ui(app.is_ok(), |ui| {
                 let save_text = if is_editing { "💾 Salvar" } else { "✅ Criar" };
                 if ui.button(
                     RichText::new(save_text)
                         .size(14.0)
                         .color(egui::Color32::WHITE),
                 ).clicked() {
-                    let mut app = state.app.clone();
-                    app.name = app.name.trim().to_string();
-                    app.working_dir = app.working_dir.trim().to_string();
-                    result = AppModalResult::Save(app, state.edit_index);
+                    if let Ok(app) = &app {
+                        result = AppModalResult::Save(Box::new(app.clone()), state.edit_index);
+                    }
                 }
             });
 
-            if !save_enabled {
+            if let Err(error) = &app {
                 ui.label(
-                    RichText::new("⚠ Nome é obrigatório")
+                    RichText::new(format!("⚠ {}", error))
                         .size(12.0)
                         .color(egui::Color32::from_rgb(255, 200, 100)),
                 );
@@ -0,0 +1,161 @@
+//! # Config Recovery Dialog
+//!
+//! Diálogo exibido quando o `config.json` não pode ser lido.
+//! Mostra onde está o erro e permite restaurar um backup, em vez de
+//! iniciar com uma configuração vazia e sobrescrever o arquivo.
+
+use std::path::Path;
+use std::time::SystemTime;
+
+use eframe::egui::{self, RichText};
+use crate::core::{BackupInfo, ConfigError};
+use crate::utils::format_age;
+
+/// Estado do diálogo de recuperação
+pub struct ConfigRecoveryState {
+    /// Erro encontrado ao carregar
+    pub error: ConfigError,
+    /// Backups disponíveis (mais recente primeiro)
+    pub backups: Vec<BackupInfo>,
+    /// Confirmação pendente para recomeçar sem aplicações
+    pub confirm_fresh: bool,
+}
+
+impl ConfigRecoveryState {
+    /// Cria o estado a partir do erro e dos backups encontrados
+    pub fn new(error: ConfigError, backups: Vec<BackupInfo>) -> Self {
+        Self {
+            error,
+            backups,
+            confirm_fresh: false,
+        }
+    }
+}
+
+/// Resultado das ações do diálogo
+pub enum ConfigRecoveryResult {
+    /// Nenhuma ação
+    None,
+    /// Restaurar o backup do índice informado
+    Restore(usize),
+    /// Recarregar o arquivo (após corrigi-lo manualmente)
+    Retry,
+    /// Preservar o arquivo inválido e começar sem aplicações
+    StartFresh,
+}
+
+/// Renderiza o diálogo de recuperação de configuração.
+///
+/// # Argumentos
+/// * `ctx` - Contexto do egui
+/// * `config_path` - Caminho do arquivo inválido
+/// * `state` - Estado do diálogo
+///
+/// # Retorno
+/// `ConfigRecoveryResult` indicando a ação tomada
+pub fn render_config_recovery(
+    ctx: &egui::Context,
+    config_path: &Path,
+    state: &mut ConfigRecoveryState,
+) -> ConfigRecoveryResult {
+    let mut result = ConfigRecoveryResult::None;
+
+    egui::Window::new("⚠ Configuração Inválida")
+        .collapsible(false)
+        .resizable(false)
+        .default_width(520.0)
+        .anchor(egui::Align2::CENTER_CENTER, [0.0, 0.0])
+        .show(ctx, |ui| {
+            ui.label("Não foi possível ler o arquivo de configuração:");
+            ui.label(
+                RichText::new(config_path.display().to_string())
+                    .monospace()
+                    .size(11.0)
+                    .color(egui::Color32::GRAY),
+            );
+            ui.add_space(8.0);
+
+            ui.label(
+                RichText::new(&state.error.message)
+                    .color(egui::Color32::from_rgb(255, 200, 100)),
+            );
+            if let (Some((line, column)), Some(excerpt)) = (state.error.location, &state.error.excerpt) {
+                ui.add_space(4.0);
+                egui::Frame::none()
+                    .fill(egui::Color32::from_rgb(28, 28, 34))
+                    .inner_margin(egui::Margin::same(8.0))
+                    .show(ui, |ui| {
+                        ui.label(RichText::new(format!("{:>4} | {}", line, excerpt)).monospace());
+                        ui.label(
+                            RichText::new(format!("{:>width$}^", "", width = column + 6))
+                                .monospace()
+                                .color(egui::Color32::from_rgb(255, 100, 100)),
+                        );
+                    });
+            }
+            ui.add_space(8.0);
+            ui.label(
+                RichText::new("Nenhuma alteração será salva até a configuração ser recuperada.")
+                    .size(11.0)
+                    .color(egui::Color32::GRAY),
+            );
+
+            ui.add_space(10.0);
+            ui.separator();
+            render_backup_list(ui, &state.backups, &mut result);
+            ui.separator();
+
+            ui.horizontal(|ui| {
+                if ui.button("🔄 Recarregar arquivo").clicked() {
+                    result = ConfigRecoveryResult::Retry;
+                }
+                ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                    if state.confirm_fresh {
+                        if ui.button(RichText::new("Confirmar").color(egui::Color32::from_rgb(255, 100, 100))).clicked() {
+                            result = ConfigRecoveryResult::StartFresh;
+                        }
+                        if ui.button("Cancelar").clicked() {
+                            state.confirm_fresh = false;
+                        }
+                    } else if ui
+                        .button("Começar sem aplicações")
+                        .on_hover_text("O arquivo atual é preservado como config.corrupt-*.json")
+                        .clicked()
+                    {
+                        state.confirm_fresh = true;
+                    }
+                });
+            });
+        });
+
+    result
+}
+
+/// Renderiza a lista de backups com o botão de restaurar
+fn render_backup_list(ui: &mut egui::Ui, backups: &[BackupInfo], result: &mut ConfigRecoveryResult) {
+    if backups.is_empty() {
+        ui.label(
+            RichText::new("Nenhum backup encontrado")
+                .size(12.0)
+                .color(egui::Color32::GRAY),
+        );
+        return;
+    }
+
+    ui.label("Backups disponíveis:");
+    egui::ScrollArea::vertical().max_height(200.0).show(ui, |ui| {
+        for (index, backup) in backups.iter().enumerate() {
+            ui.horizontal(|ui| {
+                let age = SystemTime::now().duration_since(backup.created).unwrap_or_default();
+                let name = backup.path.file_name().unwrap_or_default().to_string_lossy();
+                ui.label(RichText::new(format_age(age)).strong());
+                ui.label(RichText::new(name).monospace().size(11.0).color(egui::Color32::GRAY));
+                ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                    if ui.button("↩ Restaurar").clicked() {
+                        *result = ConfigRecoveryResult::Restore(index);
+                    }
+                });
+            });
+        }
+    });
+}
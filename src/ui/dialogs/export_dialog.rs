@@ -0,0 +1,173 @@
+//! # Export Dialog
+//!
+//! Escolha das aplicações a exportar e, opcionalmente, de uma pasta base
+//! cujos caminhos viram `${workspace.root}` para o arquivo funcionar em
+//! outras máquinas.
+
+use eframe::egui::{self, RichText};
+use crate::core::portable::{common_base, portable_text, ROOT_PLACEHOLDER};
+use crate::core::AppState;
+
+/// Aplicação listada no diálogo
+pub struct ExportEntry {
+    /// ID da aplicação
+    pub id: String,
+    /// Nome exibido
+    pub name: String,
+    /// Pasta de trabalho (prévia do caminho exportado)
+    pub working_dir: String,
+    /// Se a aplicação será exportada
+    pub selected: bool,
+}
+
+/// Estado do diálogo de exportação
+pub struct ExportDialogState {
+    /// Aplicações disponíveis
+    pub apps: Vec<ExportEntry>,
+    /// Se os caminhos sob `base` serão trocados por `${workspace.root}`
+    pub portable: bool,
+    /// Pasta base dos caminhos portáveis
+    pub base: String,
+}
+
+impl ExportDialogState {
+    /// Cria o estado com todas as aplicações marcadas e a pasta raiz do
+    /// workspace (ou a pasta comum das aplicações) como base
+    pub fn new(state: &AppState) -> Self {
+        let base = match state.workspace.root.trim() {
+            "" => common_base(&state.apps).unwrap_or_default(),
+            root => root.to_string(),
+        };
+        Self {
+            apps: state
+                .apps
+                .iter()
+                .map(|app| ExportEntry {
+                    id: app.id.clone(),
+                    name: app.name.clone(),
+                    working_dir: app.working_dir.clone(),
+                    selected: true,
+                })
+                .collect(),
+            portable: !base.is_empty(),
+            base,
+        }
+    }
+
+    /// IDs das aplicações marcadas
+    pub fn selected_ids(&self) -> Vec<String> {
+        self.apps
+            .iter()
+            .filter(|entry| entry.selected)
+            .map(|entry| entry.id.clone())
+            .collect()
+    }
+
+    /// Pasta base, se a exportação for portável
+    pub fn portable_base(&self) -> Option<&str> {
+        (self.portable && !self.base.trim().is_empty()).then(|| self.base.trim())
+    }
+}
+
+/// Resultado das ações do diálogo
+pub enum ExportDialogResult {
+    /// Nenhuma ação
+    None,
+    /// Escolher o arquivo e exportar
+    Export,
+    /// Cancelar a exportação
+    Cancelled,
+}
+
+/// Renderiza o diálogo de exportação.
+///
+/// # Argumentos
+/// * `ctx` - Contexto do egui
+/// * `state` - Estado do diálogo
+///
+/// # Retorno
+/// `ExportDialogResult` indicando a ação tomada
+pub fn render_export_dialog(ctx: &egui::Context, state: &mut ExportDialogState) -> ExportDialogResult {
+    let mut result = ExportDialogResult::None;
+
+    egui::Window::new("📤 Exportar configurações")
+        .collapsible(false)
+        .resizable(false)
+        .default_width(520.0)
+        .anchor(egui::Align2::CENTER_CENTER, [0.0, 0.0])
+        .show(ctx, |ui| {
+            ui.horizontal(|ui| {
+                ui.label("Aplicações:");
+                if ui.small_button("Marcar todas").clicked() {
+                    state.apps.iter_mut().for_each(|app| app.selected = true);
+                }
+                if ui.small_button("Desmarcar todas").clicked() {
+                    state.apps.iter_mut().for_each(|app| app.selected = false);
+                }
+            });
+
+            let base = state.portable_base().map(str::to_string);
+            egui::ScrollArea::vertical().max_height(260.0).show(ui, |ui| {
+                egui::Grid::new("export_apps_grid")
+                    .num_columns(2)
+                    .spacing([10.0, 4.0])
+                    .show(ui, |ui| {
+                        for entry in &mut state.apps {
+                            ui.checkbox(&mut entry.selected, entry.name.as_str());
+                            let shown = match &base {
+                                Some(base) => portable_text(&entry.working_dir, base),
+                                None => entry.working_dir.clone(),
+                            };
+                            let color = if shown.contains(ROOT_PLACEHOLDER) {
+                                egui::Color32::from_rgb(120, 220, 140)
+                            } else {
+                                egui::Color32::GRAY
+                            };
+                            ui.label(RichText::new(shown).size(11.0).monospace().color(color));
+                            ui.end_row();
+                        }
+                    });
+            });
+
+            ui.add_space(8.0);
+            ui.checkbox(&mut state.portable, "Exportação portável (caminhos relativos a ${workspace.root})");
+            ui.add_enabled_ui(state.portable, |ui| {
+                ui.horizontal(|ui| {
+                    ui.label("Pasta base:");
+                    ui.add(
+                        egui::TextEdit::singleline(&mut state.base)
+                            .desired_width(300.0)
+                            .hint_text("C:\\projetos"),
+                    );
+                    if ui.button("📁 Selecionar").clicked() {
+                        if let Some(path) = rfd::FileDialog::new().pick_folder() {
+                            state.base = path.display().to_string();
+                        }
+                    }
+                });
+                ui.label(
+                    RichText::new("Quem importar o arquivo informa onde essa pasta fica na máquina dele.")
+                        .size(11.0)
+                        .color(egui::Color32::GRAY),
+                );
+            });
+
+            ui.add_space(10.0);
+            ui.separator();
+
+            let count = state.apps.iter().filter(|app| app.selected).count();
+            ui.horizontal(|ui| {
+                if ui.button("Cancelar").clicked() {
+                    result = ExportDialogResult::Cancelled;
+                }
+                ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                    let export = egui::Button::new(RichText::new(format!("📤 Exportar {}", count)).strong());
+                    if ui.add_enabled(count > 0, export).clicked() {
+                        result = ExportDialogResult::Export;
+                    }
+                });
+            });
+        });
+
+    result
+}
@@ -0,0 +1,213 @@
+//! # History Dialog
+//!
+//! Lista as versões salvas da configuração, mostra as diferenças por
+//! aplicação entre duas versões e permite reverter uma aplicação ou
+//! a configuração inteira.
+
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use eframe::egui::{self, RichText};
+use crate::core::{short_hash, AppDiff, AppDiffKind, HistoryEntry};
+use crate::utils::format_age;
+
+/// Estado do diálogo de histórico
+pub struct HistoryDialogState {
+    /// Versões, da mais recente para a mais antiga
+    pub entries: Vec<HistoryEntry>,
+    /// Índice da versão antiga comparada (alvo das reversões)
+    pub from: usize,
+    /// Índice da versão nova comparada
+    pub to: usize,
+    /// Diferenças entre as duas versões
+    pub diff: Result<Vec<AppDiff>, String>,
+}
+
+impl HistoryDialogState {
+    /// Cria o estado comparando a versão atual com a anterior
+    pub fn new(mut entries: Vec<HistoryEntry>) -> Self {
+        entries.reverse();
+        Self {
+            from: entries.len().min(2).saturating_sub(1),
+            to: 0,
+            entries,
+            diff: Ok(Vec::new()),
+        }
+    }
+
+    /// Descrição curta de uma versão
+    fn entry_label(&self, index: usize) -> String {
+        let Some(entry) = self.entries.get(index) else {
+            return "-".to_string();
+        };
+        let saved = UNIX_EPOCH + Duration::from_secs(entry.saved_at);
+        let age = SystemTime::now().duration_since(saved).unwrap_or_default();
+        let current = if index == 0 { " (atual)" } else { "" };
+        format!("{} · {} · {} apps{}", format_age(age), short_hash(&entry.hash), entry.app_count, current)
+    }
+}
+
+/// Resultado das ações do diálogo
+pub enum HistoryDialogResult {
+    /// Nenhuma ação
+    None,
+    /// As versões comparadas mudaram
+    Compare,
+    /// Reverter uma aplicação (pelo ID) para a versão antiga
+    RevertApp(String),
+    /// Reverter a configuração inteira para a versão antiga
+    RevertAll,
+    /// Fechar o diálogo
+    Close,
+}
+
+/// Renderiza o diálogo de histórico.
+///
+/// # Argumentos
+/// * `ctx` - Contexto do egui
+/// * `state` - Estado do diálogo
+///
+/// # Retorno
+/// `HistoryDialogResult` indicando a ação tomada
+pub fn render_history_dialog(ctx: &egui::Context, state: &mut HistoryDialogState) -> HistoryDialogResult {
+    let mut result = HistoryDialogResult::None;
+
+    egui::Window::new("🕘 Histórico")
+        .collapsible(false)
+        .resizable(true)
+        .default_width(620.0)
+        .default_height(420.0)
+        .anchor(egui::Align2::CENTER_CENTER, [0.0, 0.0])
+        .show(ctx, |ui| {
+            if state.entries.is_empty() {
+                ui.label(
+                    RichText::new("Nenhuma versão salva ainda")
+                        .size(12.0)
+                        .color(egui::Color32::GRAY),
+                );
+            } else {
+                egui::Grid::new("history_select_grid")
+                    .num_columns(2)
+                    .spacing([12.0, 6.0])
+                    .show(ui, |ui| {
+                        ui.label("Versão antiga:");
+                        if render_entry_combo(ui, "history_from", state, true) {
+                            result = HistoryDialogResult::Compare;
+                        }
+                        ui.end_row();
+                        ui.label("Versão nova:");
+                        if render_entry_combo(ui, "history_to", state, false) {
+                            result = HistoryDialogResult::Compare;
+                        }
+                        ui.end_row();
+                    });
+
+                ui.add_space(8.0);
+                ui.separator();
+                render_diff(ui, state, &mut result);
+            }
+
+            ui.separator();
+            ui.horizontal(|ui| {
+                if ui.button("Fechar").clicked() {
+                    result = HistoryDialogResult::Close;
+                }
+                if state.from != 0 && !state.entries.is_empty() {
+                    ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                        if ui
+                            .button("↩ Reverter tudo para a versão antiga")
+                            .on_hover_text("Pode ser desfeito com Ctrl+Z")
+                            .clicked()
+                        {
+                            result = HistoryDialogResult::RevertAll;
+                        }
+                    });
+                }
+            });
+        });
+
+    result
+}
+
+/// Renderiza a seleção de uma versão. Retorna `true` se ela mudou.
+fn render_entry_combo(ui: &mut egui::Ui, id: &str, state: &mut HistoryDialogState, is_from: bool) -> bool {
+    let selected = if is_from { state.from } else { state.to };
+    let mut choice = selected;
+    egui::ComboBox::from_id_source(id)
+        .selected_text(state.entry_label(selected))
+        .width(360.0)
+        .show_ui(ui, |ui| {
+            for index in 0..state.entries.len() {
+                ui.selectable_value(&mut choice, index, state.entry_label(index));
+            }
+        });
+    if choice == selected {
+        return false;
+    }
+    if is_from {
+        state.from = choice;
+    } else {
+        state.to = choice;
+    }
+    true
+}
+
+/// Renderiza as diferenças por aplicação
+fn render_diff(ui: &mut egui::Ui, state: &HistoryDialogState, result: &mut HistoryDialogResult) {
+    let diffs = match &state.diff {
+        Ok(diffs) => diffs,
+        Err(e) => {
+            ui.label(RichText::new(format!("⚠ {}", e)).color(egui::Color32::from_rgb(255, 200, 100)));
+            return;
+        }
+    };
+    if diffs.is_empty() {
+        ui.label(
+            RichText::new("Nenhuma diferença entre as versões")
+                .size(12.0)
+                .color(egui::Color32::GRAY),
+        );
+        return;
+    }
+
+    let removed_color = egui::Color32::from_rgb(255, 120, 120);
+    let added_color = egui::Color32::from_rgb(120, 220, 140);
+    egui::ScrollArea::vertical().max_height(300.0).show(ui, |ui| {
+        for diff in diffs {
+            ui.horizontal(|ui| {
+                let (badge, color) = match diff.kind {
+                    AppDiffKind::Added => ("+ adicionada", added_color),
+                    AppDiffKind::Removed => ("− removida", removed_color),
+                    AppDiffKind::Modified(_) => ("~ alterada", egui::Color32::from_rgb(255, 200, 100)),
+                };
+                ui.label(RichText::new(&diff.name).strong());
+                ui.label(RichText::new(badge).size(11.0).color(color));
+                ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                    if ui
+                        .small_button("↩ Reverter app")
+                        .on_hover_text("Volta esta aplicação ao estado da versão antiga")
+                        .clicked()
+                    {
+                        *result = HistoryDialogResult::RevertApp(diff.app_id.clone());
+                    }
+                });
+            });
+
+            if let AppDiffKind::Modified(changes) = &diff.kind {
+                for change in changes {
+                    ui.horizontal_wrapped(|ui| {
+                        ui.add_space(16.0);
+                        ui.label(RichText::new(&change.path).monospace().size(11.0));
+                        if let Some(old) = &change.old {
+                            ui.label(RichText::new(old).monospace().size(11.0).color(removed_color));
+                        }
+                        ui.label(RichText::new("→").size(11.0).color(egui::Color32::GRAY));
+                        if let Some(new) = &change.new {
+                            ui.label(RichText::new(new).monospace().size(11.0).color(added_color));
+                        }
+                    });
+                }
+            }
+            ui.add_space(6.0);
+        }
+    });
+}
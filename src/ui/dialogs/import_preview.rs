@@ -0,0 +1,247 @@
+//! # Import Preview Dialog
+//!
+//! Pré-visualização de uma importação de configurações: mostra quais
+//! aplicações já existem (mesmo nome e pasta), a estratégia de cada uma
+//! e os campos que serão alterados antes de aplicar.
+//!
+//! Arquivos portáveis (com `${workspace.root}`) pedem a pasta raiz nesta
+//! máquina, e pastas de trabalho inexistentes são sinalizadas.
+
+use eframe::egui::{self, RichText};
+use crate::core::portable::{localize, missing_dir, uses_root};
+use crate::core::{plan_import, AppConfig, ImportItem, ImportStrategy};
+
+/// Estado do diálogo de pré-visualização
+pub struct ImportPreviewState {
+    /// Arquivo importado
+    pub source: String,
+    /// Aplicações como estão no arquivo
+    pub incoming: Vec<AppConfig>,
+    /// Pasta raiz nesta máquina (None = o arquivo não usa `${workspace.root}`)
+    pub root: Option<String>,
+    /// Aplicações importadas e suas estratégias
+    pub items: Vec<ImportItem>,
+    /// Pasta de trabalho inexistente de cada item
+    pub missing: Vec<Option<String>>,
+}
+
+impl ImportPreviewState {
+    /// Cria o estado e o plano de importação.
+    ///
+    /// # Argumentos
+    /// * `source` - Arquivo importado
+    /// * `current` - Aplicações existentes
+    /// * `incoming` - Aplicações do arquivo
+    /// * `root` - Pasta raiz sugerida (a do workspace local)
+    pub fn new(source: impl Into<String>, current: &[AppConfig], incoming: Vec<AppConfig>, root: &str) -> Self {
+        let mut state = Self {
+            source: source.into(),
+            root: uses_root(&incoming).then(|| root.trim().to_string()),
+            incoming,
+            items: Vec::new(),
+            missing: Vec::new(),
+        };
+        state.replan(current);
+        state
+    }
+
+    /// Refaz o plano com a pasta raiz atual (as estratégias voltam ao padrão)
+    pub fn replan(&mut self, current: &[AppConfig]) {
+        let apps = match self.root.as_deref().map(str::trim) {
+            Some(root) if !root.is_empty() => self.incoming.iter().map(|app| localize(app, root)).collect(),
+            _ => self.incoming.clone(),
+        };
+        self.items = plan_import(current, apps);
+        self.missing = self
+            .items
+            .iter()
+            .map(|item| missing_dir(&item.incoming).map(str::to_string))
+            .collect();
+    }
+
+    /// Quantas aplicações serão adicionadas ou alteradas
+    pub fn applied_count(&self) -> usize {
+        self.items.iter().filter(|item| item.strategy != ImportStrategy::Skip).count()
+    }
+}
+
+/// Resultado das ações do diálogo
+pub enum ImportPreviewResult {
+    /// Nenhuma ação
+    None,
+    /// Aplicar o plano
+    Apply,
+    /// Refazer o plano com a nova pasta raiz
+    Replan,
+    /// Cancelar a importação
+    Cancelled,
+}
+
+/// Renderiza a pré-visualização da importação.
+///
+/// # Argumentos
+/// * `ctx` - Contexto do egui
+/// * `state` - Estado do diálogo
+///
+/// # Retorno
+/// `ImportPreviewResult` indicando a ação tomada
+pub fn render_import_preview(ctx: &egui::Context, state: &mut ImportPreviewState) -> ImportPreviewResult {
+    let mut result = ImportPreviewResult::None;
+
+    egui::Window::new("📥 Importar configurações")
+        .collapsible(false)
+        .resizable(false)
+        .default_width(600.0)
+        .anchor(egui::Align2::CENTER_CENTER, [0.0, 0.0])
+        .show(ctx, |ui| {
+            ui.label(
+                RichText::new(&state.source)
+                    .size(11.0)
+                    .color(egui::Color32::GRAY),
+            );
+            ui.add_space(6.0);
+
+            if let Some(root) = &mut state.root {
+                ui.horizontal(|ui| {
+                    ui.label("Pasta raiz nesta máquina:");
+                    let edit = ui.add(
+                        egui::TextEdit::singleline(root)
+                            .desired_width(280.0)
+                            .hint_text("vazio = manter ${workspace.root}"),
+                    );
+                    if edit.lost_focus() {
+                        result = ImportPreviewResult::Replan;
+                    }
+                    if ui.button("📁 Selecionar").clicked() {
+                        if let Some(path) = rfd::FileDialog::new().pick_folder() {
+                            *root = path.display().to_string();
+                            result = ImportPreviewResult::Replan;
+                        }
+                    }
+                });
+                ui.add_space(6.0);
+            }
+
+            let missing = state.missing.iter().flatten().count();
+            if missing > 0 {
+                ui.label(
+                    RichText::new(format!("⚠ {} pastas de trabalho não existem nesta máquina", missing))
+                        .color(egui::Color32::from_rgb(255, 200, 100)),
+                );
+                ui.add_space(6.0);
+            }
+
+            let existing = state.items.iter().filter(|item| item.existing.is_some()).count();
+            if existing > 0 {
+                ui.horizontal(|ui| {
+                    ui.label(format!("{} aplicações já existem. Para todas:", existing));
+                    for strategy in ImportStrategy::ALL {
+                        if ui.small_button(strategy.label()).clicked() {
+                            state
+                                .items
+                                .iter_mut()
+                                .filter(|item| item.existing.is_some())
+                                .for_each(|item| item.strategy = strategy);
+                        }
+                    }
+                });
+                ui.add_space(6.0);
+            }
+
+            egui::ScrollArea::vertical().max_height(360.0).show(ui, |ui| {
+                for (index, (item, missing)) in state.items.iter_mut().zip(&state.missing).enumerate() {
+                    render_item(ui, index, item, missing.as_deref());
+                    ui.add_space(4.0);
+                }
+            });
+
+            ui.add_space(10.0);
+            ui.separator();
+
+            let count = state.applied_count();
+            ui.horizontal(|ui| {
+                if ui.button("Cancelar").clicked() {
+                    result = ImportPreviewResult::Cancelled;
+                }
+                ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                    let apply = egui::Button::new(RichText::new(format!("📥 Importar {}", count)).strong());
+                    if ui.add_enabled(count > 0, apply).clicked() {
+                        result = ImportPreviewResult::Apply;
+                    }
+                });
+            });
+        });
+
+    result
+}
+
+/// Linha de uma aplicação importada, com a estratégia e as diferenças
+fn render_item(ui: &mut egui::Ui, index: usize, item: &mut ImportItem, missing: Option<&str>) {
+    let removed_color = egui::Color32::from_rgb(255, 120, 120);
+    let added_color = egui::Color32::from_rgb(120, 220, 140);
+
+    ui.horizontal(|ui| {
+        ui.label(RichText::new(&item.incoming.name).strong());
+        if item.existing.is_some() {
+            ui.label(RichText::new("já existe").size(11.0).color(egui::Color32::from_rgb(255, 200, 100)));
+        } else {
+            ui.label(RichText::new("nova").size(11.0).color(added_color));
+        }
+        ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+            if item.existing.is_some() {
+                egui::ComboBox::from_id_source(("import_strategy", index))
+                    .selected_text(item.strategy.label())
+                    .show_ui(ui, |ui| {
+                        for strategy in ImportStrategy::ALL {
+                            ui.selectable_value(&mut item.strategy, strategy, strategy.label());
+                        }
+                    });
+            } else {
+                let mut add = item.strategy != ImportStrategy::Skip;
+                if ui.checkbox(&mut add, "Adicionar").changed() {
+                    item.strategy = if add { ImportStrategy::Add } else { ImportStrategy::Skip };
+                }
+            }
+        });
+    });
+
+    if let Some(dir) = missing {
+        ui.horizontal(|ui| {
+            ui.add_space(16.0);
+            let text = if item.incoming.repository.is_some() {
+                format!("⬇ Pasta não encontrada: {} (o repositório pode ser clonado pelo card)", dir)
+            } else {
+                format!("⚠ Pasta não encontrada: {}", dir)
+            };
+            ui.label(
+                RichText::new(text)
+                    .size(11.0)
+                    .color(egui::Color32::from_rgb(255, 200, 100)),
+            );
+        });
+    }
+
+    if !matches!(item.strategy, ImportStrategy::Overwrite | ImportStrategy::Merge) {
+        return;
+    }
+    let changes = item.changes();
+    if changes.is_empty() {
+        ui.horizontal(|ui| {
+            ui.add_space(16.0);
+            ui.label(RichText::new("Nenhum campo alterado").size(11.0).color(egui::Color32::GRAY));
+        });
+    }
+    for change in changes {
+        ui.horizontal_wrapped(|ui| {
+            ui.add_space(16.0);
+            ui.label(RichText::new(&change.path).monospace().size(11.0));
+            if let Some(old) = &change.old {
+                ui.label(RichText::new(old).monospace().size(11.0).color(removed_color));
+            }
+            ui.label(RichText::new("→").size(11.0).color(egui::Color32::GRAY));
+            if let Some(new) = &change.new {
+                ui.label(RichText::new(new).monospace().size(11.0).color(added_color));
+            }
+        });
+    }
+}
@@ -0,0 +1,151 @@
+//! # Import Review Dialog
+//!
+//! Revisão das aplicações propostas por um importador (ex: serviços de
+//! um docker-compose) antes de adicioná-las: escolha da alternativa,
+//! seleção das aplicações e ajuste dos nomes.
+
+use eframe::egui::{self, RichText};
+use crate::core::importers::ImportProposal;
+use crate::core::AppConfig;
+
+/// Estado do diálogo de revisão
+pub struct ImportReviewState {
+    /// Título da janela (ex: "Importar docker-compose")
+    pub title: String,
+    /// Proposta do importador (nomes editados ficam aqui)
+    pub proposal: ImportProposal,
+    /// Alternativa escolhida
+    pub variant: usize,
+    /// Aplicações marcadas da alternativa escolhida
+    pub selected: Vec<bool>,
+}
+
+impl ImportReviewState {
+    /// Cria o estado com a primeira alternativa e tudo marcado
+    pub fn new(title: impl Into<String>, proposal: ImportProposal) -> Self {
+        let selected = vec![true; proposal.variants.first().map_or(0, |v| v.apps.len())];
+        Self {
+            title: title.into(),
+            proposal,
+            variant: 0,
+            selected,
+        }
+    }
+
+    /// Aplicações marcadas, na ordem proposta
+    pub fn chosen(&self) -> Vec<AppConfig> {
+        let Some(variant) = self.proposal.variants.get(self.variant) else {
+            return Vec::new();
+        };
+        variant
+            .apps
+            .iter()
+            .zip(&self.selected)
+            .filter(|(_, &selected)| selected)
+            .map(|(app, _)| app.clone())
+            .collect()
+    }
+}
+
+/// Resultado das ações do diálogo
+pub enum ImportReviewResult {
+    /// Nenhuma ação
+    None,
+    /// Adicionar as aplicações marcadas
+    Add,
+    /// Cancelar a importação
+    Cancelled,
+}
+
+/// Renderiza o diálogo de revisão da importação.
+///
+/// # Argumentos
+/// * `ctx` - Contexto do egui
+/// * `state` - Estado do diálogo
+///
+/// # Retorno
+/// `ImportReviewResult` indicando a ação tomada
+pub fn render_import_review(ctx: &egui::Context, state: &mut ImportReviewState) -> ImportReviewResult {
+    let mut result = ImportReviewResult::None;
+
+    egui::Window::new(state.title.clone())
+        .collapsible(false)
+        .resizable(false)
+        .default_width(520.0)
+        .anchor(egui::Align2::CENTER_CENTER, [0.0, 0.0])
+        .show(ctx, |ui| {
+            ui.label(
+                RichText::new(&state.proposal.source)
+                    .size(11.0)
+                    .color(egui::Color32::GRAY),
+            );
+            ui.add_space(8.0);
+
+            for warning in &state.proposal.warnings {
+                ui.label(
+                    RichText::new(format!("⚠ {}", warning))
+                        .size(11.0)
+                        .color(egui::Color32::from_rgb(255, 200, 100)),
+                );
+            }
+            if !state.proposal.warnings.is_empty() {
+                ui.add_space(8.0);
+            }
+
+            if state.proposal.variants.len() > 1 {
+                let previous = state.variant;
+                for (index, variant) in state.proposal.variants.iter().enumerate() {
+                    ui.radio_value(&mut state.variant, index, &variant.label);
+                }
+                if state.variant != previous {
+                    state.selected = vec![true; state.proposal.variants[state.variant].apps.len()];
+                }
+                ui.add_space(8.0);
+            }
+
+            egui::ScrollArea::vertical().max_height(320.0).show(ui, |ui| {
+                egui::Grid::new("import_review_grid")
+                    .num_columns(3)
+                    .spacing([10.0, 6.0])
+                    .show(ui, |ui| {
+                        let variant = &mut state.proposal.variants[state.variant];
+                        for (app, selected) in variant.apps.iter_mut().zip(&mut state.selected) {
+                            ui.checkbox(selected, "");
+                            ui.add(egui::TextEdit::singleline(&mut app.name).desired_width(140.0));
+                            let mut details = app.commands.join(" && ");
+                            if let Some(port) = app.port {
+                                details.push_str(&format!("  · porta {}", port));
+                            }
+                            if !app.actions.is_empty() {
+                                details.push_str(&format!("  · {} ações", app.actions.len()));
+                            }
+                            ui.label(
+                                RichText::new(details)
+                                    .size(11.0)
+                                    .monospace()
+                                    .color(egui::Color32::GRAY),
+                            );
+                            ui.end_row();
+                        }
+                    });
+            });
+
+            ui.add_space(10.0);
+            ui.separator();
+
+            let count = state.selected.iter().filter(|&&selected| selected).count();
+            ui.horizontal(|ui| {
+                if ui.button("Cancelar").clicked() {
+                    result = ImportReviewResult::Cancelled;
+                }
+                ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                    let add = egui::Button::new(RichText::new(format!("➕ Adicionar {}", count)).strong());
+                    if ui.add_enabled(count > 0, add).clicked() {
+                        result = ImportReviewResult::Add;
+                    }
+                });
+            });
+        });
+
+    result
+}
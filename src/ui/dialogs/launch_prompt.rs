@@ -0,0 +1,136 @@
+//! # Launch Prompt Dialog
+//!
+//! Diálogo exibido antes da execução para preencher os parâmetros
+//! declarados pela aplicação.
+
+use std::collections::BTreeMap;
+
+use eframe::egui::{self, RichText};
+use crate::core::{AppConfig, LaunchParam, ParamKind};
+
+/// Estado do diálogo de parâmetros
+pub struct LaunchPromptState {
+    /// ID da aplicação a executar
+    pub app_id: String,
+    /// Perfil escolhido (`None` = perfil padrão)
+    pub profile: Option<String>,
+    /// Valores sendo preenchidos (nome -> valor)
+    pub values: BTreeMap<String, String>,
+}
+
+impl LaunchPromptState {
+    /// Cria o estado com os últimos valores (ou os padrões) da aplicação
+    pub fn new(app: &AppConfig, profile: Option<String>) -> Self {
+        Self {
+            app_id: app.id.clone(),
+            profile,
+            values: app
+                .params
+                .iter()
+                .map(|param| (param.name.clone(), app.param_value(param)))
+                .collect(),
+        }
+    }
+}
+
+/// Resultado das ações do diálogo
+pub enum LaunchPromptResult {
+    /// Nenhuma ação
+    None,
+    /// Executar com os valores preenchidos
+    Launch,
+    /// Cancelado
+    Cancelled,
+}
+
+/// Renderiza o diálogo de parâmetros de execução.
+///
+/// # Argumentos
+/// * `ctx` - Contexto do egui
+/// * `app_name` - Nome da aplicação
+/// * `params` - Parâmetros declarados pela aplicação
+/// * `state` - Estado do diálogo
+///
+/// # Retorno
+/// `LaunchPromptResult` indicando a ação tomada
+pub fn render_launch_prompt(
+    ctx: &egui::Context,
+    app_name: &str,
+    params: &[LaunchParam],
+    state: &mut LaunchPromptState,
+) -> LaunchPromptResult {
+    let mut result = LaunchPromptResult::None;
+
+    egui::Window::new(format!("▶ Executar {}", app_name))
+        .collapsible(false)
+        .resizable(false)
+        .anchor(egui::Align2::CENTER_CENTER, [0.0, 0.0])
+        .show(ctx, |ui| {
+            if let Some(profile) = &state.profile {
+                ui.label(
+                    RichText::new(format!("Perfil: {}", profile))
+                        .size(11.0)
+                        .color(egui::Color32::GRAY),
+                );
+            }
+            ui.add_space(6.0);
+
+            egui::Grid::new("launch_prompt_grid")
+                .num_columns(2)
+                .spacing([12.0, 8.0])
+                .show(ui, |ui| {
+                    for param in params {
+                        let value = state.values.entry(param.name.clone()).or_default();
+                        ui.label(param.display_label());
+                        render_param_input(ui, param, value);
+                        ui.end_row();
+                    }
+                });
+
+            ui.add_space(10.0);
+            ui.separator();
+
+            ui.horizontal(|ui| {
+                if ui.button("❌ Cancelar").clicked() {
+                    result = LaunchPromptResult::Cancelled;
+                }
+                ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                    let enter_pressed = ui.input(|i| i.key_pressed(egui::Key::Enter));
+                    if ui.button(RichText::new("▶ Executar").strong()).clicked() || enter_pressed {
+                        result = LaunchPromptResult::Launch;
+                    }
+                });
+            });
+        });
+
+    result
+}
+
+/// Renderiza o campo de um parâmetro conforme o tipo
+fn render_param_input(ui: &mut egui::Ui, param: &LaunchParam, value: &mut String) {
+    match param.kind {
+        ParamKind::Text => {
+            ui.add(
+                egui::TextEdit::singleline(value)
+                    .desired_width(240.0)
+                    .hint_text(&param.default),
+            );
+        }
+        ParamKind::Choice => {
+            egui::ComboBox::from_id_source(("launch_param", &param.name))
+                .selected_text(value.as_str())
+                .width(240.0)
+                .show_ui(ui, |ui| {
+                    for choice in &param.choices {
+                        ui.selectable_value(value, choice.clone(), choice);
+                    }
+                });
+        }
+        ParamKind::Boolean => {
+            let mut checked = value == "true";
+            if ui.checkbox(&mut checked, "").changed() {
+                *value = checked.to_string();
+            }
+        }
+    }
+}
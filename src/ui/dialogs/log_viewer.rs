@@ -0,0 +1,144 @@
+//! # Log Viewer Dialog
+//!
+//! Janela com a saída capturada de uma aplicação em segundo plano.
+
+use eframe::egui::{self, RichText};
+use crate::services::{AppSignal, LogStatus};
+use crate::ui::theme::ThemeColors;
+
+/// Estado dos controles de processo exibidos no visualizador
+#[derive(Clone, Copy)]
+pub struct ProcessControls {
+    /// Se a aplicação está pausada (SIGSTOP)
+    pub is_paused: bool,
+}
+
+/// Resultado das ações do visualizador de logs
+pub enum LogViewerResult {
+    /// Nenhuma ação
+    None,
+    /// Limpar a saída capturada
+    Clear,
+    /// Zerar o contador de erros
+    ResetErrors,
+    /// Enviar uma linha para a entrada padrão da aplicação
+    SendInput(String),
+    /// Enviar um sinal à aplicação
+    Signal(AppSignal),
+    /// Fechar a janela
+    Close,
+}
+
+/// Renderiza a janela de logs de uma aplicação.
+///
+/// # Argumentos
+/// * `ctx` - Contexto do egui
+/// * `app_name` - Nome da aplicação
+/// * `lines` - Linhas capturadas (mais antigas primeiro)
+/// * `status` - Estado derivado das regras de log
+/// * `input` - Texto sendo digitado para a entrada padrão
+/// * `controls` - Estado dos controles de processo (`None` se a app não está em execução)
+///
+/// # Retorno
+/// `LogViewerResult` indicando a ação tomada
+pub fn render_log_viewer(
+    ctx: &egui::Context,
+    app_name: &str,
+    lines: &[String],
+    status: &LogStatus,
+    input: &mut String,
+    controls: Option<ProcessControls>,
+) -> LogViewerResult {
+    let mut result = LogViewerResult::None;
+    let mut open = true;
+
+    egui::Window::new(format!("📜 Logs - {}", app_name))
+        .id(egui::Id::new("log_viewer"))
+        .open(&mut open)
+        .collapsible(false)
+        .resizable(true)
+        .default_size([640.0, 400.0])
+        .show(ctx, |ui| {
+            ui.horizontal(|ui| {
+                ui.label(
+                    RichText::new(format!("{} linha(s)", lines.len()))
+                        .size(11.0)
+                        .color(ThemeColors::TEXT_MUTED),
+                );
+
+                if status.error_count > 0 {
+                    ui.label(
+                        RichText::new(format!("⚠ {} erro(s)", status.error_count))
+                            .size(11.0)
+                            .color(ThemeColors::BTN_DANGER),
+                    );
+                    if ui.small_button("Zerar erros").clicked() {
+                        result = LogViewerResult::ResetErrors;
+                    }
+                }
+
+                ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                    if ui.small_button("🧹 Limpar").clicked() {
+                        result = LogViewerResult::Clear;
+                    }
+                });
+            });
+
+            if let Some(controls) = controls {
+                ui.horizontal(|ui| {
+                    for signal in AppSignal::MENU {
+                        if ui.small_button(signal.label()).clicked() {
+                            result = LogViewerResult::Signal(signal);
+                        }
+                    }
+                    let pause_signal = if controls.is_paused { AppSignal::Cont } else { AppSignal::Stop };
+                    if ui.small_button(pause_signal.label()).clicked() {
+                        result = LogViewerResult::Signal(pause_signal);
+                    }
+                });
+            }
+
+            ui.separator();
+
+            let input_height = if controls.is_some() { 36.0 } else { 0.0 };
+            egui::ScrollArea::both()
+                .max_height((ui.available_height() - input_height).max(80.0))
+                .auto_shrink([false, false])
+                .stick_to_bottom(true)
+                .show(ui, |ui| {
+                    if lines.is_empty() {
+                        ui.label(
+                            RichText::new("Nenhuma saída capturada ainda.")
+                                .color(ThemeColors::TEXT_MUTED),
+                        );
+                    }
+                    for line in lines {
+                        ui.label(RichText::new(line).monospace().size(12.0));
+                    }
+                });
+
+            if controls.is_some() {
+                ui.separator();
+                ui.horizontal(|ui| {
+                    ui.label(RichText::new("stdin ›").monospace().color(ThemeColors::TEXT_MUTED));
+                    let response = ui.add(
+                        egui::TextEdit::singleline(input)
+                            .desired_width(ui.available_width() - 90.0)
+                            .hint_text("Ex: rs (nodemon), r (Flutter)")
+                            .font(egui::TextStyle::Monospace),
+                    );
+                    let enter_pressed = response.lost_focus() && ui.input(|i| i.key_pressed(egui::Key::Enter));
+                    if ui.button("Enviar").clicked() || enter_pressed {
+                        result = LogViewerResult::SendInput(std::mem::take(input));
+                        response.request_focus();
+                    }
+                });
+            }
+        });
+
+    if !open {
+        result = LogViewerResult::Close;
+    }
+
+    result
+}
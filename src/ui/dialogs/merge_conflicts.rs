@@ -0,0 +1,123 @@
+//! # Merge Conflicts Dialog
+//!
+//! Exibido quando o `config.json` foi alterado fora do Iris e as mesmas
+//! aplicações também foram alteradas na interface. Para cada conflito,
+//! o usuário escolhe qual versão manter.
+
+use eframe::egui::{self, RichText};
+use crate::core::{AppConfig, AppState, MergeResult};
+
+/// Estado do diálogo de conflitos
+pub struct MergeConflictsState {
+    /// Resultado da mesclagem (com as versões da interface nos conflitos)
+    pub merge: MergeResult,
+    /// Conteúdo atual do arquivo
+    pub remote: AppState,
+    /// Escolha de cada conflito (`true` = versão do arquivo)
+    pub use_remote: Vec<bool>,
+    /// Escolha do conflito nas configurações do workspace
+    pub workspace_use_remote: bool,
+}
+
+impl MergeConflictsState {
+    /// Cria o estado mantendo, por padrão, as versões da interface
+    pub fn new(merge: MergeResult, remote: AppState) -> Self {
+        Self {
+            use_remote: vec![false; merge.conflicts.len()],
+            workspace_use_remote: false,
+            merge,
+            remote,
+        }
+    }
+
+    /// Aplica as escolhas e retorna o estado final
+    pub fn resolved(&self) -> AppState {
+        let mut merge = self.merge.clone();
+        for (conflict, &use_remote) in self.merge.conflicts.iter().zip(&self.use_remote) {
+            merge.resolve(conflict, use_remote);
+        }
+        if self.merge.workspace_conflict && self.workspace_use_remote {
+            merge.state.workspace = self.remote.workspace.clone();
+        }
+        merge.state
+    }
+}
+
+/// Resultado das ações do diálogo
+pub enum MergeConflictsResult {
+    /// Nenhuma ação
+    None,
+    /// Aplicar as escolhas
+    Apply,
+}
+
+/// Renderiza o diálogo de conflitos.
+///
+/// # Argumentos
+/// * `ctx` - Contexto do egui
+/// * `state` - Estado do diálogo
+///
+/// # Retorno
+/// `MergeConflictsResult` indicando a ação tomada
+pub fn render_merge_conflicts(ctx: &egui::Context, state: &mut MergeConflictsState) -> MergeConflictsResult {
+    let mut result = MergeConflictsResult::None;
+
+    egui::Window::new("⚠ Conflitos no config.json")
+        .collapsible(false)
+        .resizable(false)
+        .default_width(520.0)
+        .anchor(egui::Align2::CENTER_CENTER, [0.0, 0.0])
+        .show(ctx, |ui| {
+            ui.label("O arquivo de configuração foi alterado fora do Iris, e estas aplicações também foram alteradas aqui:");
+            ui.add_space(8.0);
+
+            egui::ScrollArea::vertical().max_height(300.0).show(ui, |ui| {
+                egui::Grid::new("merge_conflicts_grid")
+                    .num_columns(3)
+                    .spacing([12.0, 6.0])
+                    .show(ui, |ui| {
+                        for (conflict, use_remote) in state.merge.conflicts.iter().zip(&mut state.use_remote) {
+                            ui.label(RichText::new(conflict.name()).strong());
+                            ui.radio_value(use_remote, false, describe("Manter a da interface", &conflict.local));
+                            ui.radio_value(use_remote, true, describe("Usar a do arquivo", &conflict.remote));
+                            ui.end_row();
+                        }
+                        if state.merge.workspace_conflict {
+                            ui.label(RichText::new("Variáveis globais").strong());
+                            ui.radio_value(&mut state.workspace_use_remote, false, "Manter a da interface");
+                            ui.radio_value(&mut state.workspace_use_remote, true, "Usar a do arquivo");
+                            ui.end_row();
+                        }
+                    });
+            });
+
+            ui.add_space(10.0);
+            ui.separator();
+
+            ui.horizontal(|ui| {
+                if ui.button("Tudo da interface").clicked() {
+                    state.use_remote.iter_mut().for_each(|choice| *choice = false);
+                    state.workspace_use_remote = false;
+                }
+                if ui.button("Tudo do arquivo").clicked() {
+                    state.use_remote.iter_mut().for_each(|choice| *choice = true);
+                    state.workspace_use_remote = true;
+                }
+                ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                    if ui.button(RichText::new("✔ Aplicar").strong()).clicked() {
+                        result = MergeConflictsResult::Apply;
+                    }
+                });
+            });
+        });
+
+    result
+}
+
+/// Descreve uma opção, indicando quando a versão foi excluída
+fn describe(label: &str, app: &Option<AppConfig>) -> String {
+    match app {
+        Some(_) => label.to_string(),
+        None => format!("{} (excluída)", label),
+    }
+}
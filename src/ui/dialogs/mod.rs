@@ -3,7 +3,37 @@
 //! Diálogos e modais da aplicação.
 
 pub mod app_modal;
+pub mod config_recovery;
 pub mod delete_confirm;
+pub mod export_dialog;
+pub mod history_dialog;
+pub mod import_preview;
+pub mod import_review;
+pub mod launch_prompt;
+pub mod log_viewer;
+pub mod merge_conflicts;
+pub mod scan_wizard;
+pub mod script_export_dialog;
+pub mod secrets_dialog;
+pub mod task_output;
+pub mod terminal_window;
+pub mod trash_dialog;
+pub mod workspace_settings;
 
 pub use app_modal::*;
+pub use config_recovery::*;
 pub use delete_confirm::*;
+pub use export_dialog::*;
+pub use history_dialog::*;
+pub use import_preview::*;
+pub use import_review::*;
+pub use launch_prompt::*;
+pub use log_viewer::*;
+pub use merge_conflicts::*;
+pub use scan_wizard::*;
+pub use script_export_dialog::*;
+pub use secrets_dialog::*;
+pub use task_output::*;
+pub use terminal_window::*;
+pub use trash_dialog::*;
+pub use workspace_settings::*;
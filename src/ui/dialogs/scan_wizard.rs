@@ -0,0 +1,293 @@
+//! # Scan Wizard Dialog
+//!
+//! Descoberta em lote: percorre uma pasta raiz, lista os projetos
+//! detectados e cria as aplicações marcadas de uma só vez.
+//!
+//! A varredura roda em uma thread separada e pode ser cancelada.
+
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::mpsc::{self, Receiver, TryRecvError};
+use std::sync::Arc;
+use std::time::Duration;
+
+use eframe::egui::{self, RichText};
+use crate::core::{scan_projects, AppConfig, ProjectDetection, ProjectScan};
+
+/// Profundidade máxima oferecida no diálogo
+pub const MAX_SCAN_DEPTH: usize = 5;
+
+/// Projeto encontrado na varredura
+pub struct ScanCandidate {
+    /// Pasta do projeto
+    pub dir: PathBuf,
+    /// O que foi detectado na pasta
+    pub detection: ProjectDetection,
+    /// Nome da aplicação a criar (editável)
+    pub name: String,
+    /// Se a aplicação será criada
+    pub selected: bool,
+}
+
+/// Varredura em andamento; cancelada ao ser descartada
+pub struct ScanJob {
+    cancel: Arc<AtomicBool>,
+    receiver: Receiver<ProjectScan>,
+}
+
+impl Drop for ScanJob {
+    fn drop(&mut self) {
+        self.cancel.store(true, Ordering::Relaxed);
+    }
+}
+
+/// Estado do assistente de varredura
+pub struct ScanWizardState {
+    /// Pasta raiz da varredura
+    pub root: String,
+    /// Níveis de subpastas percorridos
+    pub depth: usize,
+    /// Projetos encontrados (None = ainda não varrido)
+    pub candidates: Option<Vec<ScanCandidate>>,
+    /// Varredura em andamento
+    pub scanning: Option<ScanJob>,
+    /// Se a última varredura parou em um dos limites
+    pub truncated: bool,
+    /// Se as aplicações serão criadas em um grupo
+    pub as_group: bool,
+    /// Nome do grupo
+    pub group_name: String,
+}
+
+impl Default for ScanWizardState {
+    fn default() -> Self {
+        Self {
+            root: String::new(),
+            depth: 2,
+            candidates: None,
+            scanning: None,
+            truncated: false,
+            as_group: false,
+            group_name: String::new(),
+        }
+    }
+}
+
+impl ScanWizardState {
+    /// Inicia a varredura da pasta raiz em uma thread separada,
+    /// cancelando a anterior, se houver
+    pub fn start_scan(&mut self) {
+        let cancel = Arc::new(AtomicBool::new(false));
+        let (sender, receiver) = mpsc::channel();
+        let root = PathBuf::from(self.root.trim());
+        let depth = self.depth;
+        let flag = Arc::clone(&cancel);
+        std::thread::spawn(move || {
+            let _ = sender.send(scan_projects(&root, depth, &flag));
+        });
+        self.scanning = Some(ScanJob { cancel, receiver });
+    }
+
+    /// Recebe o resultado da varredura, se ela já terminou
+    pub fn poll_scan(&mut self) {
+        let Some(job) = &self.scanning else {
+            return;
+        };
+        match job.receiver.try_recv() {
+            Ok(scan) => {
+                self.scanning = None;
+                self.truncated = scan.truncated;
+                self.set_results(scan.projects);
+            }
+            Err(TryRecvError::Disconnected) => self.scanning = None,
+            Err(TryRecvError::Empty) => {}
+        }
+    }
+
+    /// Registra o resultado da varredura; projetos sem comandos sugeridos
+    /// começam desmarcados
+    pub fn set_results(&mut self, results: Vec<(PathBuf, ProjectDetection)>) {
+        self.candidates = Some(
+            results
+                .into_iter()
+                .map(|(dir, detection)| ScanCandidate {
+                    name: detection.name.clone(),
+                    selected: !detection.commands.is_empty(),
+                    dir,
+                    detection,
+                })
+                .collect(),
+        );
+    }
+
+    /// Aplicações a criar a partir dos projetos marcados
+    pub fn apps(&self) -> Vec<AppConfig> {
+        let group = (self.as_group && !self.group_name.trim().is_empty()).then(|| self.group_name.trim().to_string());
+        self.candidates
+            .iter()
+            .flatten()
+            .filter(|candidate| candidate.selected)
+            .map(|candidate| AppConfig {
+                icon_emoji: candidate.detection.icon.clone(),
+                working_dir: candidate.dir.display().to_string(),
+                commands: candidate.detection.commands.clone(),
+                group: group.clone(),
+                ..AppConfig::new(candidate.name.clone())
+            })
+            .collect()
+    }
+}
+
+/// Resultado das ações do assistente
+pub enum ScanWizardResult {
+    /// Nenhuma ação
+    None,
+    /// Escolher a pasta raiz
+    PickRoot,
+    /// Varrer a pasta raiz
+    Scan,
+    /// Cancelar a varredura em andamento
+    CancelScan,
+    /// Criar as aplicações marcadas
+    Create,
+    /// Fechar o assistente
+    Close,
+}
+
+/// Renderiza o assistente de varredura.
+///
+/// # Argumentos
+/// * `ctx` - Contexto do egui
+/// * `state` - Estado do assistente
+///
+/// # Retorno
+/// `ScanWizardResult` indicando a ação tomada
+pub fn render_scan_wizard(ctx: &egui::Context, state: &mut ScanWizardState) -> ScanWizardResult {
+    let mut result = ScanWizardResult::None;
+    state.poll_scan();
+    if state.scanning.is_some() {
+        ctx.request_repaint_after(Duration::from_millis(100));
+    }
+
+    egui::Window::new("🔍 Escanear pasta")
+        .collapsible(false)
+        .resizable(false)
+        .default_width(600.0)
+        .anchor(egui::Align2::CENTER_CENTER, [0.0, 0.0])
+        .show(ctx, |ui| {
+            ui.horizontal(|ui| {
+                ui.label("Pasta raiz:");
+                ui.add(
+                    egui::TextEdit::singleline(&mut state.root)
+                        .desired_width(320.0)
+                        .hint_text("C:\\projetos"),
+                );
+                if ui.button("📁 Selecionar").clicked() {
+                    result = ScanWizardResult::PickRoot;
+                }
+            });
+            ui.horizontal(|ui| {
+                ui.label("Profundidade:");
+                ui.add(egui::Slider::new(&mut state.depth, 0..=MAX_SCAN_DEPTH).suffix(" níveis"));
+                if state.scanning.is_some() {
+                    ui.spinner();
+                    ui.label(RichText::new("Escaneando...").size(12.0).color(egui::Color32::GRAY));
+                    if ui.button("Cancelar").clicked() {
+                        result = ScanWizardResult::CancelScan;
+                    }
+                } else if ui.add_enabled(!state.root.trim().is_empty(), egui::Button::new("🔍 Escanear")).clicked() {
+                    result = ScanWizardResult::Scan;
+                }
+            });
+
+            if state.truncated && state.scanning.is_none() {
+                ui.label(
+                    RichText::new("⚠ A varredura parou no limite de pastas ou de projetos. Escolha uma pasta mais específica ou reduza a profundidade.")
+                        .size(12.0)
+                        .color(egui::Color32::from_rgb(255, 200, 100)),
+                );
+            }
+
+            if let Some(candidates) = state.candidates.as_mut().filter(|_| state.scanning.is_none()) {
+                ui.add_space(8.0);
+                ui.separator();
+                if candidates.is_empty() {
+                    ui.label(
+                        RichText::new("Nenhum projeto encontrado. Aumente a profundidade ou escolha outra pasta.")
+                            .size(12.0)
+                            .color(egui::Color32::GRAY),
+                    );
+                } else {
+                    ui.horizontal(|ui| {
+                        ui.label(format!("{} projetos encontrados", candidates.len()));
+                        if ui.small_button("Marcar todos").clicked() {
+                            candidates.iter_mut().for_each(|c| c.selected = true);
+                        }
+                        if ui.small_button("Desmarcar todos").clicked() {
+                            candidates.iter_mut().for_each(|c| c.selected = false);
+                        }
+                    });
+                    render_candidates(ui, candidates);
+                }
+            }
+
+            ui.add_space(10.0);
+            ui.separator();
+
+            let count = state.candidates.iter().flatten().filter(|c| c.selected).count();
+            ui.horizontal(|ui| {
+                ui.checkbox(&mut state.as_group, "Criar em um novo grupo:");
+                ui.add_enabled(
+                    state.as_group,
+                    egui::TextEdit::singleline(&mut state.group_name)
+                        .desired_width(140.0)
+                        .hint_text("Ex: plataforma"),
+                );
+                ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                    let create = egui::Button::new(RichText::new(format!("➕ Criar {}", count)).strong());
+                    if ui.add_enabled(count > 0 && state.scanning.is_none(), create).clicked() {
+                        result = ScanWizardResult::Create;
+                    }
+                    if ui.button("Fechar").clicked() {
+                        result = ScanWizardResult::Close;
+                    }
+                });
+            });
+        });
+
+    result
+}
+
+/// Lista de projetos encontrados, com nome editável e comandos sugeridos
+fn render_candidates(ui: &mut egui::Ui, candidates: &mut [ScanCandidate]) {
+    egui::ScrollArea::vertical().max_height(340.0).show(ui, |ui| {
+        egui::Grid::new("scan_candidates_grid")
+            .num_columns(4)
+            .spacing([10.0, 6.0])
+            .striped(true)
+            .show(ui, |ui| {
+                for candidate in candidates {
+                    ui.checkbox(&mut candidate.selected, "");
+                    ui.add(egui::TextEdit::singleline(&mut candidate.name).desired_width(130.0));
+                    ui.label(
+                        RichText::new(candidate.detection.stacks.join(", "))
+                            .size(11.0)
+                            .color(egui::Color32::GRAY),
+                    )
+                    .on_hover_text(candidate.dir.display().to_string());
+                    let commands = if candidate.detection.commands.is_empty() {
+                        "(sem comandos sugeridos)".to_string()
+                    } else {
+                        candidate.detection.commands.join(" && ")
+                    };
+                    ui.label(
+                        RichText::new(commands)
+                            .size(11.0)
+                            .monospace()
+                            .color(egui::Color32::GRAY),
+                    );
+                    ui.end_row();
+                }
+            });
+    });
+}
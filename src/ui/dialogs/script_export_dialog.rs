@@ -0,0 +1,167 @@
+//! # Script Export Dialog
+//!
+//! Escolha das aplicações (uma, um grupo ou várias) e do formato para
+//! executá-las fora do Iris: scripts, Procfile, Makefile ou systemd.
+
+use eframe::egui::{self, RichText};
+use crate::core::AppState;
+use crate::services::ScriptTarget;
+
+/// Aplicação listada no diálogo
+pub struct ScriptExportEntry {
+    /// ID da aplicação
+    pub id: String,
+    /// Nome exibido
+    pub name: String,
+    /// Grupo da aplicação
+    pub group: Option<String>,
+    /// Se a aplicação será exportada
+    pub selected: bool,
+}
+
+/// Estado do diálogo de exportação como script
+pub struct ScriptExportDialogState {
+    /// Aplicações disponíveis
+    pub apps: Vec<ScriptExportEntry>,
+    /// Grupos existentes, em ordem alfabética
+    pub groups: Vec<String>,
+    /// Formato escolhido
+    pub target: ScriptTarget,
+    /// Nome do conjunto (script que inicia todas, `.target` do systemd)
+    pub name: String,
+}
+
+impl ScriptExportDialogState {
+    /// Cria o estado sem aplicações marcadas
+    pub fn new(state: &AppState) -> Self {
+        let mut groups: Vec<String> = state.apps.iter().filter_map(|app| app.group.clone()).collect();
+        groups.sort();
+        groups.dedup();
+        Self {
+            apps: state
+                .apps
+                .iter()
+                .map(|app| ScriptExportEntry {
+                    id: app.id.clone(),
+                    name: app.name.clone(),
+                    group: app.group.clone(),
+                    selected: false,
+                })
+                .collect(),
+            groups,
+            target: ScriptTarget::default(),
+            name: "iris".to_string(),
+        }
+    }
+
+    /// Marca apenas as aplicações do grupo e usa o nome dele no conjunto
+    pub fn select_group(&mut self, group: &str) {
+        for entry in &mut self.apps {
+            entry.selected = entry.group.as_deref() == Some(group);
+        }
+        self.name = group.to_string();
+    }
+
+    /// IDs das aplicações marcadas
+    pub fn selected_ids(&self) -> Vec<String> {
+        self.apps
+            .iter()
+            .filter(|entry| entry.selected)
+            .map(|entry| entry.id.clone())
+            .collect()
+    }
+}
+
+/// Resultado das ações do diálogo
+pub enum ScriptExportResult {
+    /// Nenhuma ação
+    None,
+    /// Escolher a pasta e gerar os arquivos
+    Export,
+    /// Cancelar a exportação
+    Cancelled,
+}
+
+/// Renderiza o diálogo de exportação como script.
+///
+/// # Argumentos
+/// * `ctx` - Contexto do egui
+/// * `state` - Estado do diálogo
+///
+/// # Retorno
+/// `ScriptExportResult` indicando a ação tomada
+pub fn render_script_export_dialog(ctx: &egui::Context, state: &mut ScriptExportDialogState) -> ScriptExportResult {
+    let mut result = ScriptExportResult::None;
+
+    egui::Window::new("🧾 Exportar como script")
+        .collapsible(false)
+        .resizable(false)
+        .default_width(480.0)
+        .anchor(egui::Align2::CENTER_CENTER, [0.0, 0.0])
+        .show(ctx, |ui| {
+            ui.horizontal_wrapped(|ui| {
+                ui.label("Aplicações:");
+                if ui.small_button("Marcar todas").clicked() {
+                    state.apps.iter_mut().for_each(|app| app.selected = true);
+                }
+                if ui.small_button("Desmarcar todas").clicked() {
+                    state.apps.iter_mut().for_each(|app| app.selected = false);
+                }
+                let mut chosen = None;
+                for group in &state.groups {
+                    if ui.small_button(format!("📂 {}", group)).clicked() {
+                        chosen = Some(group.clone());
+                    }
+                }
+                if let Some(group) = chosen {
+                    state.select_group(&group);
+                }
+            });
+
+            egui::ScrollArea::vertical().max_height(220.0).show(ui, |ui| {
+                for entry in &mut state.apps {
+                    ui.horizontal(|ui| {
+                        ui.checkbox(&mut entry.selected, entry.name.as_str());
+                        if let Some(group) = &entry.group {
+                            ui.label(RichText::new(group).size(11.0).color(egui::Color32::GRAY));
+                        }
+                    });
+                }
+            });
+
+            ui.add_space(8.0);
+            ui.label("Formato:");
+            for target in ScriptTarget::ALL {
+                ui.radio_value(&mut state.target, target, target.label());
+            }
+
+            ui.add_space(8.0);
+            ui.horizontal(|ui| {
+                ui.label("Nome do conjunto:");
+                ui.add(egui::TextEdit::singleline(&mut state.name).desired_width(200.0));
+            });
+            ui.label(
+                RichText::new("Usado no script que inicia todas e no .target do systemd. As variáveis são expandidas; segredos não são exportados.")
+                    .size(11.0)
+                    .color(egui::Color32::GRAY),
+            );
+
+            ui.add_space(10.0);
+            ui.separator();
+
+            let count = state.apps.iter().filter(|app| app.selected).count();
+            ui.horizontal(|ui| {
+                if ui.button("Cancelar").clicked() {
+                    result = ScriptExportResult::Cancelled;
+                }
+                ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                    let export = egui::Button::new(RichText::new(format!("🧾 Gerar para {}", count)).strong());
+                    if ui.add_enabled(count > 0, export).clicked() {
+                        result = ScriptExportResult::Export;
+                    }
+                });
+            });
+        });
+
+    result
+}
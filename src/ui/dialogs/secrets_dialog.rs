@@ -0,0 +1,161 @@
+//! # Secrets Dialog
+//!
+//! Diálogo para desbloquear o cofre de segredos e gerenciar os valores
+//! referenciados como `${secret:NOME}`.
+
+use eframe::egui::{self, RichText};
+use crate::services::{SecretStore, SECRET_MASK};
+
+/// Estado do diálogo de segredos
+#[derive(Default)]
+pub struct SecretsDialogState {
+    /// Senha digitada para desbloquear
+    pub passphrase: String,
+    /// Nome do novo segredo
+    pub new_name: String,
+    /// Valor do novo segredo
+    pub new_value: String,
+    /// Último erro a exibir
+    pub error: Option<String>,
+}
+
+/// Resultado das ações do diálogo
+pub enum SecretsDialogResult {
+    /// Nenhuma ação
+    None,
+    /// Desbloquear (ou criar) o cofre com a senha digitada
+    Unlock,
+    /// Salvar o segredo digitado
+    Set,
+    /// Remover um segredo pelo nome
+    Remove(String),
+    /// Bloquear o cofre
+    Lock,
+    /// Fechar o diálogo
+    Close,
+}
+
+/// Renderiza o diálogo de segredos.
+///
+/// Os valores nunca são exibidos: apenas os nomes e a referência a usar.
+///
+/// # Argumentos
+/// * `ctx` - Contexto do egui
+/// * `store` - Cofre de segredos
+/// * `state` - Estado do diálogo
+///
+/// # Retorno
+/// `SecretsDialogResult` indicando a ação tomada
+pub fn render_secrets_dialog(
+    ctx: &egui::Context,
+    store: &SecretStore,
+    state: &mut SecretsDialogState,
+) -> SecretsDialogResult {
+    let mut result = SecretsDialogResult::None;
+
+    egui::Window::new("🔐 Segredos")
+        .collapsible(false)
+        .resizable(false)
+        .default_width(440.0)
+        .anchor(egui::Align2::CENTER_CENTER, [0.0, 0.0])
+        .show(ctx, |ui| {
+            ui.add_space(6.0);
+
+            if store.is_unlocked() {
+                render_secret_list(ui, store, state, &mut result);
+            } else {
+                let label = if store.exists() {
+                    "Senha do cofre:"
+                } else {
+                    "Nenhum cofre encontrado. Defina a senha para criá-lo:"
+                };
+                ui.label(label);
+                let response = ui.add(
+                    egui::TextEdit::singleline(&mut state.passphrase)
+                        .password(true)
+                        .desired_width(300.0),
+                );
+                let enter_pressed = response.lost_focus() && ui.input(|i| i.key_pressed(egui::Key::Enter));
+                ui.add_space(4.0);
+                if ui.button("🔓 Desbloquear").clicked() || enter_pressed {
+                    result = SecretsDialogResult::Unlock;
+                }
+            }
+
+            if let Some(error) = &state.error {
+                ui.add_space(6.0);
+                ui.label(
+                    RichText::new(format!("⚠ {}", error))
+                        .size(12.0)
+                        .color(egui::Color32::from_rgb(255, 200, 100)),
+                );
+            }
+
+            ui.add_space(10.0);
+            ui.separator();
+
+            ui.horizontal(|ui| {
+                if ui.button("Fechar").clicked() {
+                    result = SecretsDialogResult::Close;
+                }
+                if store.is_unlocked() {
+                    ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                        if ui.button("🔒 Bloquear").clicked() {
+                            result = SecretsDialogResult::Lock;
+                        }
+                    });
+                }
+            });
+        });
+
+    result
+}
+
+/// Renderiza a lista de segredos e o formulário de inclusão
+fn render_secret_list(
+    ui: &mut egui::Ui,
+    store: &SecretStore,
+    state: &mut SecretsDialogState,
+    result: &mut SecretsDialogResult,
+) {
+    if store.secrets().is_empty() {
+        ui.label(
+            RichText::new("Nenhum segredo cadastrado")
+                .size(12.0)
+                .color(egui::Color32::GRAY),
+        );
+    } else {
+        egui::Grid::new("secrets_grid")
+            .num_columns(3)
+            .spacing([12.0, 6.0])
+            .show(ui, |ui| {
+                for name in store.secrets().keys() {
+                    ui.label(RichText::new(format!("${{secret:{}}}", name)).monospace());
+                    ui.label(RichText::new(SECRET_MASK).color(egui::Color32::GRAY));
+                    if ui.small_button("🗑").on_hover_text("Remover").clicked() {
+                        *result = SecretsDialogResult::Remove(name.clone());
+                    }
+                    ui.end_row();
+                }
+            });
+    }
+
+    ui.add_space(10.0);
+    ui.label("Novo segredo:");
+    ui.horizontal(|ui| {
+        ui.add(
+            egui::TextEdit::singleline(&mut state.new_name)
+                .desired_width(140.0)
+                .hint_text("API_TOKEN"),
+        );
+        ui.add(
+            egui::TextEdit::singleline(&mut state.new_value)
+                .password(true)
+                .desired_width(180.0)
+                .hint_text("valor"),
+        );
+        if ui.button("➕ Salvar").clicked() {
+            *result = SecretsDialogResult::Set;
+        }
+    });
+}
@@ -0,0 +1,96 @@
+//! # Task Output Dialog
+//!
+//! Janela com a saída e o resultado de uma ação personalizada.
+
+use eframe::egui::{self, RichText};
+use crate::services::{TaskInfo, TaskStatus};
+use crate::ui::theme::ThemeColors;
+
+/// Resultado das ações da janela de saída da tarefa
+pub enum TaskOutputResult {
+    /// Nenhuma ação
+    None,
+    /// Executar a ação novamente
+    Rerun,
+    /// Fechar a janela
+    Close,
+}
+
+/// Renderiza a janela de saída de uma ação personalizada.
+///
+/// # Argumentos
+/// * `ctx` - Contexto do egui
+/// * `title` - Nome da aplicação e da ação
+/// * `lines` - Linhas capturadas (mais antigas primeiro)
+/// * `info` - Última execução da ação, se houver
+///
+/// # Retorno
+/// `TaskOutputResult` indicando a ação tomada
+pub fn render_task_output(
+    ctx: &egui::Context,
+    title: &str,
+    lines: &[String],
+    info: Option<&TaskInfo>,
+) -> TaskOutputResult {
+    let mut result = TaskOutputResult::None;
+    let mut open = true;
+
+    egui::Window::new(format!("🛠 {}", title))
+        .id(egui::Id::new("task_output"))
+        .open(&mut open)
+        .collapsible(false)
+        .resizable(true)
+        .default_size([600.0, 360.0])
+        .show(ctx, |ui| {
+            ui.horizontal(|ui| {
+                if let Some(info) = info {
+                    let color = match info.status {
+                        TaskStatus::Running => ThemeColors::LOADING_BORDER,
+                        TaskStatus::Succeeded => ThemeColors::RUNNING_TEXT,
+                        TaskStatus::Failed(_) => ThemeColors::BTN_DANGER,
+                    };
+                    ui.label(
+                        RichText::new(format!("{} {}", info.status.icon(), info.status.label()))
+                            .size(11.0)
+                            .color(color),
+                    );
+                    let elapsed = info.duration.unwrap_or_else(|| info.started.elapsed());
+                    ui.label(
+                        RichText::new(format!("{:.1}s", elapsed.as_secs_f32()))
+                            .size(11.0)
+                            .color(ThemeColors::TEXT_MUTED),
+                    );
+                }
+
+                ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                    let running = info.is_some_and(|info| info.status == TaskStatus::Running);
+                    if ui.add_enabled(!running, egui::Button::new("↻ Executar novamente").small()).clicked() {
+                        result = TaskOutputResult::Rerun;
+                    }
+                });
+            });
+
+            ui.separator();
+
+            egui::ScrollArea::both()
+                .auto_shrink([false, false])
+                .stick_to_bottom(true)
+                .show(ui, |ui| {
+                    if lines.is_empty() {
+                        ui.label(
+                            RichText::new("Nenhuma saída.")
+                                .color(ThemeColors::TEXT_MUTED),
+                        );
+                    }
+                    for line in lines {
+                        ui.label(RichText::new(line).monospace().size(12.0));
+                    }
+                });
+        });
+
+    if !open {
+        result = TaskOutputResult::Close;
+    }
+
+    result
+}
@@ -0,0 +1,244 @@
+//! # Terminal Window Dialog
+//!
+//! Janela com o terminal embutido de uma aplicação: renderiza a grade
+//! VT100 e encaminha o teclado para o processo.
+
+use std::time::Duration;
+
+use eframe::egui::{self, text::LayoutJob, Color32, FontId, RichText, TextFormat};
+use crate::services::{encode_key, PtySession};
+use crate::ui::theme::ThemeColors;
+
+/// Cor padrão do texto do terminal
+const TERMINAL_FG: Color32 = Color32::from_rgb(220, 220, 225);
+
+/// Cor padrão do fundo do terminal
+const TERMINAL_BG: Color32 = Color32::from_rgb(14, 14, 18);
+
+/// Paleta ANSI básica (cores 0-15)
+const ANSI_COLORS: [Color32; 16] = [
+    Color32::from_rgb(30, 30, 36),
+    Color32::from_rgb(224, 82, 82),
+    Color32::from_rgb(80, 200, 120),
+    Color32::from_rgb(230, 190, 80),
+    Color32::from_rgb(90, 140, 240),
+    Color32::from_rgb(190, 110, 220),
+    Color32::from_rgb(70, 190, 200),
+    Color32::from_rgb(200, 200, 205),
+    Color32::from_rgb(100, 100, 110),
+    Color32::from_rgb(255, 120, 120),
+    Color32::from_rgb(120, 240, 160),
+    Color32::from_rgb(255, 220, 120),
+    Color32::from_rgb(130, 170, 255),
+    Color32::from_rgb(220, 150, 250),
+    Color32::from_rgb(110, 230, 240),
+    Color32::from_rgb(250, 250, 255),
+];
+
+/// Resultado das ações da janela de terminal
+pub enum TerminalWindowResult {
+    /// Nenhuma ação
+    None,
+    /// Fechar a janela
+    Close,
+}
+
+/// Renderiza a janela do terminal embutido.
+///
+/// # Argumentos
+/// * `ctx` - Contexto do egui
+/// * `app_name` - Nome da aplicação
+/// * `session` - Sessão de pseudo-terminal da aplicação
+///
+/// # Retorno
+/// `TerminalWindowResult` indicando a ação tomada
+pub fn render_terminal_window(
+    ctx: &egui::Context,
+    app_name: &str,
+    session: &mut PtySession,
+) -> TerminalWindowResult {
+    let mut open = true;
+
+    egui::Window::new(format!("🖥 Terminal - {}", app_name))
+        .id(egui::Id::new("terminal_window"))
+        .open(&mut open)
+        .collapsible(false)
+        .resizable(true)
+        .default_size([760.0, 440.0])
+        .show(ctx, |ui| {
+            ui.horizontal(|ui| {
+                if session.is_running() {
+                    ui.label(
+                        RichText::new("● Em execução")
+                            .size(11.0)
+                            .color(ThemeColors::RUNNING_TEXT),
+                    );
+                } else {
+                    ui.label(
+                        RichText::new("■ Processo encerrado")
+                            .size(11.0)
+                            .color(ThemeColors::TEXT_MUTED),
+                    );
+                }
+                ui.label(
+                    RichText::new("Clique no terminal para digitar")
+                        .size(11.0)
+                        .color(ThemeColors::TEXT_MUTED),
+                );
+            });
+
+            ui.separator();
+            render_terminal(ui, session);
+        });
+
+    // Mantém a tela atualizada enquanto a janela estiver aberta
+    ctx.request_repaint_after(Duration::from_millis(50));
+
+    if open {
+        TerminalWindowResult::None
+    } else {
+        TerminalWindowResult::Close
+    }
+}
+
+/// Renderiza a grade do terminal e trata o teclado quando focado
+fn render_terminal(ui: &mut egui::Ui, session: &mut PtySession) {
+    let font_id = FontId::monospace(13.0);
+    let (char_width, row_height) = ui.fonts(|fonts| {
+        (fonts.glyph_width(&font_id, 'M'), fonts.row_height(&font_id))
+    });
+
+    // Ajusta o tamanho do pseudo-terminal ao espaço disponível
+    let available = ui.available_size();
+    let cols = (available.x / char_width).floor().max(10.0) as u16;
+    let rows = (available.y / row_height).floor().max(2.0) as u16;
+    session.resize(rows, cols);
+
+    let screen = session.screen();
+    let (rows, cols) = screen.size();
+    let size = egui::vec2(cols as f32 * char_width, rows as f32 * row_height);
+    let (rect, response) = ui.allocate_exact_size(size, egui::Sense::click());
+
+    if response.clicked() {
+        response.request_focus();
+    }
+    let focused = response.has_focus();
+    if focused {
+        ui.memory_mut(|memory| {
+            memory.set_focus_lock_filter(
+                response.id,
+                egui::EventFilter {
+                    tab: true,
+                    horizontal_arrows: true,
+                    vertical_arrows: true,
+                    escape: true,
+                },
+            );
+        });
+        forward_input(ui, session, screen.application_cursor());
+    }
+
+    let painter = ui.painter_at(rect);
+    painter.rect_filled(rect, 4.0, TERMINAL_BG);
+
+    let (cursor_row, cursor_col) = screen.cursor_position();
+    let show_cursor = focused && !screen.hide_cursor();
+
+    let mut job = LayoutJob::default();
+    for row in 0..rows {
+        for col in 0..cols {
+            let Some(cell) = screen.cell(row, col) else {
+                continue;
+            };
+            if cell.is_wide_continuation() {
+                continue;
+            }
+
+            let mut fg = to_color32(cell.fgcolor(), TERMINAL_FG);
+            let mut bg = to_color32(cell.bgcolor(), Color32::TRANSPARENT);
+            if cell.inverse() {
+                (fg, bg) = (if bg == Color32::TRANSPARENT { TERMINAL_BG } else { bg }, fg);
+            }
+            if show_cursor && row == cursor_row && col == cursor_col {
+                bg = TERMINAL_FG;
+                fg = TERMINAL_BG;
+            }
+
+            let contents = cell.contents();
+            let text = if contents.is_empty() { " " } else { contents.as_str() };
+            job.append(
+                text,
+                0.0,
+                TextFormat {
+                    font_id: font_id.clone(),
+                    color: fg,
+                    background: bg,
+                    italics: cell.italic(),
+                    underline: if cell.underline() {
+                        egui::Stroke::new(1.0, fg)
+                    } else {
+                        egui::Stroke::NONE
+                    },
+                    ..Default::default()
+                },
+            );
+        }
+        if row + 1 < rows {
+            job.append("\n", 0.0, TextFormat::simple(font_id.clone(), TERMINAL_FG));
+        }
+    }
+
+    let galley = ui.fonts(|fonts| fonts.layout_job(job));
+    painter.galley(rect.min, galley, TERMINAL_FG);
+
+    if focused {
+        painter.rect_stroke(rect, 4.0, egui::Stroke::new(1.0, ThemeColors::BTN_PRIMARY));
+    }
+}
+
+/// Encaminha texto, teclas e colagens para o processo do terminal
+fn forward_input(ui: &egui::Ui, session: &mut PtySession, application_cursor: bool) {
+    let events = ui.input(|input| input.events.clone());
+    for event in events {
+        match event {
+            egui::Event::Text(text) => session.write(text.as_bytes()),
+            egui::Event::Paste(text) => session.write(text.as_bytes()),
+            egui::Event::Key {
+                key,
+                pressed: true,
+                modifiers,
+                ..
+            } => {
+                if let Some(bytes) = encode_key(key, modifiers, application_cursor) {
+                    session.write(&bytes);
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Converte uma cor VT100 para uma cor do egui
+fn to_color32(color: vt100::Color, default: Color32) -> Color32 {
+    match color {
+        vt100::Color::Default => default,
+        vt100::Color::Rgb(r, g, b) => Color32::from_rgb(r, g, b),
+        vt100::Color::Idx(index) => indexed_color(index),
+    }
+}
+
+/// Converte um índice da paleta de 256 cores
+fn indexed_color(index: u8) -> Color32 {
+    match index {
+        0..=15 => ANSI_COLORS[index as usize],
+        16..=231 => {
+            let index = index - 16;
+            let level = |v: u8| if v == 0 { 0 } else { 55 + v * 40 };
+            Color32::from_rgb(level(index / 36), level((index / 6) % 6), level(index % 6))
+        }
+        _ => {
+            let gray = 8 + (index - 232) * 10;
+            Color32::from_rgb(gray, gray, gray)
+        }
+    }
+}
@@ -0,0 +1,101 @@
+//! # Trash Dialog
+//!
+//! Lista as aplicações excluídas, permitindo restaurá-las ou
+//! excluí-las definitivamente.
+
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use eframe::egui::{self, RichText};
+use crate::core::TrashedApp;
+use crate::utils::format_age;
+
+/// Resultado das ações do diálogo
+pub enum TrashDialogResult {
+    /// Nenhuma ação
+    None,
+    /// Restaurar o item do índice informado
+    Restore(usize),
+    /// Excluir definitivamente o item do índice informado
+    Purge(usize),
+    /// Esvaziar a lixeira
+    Empty,
+    /// Fechar o diálogo
+    Close,
+}
+
+/// Renderiza o diálogo da lixeira.
+///
+/// # Argumentos
+/// * `ctx` - Contexto do egui
+/// * `items` - Itens da lixeira (mais antigo primeiro)
+///
+/// # Retorno
+/// `TrashDialogResult` indicando a ação tomada
+pub fn render_trash_dialog(ctx: &egui::Context, items: &[TrashedApp]) -> TrashDialogResult {
+    let mut result = TrashDialogResult::None;
+
+    egui::Window::new("🗑 Lixeira")
+        .collapsible(false)
+        .resizable(false)
+        .default_width(440.0)
+        .anchor(egui::Align2::CENTER_CENTER, [0.0, 0.0])
+        .show(ctx, |ui| {
+            ui.add_space(6.0);
+            if items.is_empty() {
+                ui.label(
+                    RichText::new("A lixeira está vazia")
+                        .size(12.0)
+                        .color(egui::Color32::GRAY),
+                );
+            } else {
+                egui::ScrollArea::vertical().max_height(300.0).show(ui, |ui| {
+                    // Mais recentes primeiro
+                    for (index, item) in items.iter().enumerate().rev() {
+                        ui.horizontal(|ui| {
+                            let deleted = UNIX_EPOCH + Duration::from_secs(item.deleted_at);
+                            let age = SystemTime::now().duration_since(deleted).unwrap_or_default();
+                            ui.label(RichText::new(&item.app.name).strong());
+                            ui.label(
+                                RichText::new(format!("excluída {}", format_age(age)))
+                                    .size(11.0)
+                                    .color(egui::Color32::GRAY),
+                            );
+                            ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                                if ui
+                                    .small_button(RichText::new("✖").color(egui::Color32::from_rgb(255, 100, 100)))
+                                    .on_hover_text("Excluir definitivamente")
+                                    .clicked()
+                                {
+                                    result = TrashDialogResult::Purge(index);
+                                }
+                                if ui.small_button("↩ Restaurar").clicked() {
+                                    result = TrashDialogResult::Restore(index);
+                                }
+                            });
+                        });
+                    }
+                });
+            }
+
+            ui.add_space(10.0);
+            ui.separator();
+
+            ui.horizontal(|ui| {
+                if ui.button("Fechar").clicked() {
+                    result = TrashDialogResult::Close;
+                }
+                if !items.is_empty() {
+                    ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                        if ui
+                            .button(RichText::new("Esvaziar lixeira").color(egui::Color32::from_rgb(255, 100, 100)))
+                            .clicked()
+                        {
+                            result = TrashDialogResult::Empty;
+                        }
+                    });
+                }
+            });
+        });
+
+    result
+}
@@ -0,0 +1,119 @@
+//! # Workspace Settings Dialog
+//!
+//! Diálogo para editar a pasta raiz do workspace e as variáveis globais
+//! usadas na interpolação de `${...}`.
+
+use eframe::egui::{self, RichText};
+use crate::core::{EnvVar, WorkspaceSettings};
+
+/// Estado do diálogo de variáveis globais
+#[derive(Default)]
+pub struct WorkspaceSettingsState {
+    /// Pasta raiz do workspace
+    pub root: String,
+    /// Variáveis globais, `NOME=valor` por linha
+    pub variables_text: String,
+}
+
+impl WorkspaceSettingsState {
+    /// Cria o estado a partir das configurações salvas
+    pub fn from_settings(settings: &WorkspaceSettings) -> Self {
+        Self {
+            root: settings.root.clone(),
+            variables_text: EnvVar::format_lines(&settings.variables),
+        }
+    }
+}
+
+/// Resultado das ações do diálogo
+pub enum WorkspaceSettingsResult {
+    /// Nenhuma ação
+    None,
+    /// Salvar as configurações
+    Save(WorkspaceSettings),
+    /// Cancelado
+    Cancelled,
+}
+
+/// Renderiza o diálogo de variáveis globais.
+///
+/// # Argumentos
+/// * `ctx` - Contexto do egui
+/// * `state` - Estado do diálogo
+///
+/// # Retorno
+/// `WorkspaceSettingsResult` indicando a ação tomada
+pub fn render_workspace_settings(
+    ctx: &egui::Context,
+    state: &mut WorkspaceSettingsState,
+) -> WorkspaceSettingsResult {
+    let mut result = WorkspaceSettingsResult::None;
+
+    egui::Window::new("🔣 Variáveis Globais")
+        .collapsible(false)
+        .resizable(false)
+        .default_width(460.0)
+        .anchor(egui::Align2::CENTER_CENTER, [0.0, 0.0])
+        .show(ctx, |ui| {
+            ui.add_space(6.0);
+            ui.label("Pasta raiz do workspace (${workspace.root}):");
+            ui.horizontal(|ui| {
+                ui.add(
+                    egui::TextEdit::singleline(&mut state.root)
+                        .desired_width(320.0)
+                        .hint_text("C:\\meus-projetos"),
+                );
+                if ui.button("📁 Selecionar").clicked() {
+                    if let Some(path) = rfd::FileDialog::new().pick_folder() {
+                        state.root = path.display().to_string();
+                    }
+                }
+            });
+
+            ui.add_space(10.0);
+            ui.label("Variáveis (NOME=valor, usadas como ${NOME}):");
+            ui.add(
+                egui::TextEdit::multiline(&mut state.variables_text)
+                    .desired_width(440.0)
+                    .desired_rows(5)
+                    .hint_text("TENANT=acme")
+                    .font(egui::TextStyle::Monospace),
+            );
+            ui.label(
+                RichText::new("Também disponíveis: ${HOME}, ${env:VAR}, ${app.dir}, ${app.name}, ${app.port}, ${secret:NOME}")
+                    .size(11.0)
+                    .color(egui::Color32::GRAY),
+            );
+
+            ui.add_space(10.0);
+            ui.separator();
+
+            let variables = EnvVar::parse_lines(&state.variables_text);
+            ui.horizontal(|ui| {
+                if ui.button("❌ Cancelar").clicked() {
+                    result = WorkspaceSettingsResult::Cancelled;
+                }
+                ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                    match &variables {
+                        Ok(variables) => {
+                            if ui.button("💾 Salvar").clicked() {
+                                result = WorkspaceSettingsResult::Save(WorkspaceSettings {
+                                    root: state.root.trim().to_string(),
+                                    variables: variables.clone(),
+                                });
+                            }
+                        }
+                        Err(e) => {
+                            ui.label(
+                                RichText::new(format!("⚠ {}", e))
+                                    .size(12.0)
+                                    .color(egui::Color32::from_rgb(255, 200, 100)),
+                            );
+                        }
+                    }
+                });
+            });
+        });
+
+    result
+}
@@ -23,10 +23,22 @@ impl ThemeColors {
     pub const RUNNING_BADGE_BG: Color32 = Color32::from_rgb(34, 55, 40);
     pub const RUNNING_TEXT: Color32 = Color32::from_rgb(80, 220, 120);
     
+    // Cores de estado - Pausado (SIGSTOP)
+    pub const PAUSED_BG: Color32 = Color32::from_rgb(38, 34, 24);
+    pub const PAUSED_BORDER: Color32 = Color32::from_rgb(245, 158, 11);
+    pub const PAUSED_BADGE_BG: Color32 = Color32::from_rgb(60, 48, 22);
+    
+    // Cores de estado - Pronto (detectado pelos logs)
+    pub const READY_BADGE_BG: Color32 = Color32::from_rgb(22, 70, 45);
+    pub const READY_TEXT: Color32 = Color32::from_rgb(134, 239, 172);
+    
     // Cores de estado - Loading
     pub const LOADING_BG: Color32 = Color32::from_rgb(25, 30, 40);
     pub const LOADING_BORDER: Color32 = Color32::from_rgb(99, 102, 241);
     
+    // Cores de estado - Observação de arquivos
+    pub const WATCH_ACTIVE: Color32 = Color32::from_rgb(56, 189, 248);
+    
     // Cores de botões
     pub const BTN_PRIMARY: Color32 = Color32::from_rgb(99, 102, 241);  // Indigo
     pub const BTN_DANGER: Color32 = Color32::from_rgb(239, 68, 68);   // Vermelho
@@ -98,8 +110,14 @@ pub fn apply_theme(ctx: &egui::Context) {
 }
 
 /// Retorna as cores do card baseadas no estado
-pub fn get_card_colors(is_running: bool, is_loading: bool) -> (Color32, Color32, Color32) {
-    if is_running {
+pub fn get_card_colors(is_running: bool, is_loading: bool, is_paused: bool) -> (Color32, Color32, Color32) {
+    if is_running && is_paused {
+        (
+            ThemeColors::PAUSED_BG,
+            ThemeColors::PAUSED_BORDER,
+            Color32::from_rgba_unmultiplied(245, 158, 11, 30),
+        )
+    } else if is_running {
         (
             ThemeColors::RUNNING_BG,
             ThemeColors::RUNNING_BORDER,
@@ -9,6 +9,8 @@
 /// 
 /// # Exemplo
 /// ```rust
+/// use iris::utils::uuid_simple;
+///
 /// let id = uuid_simple();
 /// assert!(!id.is_empty());
 /// ```
@@ -29,6 +31,8 @@ pub fn uuid_simple() -> String {
 /// 
 /// # Exemplo
 /// ```rust
+/// use iris::utils::truncate_path;
+///
 /// let truncated = truncate_path("C:\\Users\\Name\\Projects\\MyApp", 20);
 /// assert!(truncated.len() <= 20);
 /// ```
@@ -40,6 +44,42 @@ pub fn truncate_path(path: &str, max_len: usize) -> String {
     }
 }
 
+/// Formata há quanto tempo algo aconteceu (ex: "há 5 min").
+/// 
+/// # Exemplo
+/// ```rust
+/// use std::time::Duration;
+/// use iris::utils::format_age;
+///
+/// assert_eq!(format_age(Duration::from_secs(30)), "agora");
+/// assert_eq!(format_age(Duration::from_secs(3 * 3600)), "há 3 h");
+/// ```
+pub fn format_age(age: std::time::Duration) -> String {
+    let secs = age.as_secs();
+    match secs {
+        0..=59 => "agora".to_string(),
+        60..=3599 => format!("há {} min", secs / 60),
+        3600..=86399 => format!("há {} h", secs / 3600),
+        86400..=172799 => "há 1 dia".to_string(),
+        _ => format!("há {} dias", secs / 86400),
+    }
+}
+
+/// Escapa um valor para uso seguro em scripts POSIX `sh`.
+/// 
+/// O valor é envolvido em aspas simples; aspas simples internas
+/// são fechadas, escapadas e reabertas.
+/// 
+/// # Exemplo
+/// ```rust
+/// use iris::utils::shell_quote;
+///
+/// assert_eq!(shell_quote("/home/dev/my app"), "'/home/dev/my app'");
+/// ```
+pub fn shell_quote(value: &str) -> String {
+    format!("'{}'", value.replace('\'', "'\\''"))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -65,6 +105,11 @@ mod tests {
         assert_eq!(result, path);
     }
     
+    #[test]
+    fn test_shell_quote_single_quotes() {
+        assert_eq!(shell_quote("it's"), "'it'\\''s'");
+    }
+    
     #[test]
     fn test_truncate_path_long() {
         let path = "C:\\Users\\Name\\Very\\Long\\Path\\To\\Project";